@@ -5,41 +5,211 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'wrdl_helper.dart';
 
-// These functions are ignored because they are not marked as `pub`: `filter_words_with_feedback`, `load_answer_words_from_assets`, `load_guess_words_from_assets`, `word_matches_all_feedback`, `word_matches_single_feedback`
+// These functions are ignored because they are not marked as `pub`: `best_guess_with_config`, `count_remaining_guess_words`, `difficulty_preset`, `emoji_row_to_pattern`, `ensure_word_lists_loaded`, `expected_remaining_if_wrong`, `filter_words_matching`, `filter_words_with_feedback`, `for_each_matching_word`, `get_answer_words`, `get_guess_words`, `get_optimal_first_guess`, `load_answer_words_from_assets`, `load_guess_words_from_assets`, `parse_guess_results`, `parse_word_array`, `set_last_error`, `to_internal_guess_results`, `to_owned_words`, `validate_word`, `word_is_possible`, `word_list_path`
 
-/// * NORTH STAR ARCHITECTURE: Single FFI Entry Point
+/// NORTH STAR ARCHITECTURE: Single FFI Entry Point
+///
+/// This is the ONLY public FFI function in the North Star architecture.
+/// Client sends only GameState → Server handles ALL logic → Returns best_guess
+///
+/// # Arguments
+/// - `guess_results`: Vector of tuples containing (word, result_pattern)
+///   - word: The guessed word (e.g., "TARES")
+///   - result_pattern: Array of 5 result strings ["G", "Y", "X", "G", "X"]
+///     - "G" = Green (correct letter, correct position)
+///     - "Y" = Yellow (correct letter, wrong position)
+///     - "X" = Gray (letter not in word)
+///
+/// # Returns
+/// - `Option<String>`: The best word to guess next, or None if no valid guesses remain
+/// - If the last guess was all green the game is already won, and that word is
+///   returned as-is without scoring
+///
+/// # Performance
+/// - Time complexity: O(n*m) where n is candidate words, m is remaining words
+/// - Space complexity: O(n) for pattern analysis
+/// - Target response time: < 200ms
+/// - Success rate: 100% (preserved from perfect algorithm)
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_best_guess;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let best_guess = get_best_guess(guess_results);
+/// ```
+String? getBestGuess({required List<(String, List<String>)> guessResults}) =>
+    RustLib.instance.api.crateApiSimpleGetBestGuess(guessResults: guessResults);
+
+/// * Get the best guess for a remaining-word set the caller already has
 /// *
-/// * This is the ONLY public FFI function in the North Star architecture.
-/// * Client sends only GameState → Server handles ALL logic → Returns best_guess
+/// * Same scoring as `get_best_guess`, but the remaining words are taken as given
+/// * (e.g. from a prior `get_possible_words` call) instead of being filtered again.
+/// * Candidates are still drawn from the solver's strategic words as well.
 /// *
 /// * # Arguments
-/// * - `guess_results`: Vector of tuples containing (word, result_pattern)
-/// *   - word: The guessed word (e.g., "TARES")
-/// *   - result_pattern: Array of 5 result strings ["G", "Y", "X", "G", "X"]
-/// *     - "G" = Green (correct letter, correct position)
-/// *     - "Y" = Yellow (correct letter, wrong position)
-/// *     - "X" = Gray (letter not in word)
+/// * - `remaining_words`: Words that could still be the answer
+/// * - `guess_results`: Vector of (word, pattern) tuples from game state
 /// *
 /// * # Returns
-/// * - `Option<String>`: The best word to guess next, or None if no valid guesses remain
+/// * - The best word to guess next, or None if no words remain
+///
+String? getBestGuessForRemaining({
+  required List<String> remainingWords,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetBestGuessForRemaining(
+  remainingWords: remainingWords,
+  guessResults: guessResults,
+);
+
+/// * Get the best guess plus the runner-up
 /// *
-/// * # Performance
-/// * - Time complexity: O(n*m) where n is candidate words, m is remaining words
-/// * - Space complexity: O(n) for pattern analysis
-/// * - Target response time: < 200ms
-/// * - Success rate: 100% (preserved from perfect algorithm)
+/// * Runs the same candidate scoring as `get_best_guess` but keeps the two highest
+/// * combined scores, so the UI can offer the user a choice.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Vector of (word, pattern) tuples from game state
 /// *
-/// * # Example
-/// * ```rust
-/// * let guess_results = vec![
-/// *     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
-/// * ];
-/// * let best_guess = get_best_guess(guess_results);
-/// * ```
+/// * # Returns
+/// * - `(best, runner_up)`: The first element always equals `get_best_guess`'s output.
+/// *   The runner-up is a different word, or None when no second option exists
+/// *   (e.g. a single remaining word, or the first guess of the game).
 ///
-String? getBestGuess({required List<(String, List<String>)> guessResults}) =>
-    RustLib.instance.api.crateApiSimpleGetBestGuess(guessResults: guessResults);
+(String?, String?) getTopTwoGuesses({
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetTopTwoGuesses(
+  guessResults: guessResults,
+);
+
+/// * Get the best single guess for several simultaneous boards (Quordle/Dordle)
+/// *
+/// * Every board is one hidden word with its own feedback history. The remaining
+/// * words of each board are computed separately, and the returned guess maximizes
+/// * the summed information over all unsolved boards.
+/// *
+/// * # Arguments
+/// * - `boards`: One (word, pattern) history per board, in the same format as `get_best_guess`
+/// *
+/// * # Returns
+/// * - The best shared guess, or None if every board is solved or has no valid words left
+/// *
+/// * # Notes
+/// * - A board whose history contains an all-green guess is solved and ignored
+/// * - When no unsolved board has any feedback yet, the optimal first guess is returned
+///
+String? getBestGuessMultiboard({
+  required List<List<(String, List<String>)>> boards,
+}) => RustLib.instance.api.crateApiSimpleGetBestGuessMultiboard(
+  boards: boards,
+);
+
+/// * Get the most recent error message
+/// *
+/// * Functions that can only signal failure with None or an empty list (e.g. strict
+/// * pattern validation) record the reason here.
+/// *
+/// * # Returns
+/// * - The most recent error message, or None if no error has occurred
+///
+String? getLastError() => RustLib.instance.api.crateApiSimpleGetLastError();
+
+/// * Filter words against the game state, streaming matches as they are found
+/// *
+/// * Same filtering as `get_possible_words`, but each match is sent to Dart
+/// * immediately instead of collecting a `Vec`, so peak memory stays bounded for
+/// * very large word lists and the UI can render progressively.
+/// *
+/// * # Arguments
+/// * - `words`: Words to filter
+/// * - `guess_results`: Vector of (word, pattern) tuples from game state
+/// * - `sink`: Receives each matching word; filtering stops once Dart closes it
+///
+Stream<String> filterWordsStreamed({
+  required List<String> words,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleFilterWordsStreamed(
+  words: words,
+  guessResults: guessResults,
+);
+
+/// * Set the directory the word list assets are loaded from
+/// *
+/// * Defaults to `../assets/word_lists`. Call before `initialize_word_lists` when
+/// * the app bundles its assets elsewhere.
+/// *
+/// * # Arguments
+/// * - `path`: Directory containing `official_wordle_words.json` and `official_guess_words.txt`
+///
+void setWordListDir({required String path}) =>
+    RustLib.instance.api.crateApiSimpleSetWordListDir(path: path);
+
+/// * Load the word lists from the assets into the global word manager
+/// *
+/// * Reads the answer and guess lists once, interns them, and computes the optimal
+/// * first guess. All solver functions then work from memory. Calling it again
+/// * reloads the lists from disk.
+/// *
+/// * # Returns
+/// * - `Ok(())` on success, or an error describing which list failed to load
+///
+void initializeWordLists() =>
+    RustLib.instance.api.crateApiSimpleInitializeWordLists();
+
+/// * Export the word lists currently held in memory as JSON
+/// *
+/// * Produces `{ "answer_words": [...], "guess_words": [...] }` so the app can
+/// * snapshot the lists for backup or offline use.
+/// *
+/// * # Returns
+/// * - The JSON blob (both lists empty if the word manager is unavailable)
+///
+String exportWordListsJson() =>
+    RustLib.instance.api.crateApiSimpleExportWordListsJson();
+
+/// * Replace the word lists in memory from a JSON blob
+/// *
+/// * Accepts the format produced by `export_word_lists_json`. Every word must be
+/// * 5 ASCII letters and the answer list must not be empty. Words are uppercased,
+/// * and nothing is changed if validation fails.
+/// *
+/// * # Arguments
+/// * - `json`: `{ "answer_words": [...], "guess_words": [...] }`
+/// *
+/// * # Returns
+/// * - `Ok(())` on success, or an error describing the first invalid entry
+///
+void importWordListsJson({required String json}) =>
+    RustLib.instance.api.crateApiSimpleImportWordListsJson(json: json);
+
+/// * Check several typed guesses in one call
+/// *
+/// * A word is a legal guess if it is 5 ASCII letters and in the loaded guess list
+/// * (any case).
+/// *
+/// * # Arguments
+/// * - `words`: Words to check
+/// *
+/// * # Returns
+/// * - One flag per input word, in the same order (all false if the word lists
+/// *   can't be loaded)
+///
+List<bool> validateWords({required List<String> words}) =>
+    RustLib.instance.api.crateApiSimpleValidateWords(words: words);
+
+/// * Prepare everything the solver builds on first use
+/// *
+/// * Loads the word lists (interning the guess list) and makes sure the optimal
+/// * first guess is cached, so the first `get_best_guess` doesn't pay for it.
+/// * Intended to run while the app shows its splash screen; the time taken is logged.
+/// *
+/// * # Returns
+/// * - `Ok(())` once everything is ready, or the error from loading the word lists
+///
+void warmUp() => RustLib.instance.api.crateApiSimpleWarmUp();
 
 /// * Get intelligent guess using advanced algorithms (optimized version)
 /// *
@@ -144,21 +314,416 @@ double calculateEntropy({
   remainingWords: remainingWords,
 );
 
-/// * Simulate guess pattern for testing
+/// * Calculate entropy for a candidate word against a game state
 /// *
-/// * This function simulates what pattern would result from guessing
-/// * a word against a target word.
+/// * Unlike `calculate_entropy`, the remaining words are derived from the state
+/// * with the same filtering as `get_best_guess`, so the caller can't score
+/// * against a stale or hand-edited word list.
 /// *
 /// * # Arguments
-/// * - `guess`: The word being guessed
-/// * - `target`: The target word
+/// * - `candidate_word`: The word to analyze
+/// * - `guess_results`: Vector of (word, pattern) tuples from game state
 /// *
 /// * # Returns
-/// * Pattern string like "GGYXY" (G=Green, Y=Yellow, X=Gray)
+/// * Entropy value (higher = more information), or 0.0 if the state is invalid
 /// *
 /// * # Performance
-/// * - Time complexity: O(1) for 5-letter words
-/// * - Space complexity: O(1)
+/// * - Time complexity: O(n) where n is the guess word list
+///
+double calculateEntropyForState({
+  required String candidateWord,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleCalculateEntropyForState(
+  candidateWord: candidateWord,
+  guessResults: guessResults,
+);
+
+/// * Evaluate how informative a custom opener is
+/// *
+/// * Returns the opener's entropy against the full answer list, so the UI can
+/// * compare a user's own first guess with the recommended one.
+/// *
+/// * # Arguments
+/// * - `opener`: The first guess to evaluate (case-insensitive)
+/// *
+/// * # Returns
+/// * - Expected information in bits (higher is better), or 0.0 if the word
+/// *   lists can't be loaded
+///
+double evaluateOpener({required String opener}) =>
+    RustLib.instance.api.crateApiSimpleEvaluateOpener(opener: opener);
+
+/// * Rank openers by entropy against the full answer list
+/// *
+/// * Backs a "best openers" screen. Every candidate is scored against all answer
+/// * words, so this is heavy: it runs asynchronously off the UI thread, and the
+/// * result is best cached.
+/// *
+/// * # Arguments
+/// * - `candidates`: Openers to rank (case-insensitive); invalid words are skipped.
+/// *   When empty, the 500 loaded guess words whose letters best split the answers
+/// *   are ranked
+/// * - `top_n`: Maximum number of openers to return
+/// *
+/// * # Returns
+/// * - (word, entropy in bits) pairs, best first, ties broken alphabetically.
+/// *   Empty if `top_n` is not positive or the word lists can't be loaded
+///
+Future<List<(String, double)>> rankOpeners({
+  required List<String> candidates,
+  required int topN,
+}) => RustLib.instance.api.crateApiSimpleRankOpeners(
+  candidates: candidates,
+  topN: topN,
+);
+
+/// Get all possible remaining words based on current constraints
+///
+/// This function returns all words that could still be the answer
+/// based on the current game state and constraints. Words come from the
+/// in-memory word manager; the assets are only read if `initialize_word_lists`
+/// hasn't run yet.
+///
+/// # Arguments
+/// * `guess_results` - Vector of tuples containing (word, result_pattern)
+///   - word: The guessed word (e.g., "TARES")
+///   - result_pattern: Vector of result strings (e.g., ["G", "Y", "Y", "X", "X"])
+///     - "G" = Green (correct letter, correct position)
+///     - "Y" = Yellow (correct letter, wrong position)
+///     - "X" = Gray (letter not in word)
+///
+/// # Returns
+/// * `Vec<String>` - All possible remaining answer words
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_possible_words;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let possible_words = get_possible_words(guess_results);
+/// ```
+List<String> getPossibleWords({
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetPossibleWords(
+  guessResults: guessResults,
+);
+
+/// * Get the possible words from a caller-supplied answer pool
+/// *
+/// * Same filtering as `get_possible_words`, but over `universe` instead of the
+/// * loaded word lists, for variants with a themed answer pool. `WORD_MANAGER` is
+/// * not touched.
+/// *
+/// * # Arguments
+/// * - `universe`: Candidate answers (any case)
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - The words of `universe` (uppercased, in order) consistent with every guess,
+/// *   or empty if an entry isn't a 5-letter word (see `get_last_error`)
+///
+List<String> getPossibleWordsIn({
+  required List<String> universe,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetPossibleWordsIn(
+  universe: universe,
+  guessResults: guessResults,
+);
+
+/// * Get the possible words for a shared Wordle result
+/// *
+/// * Shared games are emoji grids, one row per guess. Each row is converted to a
+/// * G/Y/X pattern and paired with the matching guess, then filtered like
+/// * `get_possible_words`. Both the standard (🟩 🟨) and high-contrast (🟧 🟦)
+/// * colors are accepted, with ⬛ or ⬜ for gray; surrounding whitespace is ignored.
+/// *
+/// * # Arguments
+/// * - `guesses`: The guessed words, in order
+/// * - `emoji_rows`: One emoji row per guess, e.g. "🟩⬛🟨⬛⬛"
+/// *
+/// * # Returns
+/// * - The possible words, or empty if the counts differ or a row isn't 5 known
+/// *   emoji (see `get_last_error`)
+///
+List<String> reconstructRemainingFromEmoji({
+  required List<String> guesses,
+  required List<String> emojiRows,
+}) => RustLib.instance.api.crateApiSimpleReconstructRemainingFromEmoji(
+  guesses: guesses,
+  emojiRows: emojiRows,
+);
+
+/// Get count of possible remaining words based on current constraints
+///
+/// This function returns the count of words that could still be the answer
+/// based on the current game state and constraints. This is a lightweight
+/// alternative to get_possible_words() for UI updates.
+///
+/// # Arguments
+/// * `guess_results` - Vector of tuples containing (word, result_pattern)
+///
+/// # Returns
+/// * `i32` - Count of possible remaining answer words
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_possible_word_count;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let count = get_possible_word_count(guess_results);
+/// ```
+int getPossibleWordCount({
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetPossibleWordCount(
+  guessResults: guessResults,
+);
+
+/// * Check whether a single word is still possible
+/// *
+/// * Agrees with membership in `get_possible_words` but checks only `word`, so a UI
+/// * can grey out impossible words in a long list without building the full
+/// * possible-words list for each one.
+/// *
+/// * # Arguments
+/// * - `word`: Word to check (any case)
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - `true` if `word` would be in `get_possible_words(guess_results)`
+///
+bool isWordStillPossible({
+  required String word,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleIsWordStillPossible(
+  word: word,
+  guessResults: guessResults,
+);
+
+/// * Check one word against the feedback of a single guess
+/// *
+/// * A lightweight alternative to `is_word_still_possible` when only one guess
+/// * matters: no word lists are loaded and dictionary membership isn't checked.
+/// *
+/// * # Arguments
+/// * - `candidate`: Word to check (any case)
+/// * - `guess_word`: The guessed word (any case)
+/// * - `pattern`: Feedback for the guess as 5 tokens: "G", "Y" or "X" (any case)
+/// *
+/// * # Returns
+/// * - `true` if `candidate` could be the answer given this feedback; `false` for
+/// *   invalid input, with the reason available from `get_last_error`
+///
+bool wordMatchesFeedback({
+  required String candidate,
+  required String guessWord,
+  required List<String> pattern,
+}) => RustLib.instance.api.crateApiSimpleWordMatchesFeedback(
+  candidate: candidate,
+  guessWord: guessWord,
+  pattern: pattern,
+);
+
+/// * Get how many possible words the most recent guess eliminated
+/// *
+/// * For a post-guess message like "that guess eliminated 1,842 words!". Both
+/// * counts are `get_possible_word_count` values: before uses every guess but the
+/// * last, after uses all of them.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns, most recent last
+/// *
+/// * # Returns
+/// * - `(before, after)`: Possible words before and after the last guess. With no
+/// *   guesses both are the full answer count.
+///
+(int, int) informationGained({
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleInformationGained(
+  guessResults: guessResults,
+);
+
+/// * Get the answer once the game is effectively solved
+/// *
+/// * Lets the UI auto-fill the answer as soon as the constraints pin it down,
+/// * even before it has been guessed.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - `Some(word)` when exactly one possible word remains, otherwise None
+///
+String? isGameSolved({required List<(String, List<String>)> guessResults}) =>
+    RustLib.instance.api.crateApiSimpleIsGameSolved(guessResults: guessResults);
+
+/// * Get a single-letter hint
+/// *
+/// * A gentler alternative to `get_best_guess`: the letter not yet tried that
+/// * appears in the most remaining possible words. Letters from previous guesses
+/// * are skipped, since their presence or absence is already known.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - The hint letter (e.g. "O"), alphabetically first on ties, or None if no
+/// *   words remain or every letter in them has been tried
+///
+String? getLetterHint({required List<(String, List<String>)> guessResults}) =>
+    RustLib.instance.api.crateApiSimpleGetLetterHint(
+      guessResults: guessResults,
+    );
+
+/// * Get at most `max` possible words, plus whether the list was truncated
+/// *
+/// * Early in a game thousands of words are still possible, but the UI only shows a
+/// * few. This avoids sending the whole list across FFI on every keystroke.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// * - `max`: Maximum number of words to return (negative values are treated as 0)
+/// *
+/// * # Returns
+/// * - `(words, truncated, total)`: The first `max` possible words in alphabetical
+/// *   order, whether any were left out, and the total number of possible words
+///
+(List<String>, bool, int) getPossibleWordsCapped({
+  required List<(String, List<String>)> guessResults,
+  required int max,
+}) => RustLib.instance.api.crateApiSimpleGetPossibleWordsCapped(
+  guessResults: guessResults,
+  max: max,
+);
+
+/// * Get the possible answer most worth guessing when it has to be able to win now
+/// *
+/// * Only answer words consistent with the feedback are considered, so the guess
+/// * can always be correct this turn. Among them it picks the one that leaves the
+/// * fewest answers on average if it turns out to be wrong: a two-ply search
+/// * restricted to the possible answers.
+/// *
+/// * With no guesses yet this is the precomputed opener, as in `get_best_guess`:
+/// * the search over the whole answer list would take over a second.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - The chosen word, alphabetically first on ties, or None if no answers remain
+///
+String? getBestWinningGuess({
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGetBestWinningGuess(
+  guessResults: guessResults,
+);
+
+/// * Get the best guess given how many guesses the player has left
+/// *
+/// * On the last allowed guess a word that can't be the answer guarantees a loss,
+/// * however informative it is. So with one guess (or none) left the choice is
+/// * restricted to possible answers via `get_best_winning_guess`, whatever the
+/// * solver config says; otherwise this is `get_best_guess`.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// * - `guesses_remaining`: Guesses the player has left, including this one
+/// *
+/// * # Returns
+/// * - The word to guess next, or None if no words remain
+///
+String? getBestGuessWithBudget({
+  required List<(String, List<String>)> guessResults,
+  required int guessesRemaining,
+}) => RustLib.instance.api.crateApiSimpleGetBestGuessWithBudget(
+  guessResults: guessResults,
+  guessesRemaining: guessesRemaining,
+);
+
+/// * Get the distribution of feedback patterns a guess would produce
+/// *
+/// * For every pattern the guess could produce against the remaining possible
+/// * words, reports how many words produce it and how likely it is. This is the
+/// * data behind entropy, surfaced directly for an educational chart.
+/// *
+/// * # Arguments
+/// * - `guess`: Word to evaluate
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - `(pattern, count, probability)` per pattern (e.g. ("XYXXG", 12, 0.05)),
+/// *   most likely first; empty if no words remain or `guess` isn't a 5-letter
+/// *   word (see `get_last_error`)
+///
+List<(String, int, double)> guessOutcomeDistribution({
+  required String guess,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimpleGuessOutcomeDistribution(
+  guess: guess,
+  guessResults: guessResults,
+);
+
+/// * Group the possible words by the feedback a guess would produce
+/// *
+/// * The words behind `guess_outcome_distribution`, for a "decision tree" view:
+/// * each pattern the candidate could produce with the words that would remain.
+/// *
+/// * # Arguments
+/// * - `candidate`: Word to evaluate
+/// * - `guess_results`: Previous guess results with patterns
+/// * - `max_words_per_bucket`: Maximum words listed per pattern, to bound the payload
+/// *   (negative values are treated as 0)
+/// *
+/// * # Returns
+/// * - `(pattern, words)` per pattern, largest bucket first (ties in pattern order),
+/// *   with words in alphabetical order; empty if no words remain or `candidate`
+/// *   isn't a 5-letter word (see `get_last_error`)
+///
+List<(String, List<String>)> guessPatternBuckets({
+  required String candidate,
+  required List<(String, List<String>)> guessResults,
+  required int maxWordsPerBucket,
+}) => RustLib.instance.api.crateApiSimpleGuessPatternBuckets(
+  candidate: candidate,
+  guessResults: guessResults,
+  maxWordsPerBucket: maxWordsPerBucket,
+);
+
+/// * Preview how many words a guess is expected to leave
+/// *
+/// * The same information as entropy, in words instead of bits, for a UI hint
+/// * like "if you guess X, you'll likely have ~N words left". Each pattern leaves
+/// * its bucket of words, weighted by how likely the pattern is.
+/// *
+/// * # Arguments
+/// * - `candidate`: Word to preview (case-insensitive)
+/// * - `guess_results`: Previous guess results with patterns
+/// *
+/// * # Returns
+/// * - Expected number of possible words after guessing `candidate` (lower is
+/// *   better), or 0.0 if no words remain or `candidate` isn't a 5-letter word
+/// *   (see `get_last_error`)
+///
+double previewGuess({
+  required String candidate,
+  required List<(String, List<String>)> guessResults,
+}) => RustLib.instance.api.crateApiSimplePreviewGuess(
+  candidate: candidate,
+  guessResults: guessResults,
+);
+
+/// * Simulate the feedback for a guess against a known answer
+/// *
+/// * # Arguments
+/// * - `guess`: The guessed word (case-insensitive)
+/// * - `target`: The answer word (case-insensitive)
+/// *
+/// * # Returns
+/// * - The pattern as five characters, e.g. "GYXXG"; empty if either word isn't
+/// *   a 5-letter word (see `get_last_error`)
 ///
 String simulateGuessPattern({required String guess, required String target}) =>
     RustLib.instance.api.crateApiSimpleSimulateGuessPattern(
@@ -166,6 +731,93 @@ String simulateGuessPattern({required String guess, required String target}) =>
       target: target,
     );
 
+/// * Simulate the feedback for a guess as one token per letter
+/// *
+/// * Same feedback as `simulate_guess_pattern`, already split into the
+/// * `["G", "Y", "X", ...]` form `get_best_guess` and friends accept.
+/// *
+/// * # Arguments
+/// * - `guess`: The guessed word (case-insensitive)
+/// * - `target`: The answer word (case-insensitive)
+/// *
+/// * # Returns
+/// * - Five tokens: "G" (Green), "Y" (Yellow), or "X" (Gray); empty if either
+/// *   word isn't a 5-letter word (see `get_last_error`)
+///
+List<String> simulateGuessPatternResults({
+  required String guess,
+  required String target,
+}) => RustLib.instance.api.crateApiSimpleSimulateGuessPatternResults(
+  guess: guess,
+  target: target,
+);
+
+/// * Recompute the cached optimal first guess
+/// *
+/// * The optimal first guess is computed once when word lists load. Call this after
+/// * replacing the word lists to refresh it without restarting the app.
+/// *
+/// * # Returns
+/// * - The new optimal first guess, or None if the manager is unavailable or no
+/// *   known opener is in the current guess list
+///
+String? recomputeOptimalFirstGuess() =>
+    RustLib.instance.api.crateApiSimpleRecomputeOptimalFirstGuess();
+
+/// * Play a game against a known target and explain every guess
+/// *
+/// * Uses the same guesses as `get_best_guess` and tags each with a short reason,
+/// * for a tutorial that narrates the solver's thinking:
+/// * - "optimal opener": The precomputed first guess
+/// * - "only one word left": The constraints pin down the answer
+/// * - "highest entropy among remaining": The most informative possible answer
+/// * - "highest entropy, not a possible answer": A throwaway guess that splits the
+/// *   remaining words best
+/// *
+/// * # Arguments
+/// * - `target`: The answer to solve for (any case)
+/// * - `max_guesses`: Maximum number of guesses (negative values are treated as 0)
+/// *
+/// * # Returns
+/// * - `(guess, rationale, entropy)` per guess, where entropy is in bits over the
+/// *   words still possible before the guess. Stops at the target or after
+/// *   `max_guesses`; empty if `target` isn't a 5-letter word (see `get_last_error`)
+///
+List<(String, String, double)> solveWithExplanations({
+  required String target,
+  required int maxGuesses,
+}) => RustLib.instance.api.crateApiSimpleSolveWithExplanations(
+  target: target,
+  maxGuesses: maxGuesses,
+);
+
+/// * Start a new game
+/// *
+/// * Clears per-game state (currently the last recorded error) while keeping the
+/// * loaded word lists, so the next `get_best_guess` sees a fresh board. Loads the
+/// * word lists first if they aren't loaded yet.
+/// *
+/// * # Returns
+/// * - The optimal first guess for the new game, or None if it is unavailable
+///
+String? newGame() => RustLib.instance.api.crateApiSimpleNewGame();
+
+/// * Get the answer word for a daily challenge
+/// *
+/// * Maps a day number onto a seeded shuffle of the answer list as
+/// * `shuffled[day_number % len]`, so every player sees the same word on the same
+/// * day without a server, and consecutive days aren't alphabetical neighbors.
+/// * Negative day numbers wrap around from the end of the list.
+/// *
+/// * # Arguments
+/// * - `day_number`: Index of the day (e.g. days since the app's launch date)
+/// *
+/// * # Returns
+/// * - The answer word for that day, or None if the word lists aren't loaded
+///
+String? getDailyAnswer({required int dayNumber}) =>
+    RustLib.instance.api.crateApiSimpleGetDailyAnswer(dayNumber: dayNumber);
+
 /// * Set solver configuration
 /// *
 /// * This function sets the global solver configuration for all subsequent operations.
@@ -173,6 +825,18 @@ String simulateGuessPattern({required String guess, required String target}) =>
 /// * # Arguments
 /// * - `config`: Configuration struct with all solver settings
 /// *
+/// * # Candidate Cap
+/// * `candidate_cap` is clamped to a minimum of 1. A cap of 0 would otherwise leave
+/// * the solver with no candidates to score, so it returns a guess whenever words remain.
+/// *
+/// * # Errors
+/// * Returns an error, leaving the current config untouched, if:
+/// * - `candidate_cap` is negative
+/// * - `early_termination_threshold` is NaN, infinite, negative, or above 16 bits
+/// *
+/// * Callers that ignored the old `()` return keep working; valid input behaves
+/// * exactly as before.
+/// *
 /// * # Performance
 /// * - Time complexity: O(1)
 /// * - Space complexity: O(1)
@@ -192,3 +856,155 @@ void setSolverConfig({
   earlyTerminationThreshold: earlyTerminationThreshold,
   entropyOnlyScoring: entropyOnlyScoring,
 );
+
+/// * Set the solver configuration from a difficulty preset
+/// *
+/// * A friendlier alternative to `set_solver_config` for casual users:
+/// * - "easy": Only guesses words that are still possible, scores at most 50
+/// *   candidates, and stops at the first new best guess with 3.0 bits
+/// * - "balanced": The default configuration: killer words, at most 100 candidates
+/// * - "max": Killer words, every candidate scored
+/// *
+/// * All presets use pure entropy scoring. Game rule settings (`strict_patterns`,
+/// * `exclude_prior_guesses`), `prefer_unique_letters`, `prime_suspect_bonus`, and
+/// * `full_scoring_max_remaining` are left as they are.
+/// *
+/// * # Arguments
+/// * - `level`: "easy", "balanced", or "max" (case-insensitive)
+/// *
+/// * # Returns
+/// * - `Ok(())` on success, or an error for an unknown level (config unchanged)
+///
+void setSolverDifficulty({required String level}) =>
+    RustLib.instance.api.crateApiSimpleSetSolverDifficulty(level: level);
+
+/// * Reset the solver configuration to its defaults
+/// *
+/// * Restores every setting, including the game rule settings that
+/// * `set_solver_config` and `set_solver_difficulty` leave untouched.
+///
+void resetSolverConfig() =>
+    RustLib.instance.api.crateApiSimpleResetSolverConfig();
+
+/// * Compare the best guesses of two solver configs on the same state
+/// *
+/// * Computes `get_best_guess` under each config without touching the global
+/// * solver config, so concurrent solver calls are unaffected. The configs are used
+/// * as given; `candidate_cap` is still floored at 1 by the solver.
+/// *
+/// * # Arguments
+/// * - `guess_results`: Previous guess results with patterns
+/// * - `config_a`, `config_b`: The configs to compare
+/// *
+/// * # Returns
+/// * - `(guess_a, guess_b, agree)`: The best guess under each config and whether they match
+///
+(String?, String?, bool) guessesAgree({
+  required List<(String, List<String>)> guessResults,
+  required SolverConfig configA,
+  required SolverConfig configB,
+}) => RustLib.instance.api.crateApiSimpleGuessesAgree(
+  guessResults: guessResults,
+  configA: configA,
+  configB: configB,
+);
+
+/// * Get the solver algorithm identifier and active configuration
+/// *
+/// * Lets the app log exactly which solver variant produced a suggestion, which
+/// * makes support reports reproducible.
+/// *
+/// * # Returns
+/// * - `(algorithm, config)`: The algorithm name and crate version (e.g.
+/// *   "wrdlhelper-entropy/0.1.0"), and a compact serialization of the active
+/// *   `SolverConfig` (e.g. "ref=0,killer=1,cap=100,...")
+///
+(String, String) getSolverInfo() =>
+    RustLib.instance.api.crateApiSimpleGetSolverInfo();
+
+/// * Check whether `candidate_cap` cut candidates from the last `get_best_guess`
+/// *
+/// * A capped search may miss the globally best guess, so this helps tune
+/// * `candidate_cap` against accuracy. The cap (100 by default) is the only limit on
+/// * how many candidates are scored. Stopping early on an unbeatable score or through
+/// * `early_termination_enabled` isn't counted, since it can't miss a better guess
+/// * (early termination trades that risk for speed on purpose). First guesses,
+/// * solved boards, and endgames never select candidates and always report false.
+/// *
+/// * # Returns
+/// * - `true` if the most recent `get_best_guess` dropped candidates
+///
+bool lastGuessWasCapped() =>
+    RustLib.instance.api.crateApiSimpleLastGuessWasCapped();
+
+/// * Get how many candidates `candidate_cap` cut from the last `get_best_guess`
+/// *
+/// * # Returns
+/// * - The number of candidates dropped, or 0 if the call wasn't capped
+///
+int lastGuessCappedCount() =>
+    RustLib.instance.api.crateApiSimpleLastGuessCappedCount();
+
+/// * Enable or disable strict pattern validation
+/// *
+/// * When enabled, `get_best_guess`, `get_intelligent_guess`, and `get_possible_words`
+/// * reject patterns containing anything other than "G", "Y", or "X" (or not exactly
+/// * 5 tokens). They return None/empty and record the reason for `get_last_error`,
+/// * instead of treating unknown tokens as Gray. Disabled by default.
+///
+void setStrictPatterns({required bool enabled}) =>
+    RustLib.instance.api.crateApiSimpleSetStrictPatterns(enabled: enabled);
+
+/// * Enable or disable excluding previously guessed words
+/// *
+/// * For Wordle variants where the answer can never repeat an earlier guess. When
+/// * enabled, every word already in `guess_results` is removed from the possible
+/// * answers and from the candidate guesses. Disabled by default.
+///
+void setExcludePriorGuesses({required bool enabled}) =>
+    RustLib.instance.api.crateApiSimpleSetExcludePriorGuesses(enabled: enabled);
+
+/// * Enable or disable favoring unique-letter words in the opening
+/// *
+/// * When enabled, words with five distinct letters get a small bonus during the
+/// * first two guesses, mirroring the human habit of maximizing letter coverage
+/// * early. Disabled by default.
+///
+void setPreferUniqueLetters({required bool enabled}) =>
+    RustLib.instance.api.crateApiSimpleSetPreferUniqueLetters(enabled: enabled);
+
+/// * Choose which words the solver considers as guesses
+/// *
+/// * - `RemainingOnly`: Only words that could still be the answer, for players who
+/// *   never want a throwaway guess
+/// * - `RemainingPlusStrategic`: Remaining words plus the curated strategic words (default)
+/// * - `FullDictionary`: Every allowed guess, subject to `candidate_cap`
+///
+void setCandidateSource({required CandidateSource source}) =>
+    RustLib.instance.api.crateApiSimpleSetCandidateSource(source: source);
+
+/// * Set the score bonus for guesses that could still be the answer
+/// *
+/// * The default of 0.1 bits lets a possible answer win near-ties against slightly
+/// * more informative throwaway words. 0.0 disables it for pure information
+/// * maximization.
+/// *
+/// * # Returns
+/// * - `Ok(())` on success, or an error for a negative or non-finite bonus (config unchanged)
+///
+void setPrimeSuspectBonus({required double bonus}) =>
+    RustLib.instance.api.crateApiSimpleSetPrimeSuspectBonus(bonus: bonus);
+
+/// * Set the largest remaining-word count that gets full entropy scoring
+/// *
+/// * With more words left, the solver ranks its strategic shortlist by letter
+/// * frequency instead of scoring every candidate's entropy: much faster early in
+/// * a game, at a small cost in accuracy. Defaults to `i32::MAX` (always score fully).
+/// *
+/// * # Returns
+/// * - `Ok(())` on success, or an error for a negative limit (config unchanged)
+///
+void setFullScoringMaxRemaining({required int maxRemaining}) =>
+    RustLib.instance.api.crateApiSimpleSetFullScoringMaxRemaining(
+      maxRemaining: maxRemaining,
+    );
@@ -4,182 +4,89 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
-import 'package:collection/collection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_candidate_words`, `get_top_strategic_words`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SolverConfig`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+/// Where the solver draws the guesses it scores from
+enum CandidateSource {
+  /// Only words that could still be the answer, so every guess can win
+  remainingOnly,
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WordManager>>
-abstract class WordManager implements RustOpaqueInterface {
-  List<String> get answerWords;
+  /// Remaining words plus the curated strategic words, if `include_killer_words` is set
+  remainingPlusStrategic,
 
-  List<String> get guessWords;
-
-  String? get optimalFirstGuess;
-
-  set answerWords(List<String> answerWords);
-
-  set guessWords(List<String> guessWords);
-
-  set optimalFirstGuess(String? optimalFirstGuess);
-
-  /// Compute the optimal first guess once at startup
-  ///
-  /// Uses proven optimal first guesses from statistical analysis.
-  /// No computation needed - these are already known to be optimal!
-  Future<void> computeOptimalFirstGuess();
-
-  Future<void> getAnswerWords();
-
-  Future<void> getGuessWords();
-
-  Future<String?> getOptimalFirstGuess();
-
-  Future<void> loadWords();
-
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<WordManager> newInstance() =>
-      RustLib.instance.api.crateApiWrdlHelperWordManagerNew();
-}
-
-/// FFI-compatible struct for guess results
-class GuessResult {
-  const GuessResult({required this.word, required this.results});
-  final String word;
-  final List<LetterResult> results;
-
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<GuessResult> newInstance({
-    required String word,
-    required LetterResultArray5 results,
-  }) => RustLib.instance.api.crateApiWrdlHelperGuessResultNew(
-    word: word,
-    results: results,
-  );
-
-  @override
-  int get hashCode => word.hashCode ^ results.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is GuessResult &&
-          runtimeType == other.runtimeType &&
-          word == other.word &&
-          results == other.results;
+  /// Every word in the loaded guess list
+  fullDictionary,
 }
 
-/// Intelligent solver that combines multiple algorithms for optimal word selection
-class IntelligentSolver {
-  const IntelligentSolver({required this.words});
-  final List<String> words;
-
-  /// Calculate entropy (information gain) for a candidate word
-  ///
-  /// BALANCED: Uses Shannon entropy - simple and effective
-  /// Based on the working algorithm that achieved 96% success rate
-  Future<double> calculateEntropy({
-    required String candidateWord,
-    required List<String> remainingWords,
-  }) =>
-      RustLib.instance.api.crateApiWrdlHelperIntelligentSolverCalculateEntropy(
-        that: this,
-        candidateWord: candidateWord,
-        remainingWords: remainingWords,
-      );
-
-  /// Calculate statistical score based on letter frequency and position probability
-  ///
-  /// BALANCED: Simple and effective statistical analysis
-  /// Based on the working algorithm that achieved 96% success rate
-  Future<double> calculateStatisticalScore({
-    required String candidateWord,
-    required List<String> remainingWords,
-  }) => RustLib.instance.api
-      .crateApiWrdlHelperIntelligentSolverCalculateStatisticalScore(
-        that: this,
-        candidateWord: candidateWord,
-        remainingWords: remainingWords,
-      );
-
-  /// Filter words based on guess results
-  Future<List<String>> filterWords({
-    required List<String> words,
-    required List<GuessResult> guessResults,
-  }) => RustLib.instance.api.crateApiWrdlHelperIntelligentSolverFilterWords(
-    that: this,
-    words: words,
-    guessResults: guessResults,
-  );
-
-  /// Get the best guess using intelligent algorithms
-  ///
-  /// IMPROVED VERSION: Implements three advanced strategies:
-  /// 1. Two-List "Hard Mode" Strategy - Smart choice between info gathering vs winning
-  /// 2. Dynamic Candidate Selection - Focus on high-potential words only
-  /// 3. Minimax Look-Ahead Score - Advanced endgame optimization
-  Future<String?> getBestGuess({
-    required List<String> remainingWords,
-    required List<GuessResult> guessResults,
-  }) => RustLib.instance.api.crateApiWrdlHelperIntelligentSolverGetBestGuess(
-    that: this,
-    remainingWords: remainingWords,
-    guessResults: guessResults,
-  );
-
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Create a new intelligent solver
-  static Future<IntelligentSolver> newInstance({required List<String> words}) =>
-      RustLib.instance.api.crateApiWrdlHelperIntelligentSolverNew(words: words);
-
-  /// Simulate the guess pattern that would result from guessing against a target word
-  Future<String> simulateGuessPattern({
-    required String guess,
-    required String target,
-  }) => RustLib.instance.api
-      .crateApiWrdlHelperIntelligentSolverSimulateGuessPattern(
-        that: this,
-        guess: guess,
-        target: target,
-      );
-
-  /// Check if a word matches the given guess pattern
-  Future<bool> wordMatchesPattern({
-    required String word,
-    required GuessResult guessResult,
-  }) => RustLib.instance.api
-      .crateApiWrdlHelperIntelligentSolverWordMatchesPattern(
-        that: this,
-        word: word,
-        guessResult: guessResult,
-      );
+/// FFI-compatible configuration struct
+class SolverConfig {
+  const SolverConfig({
+    required this.referenceMode,
+    required this.includeKillerWords,
+    required this.candidateCap,
+    required this.earlyTerminationEnabled,
+    required this.earlyTerminationThreshold,
+    required this.entropyOnlyScoring,
+    required this.strictPatterns,
+    required this.excludePriorGuesses,
+    required this.preferUniqueLetters,
+    required this.candidateSource,
+    required this.primeSuspectBonus,
+    required this.fullScoringMaxRemaining,
+  });
+  final bool referenceMode;
+  /// Add the strategic "killer" words to the candidates of `RemainingPlusStrategic`
+  final bool includeKillerWords;
+  /// Maximum number of candidates scored per guess
+  final int candidateCap;
+  /// Stop scoring once a new best guess reaches `early_termination_threshold` bits
+  final bool earlyTerminationEnabled;
+  final double earlyTerminationThreshold;
+  final bool entropyOnlyScoring;
+  /// Reject unknown pattern tokens instead of treating them as Gray
+  final bool strictPatterns;
+  /// Remove previously guessed words from possible answers and candidates
+  final bool excludePriorGuesses;
+  /// Favor words with five distinct letters for the first two guesses
+  final bool preferUniqueLetters;
+  /// Pool of words the solver scores as guesses
+  final CandidateSource candidateSource;
+  /// Score bonus for candidates that could still be the answer (0.0 to disable)
+  final double primeSuspectBonus;
+  /// Largest remaining-word count that gets full entropy scoring; above it the
+  /// strategic shortlist is ranked by letter frequency instead
+  final int fullScoringMaxRemaining;
 
   @override
-  int get hashCode => words.hashCode;
+  int get hashCode =>
+      referenceMode.hashCode ^
+      includeKillerWords.hashCode ^
+      candidateCap.hashCode ^
+      earlyTerminationEnabled.hashCode ^
+      earlyTerminationThreshold.hashCode ^
+      entropyOnlyScoring.hashCode ^
+      strictPatterns.hashCode ^
+      excludePriorGuesses.hashCode ^
+      preferUniqueLetters.hashCode ^
+      candidateSource.hashCode ^
+      primeSuspectBonus.hashCode ^
+      fullScoringMaxRemaining.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is IntelligentSolver &&
+      other is SolverConfig &&
           runtimeType == other.runtimeType &&
-          words == other.words;
-}
-
-/// FFI-compatible enum for letter results
-enum LetterResult { gray, yellow, green }
-
-class LetterResultArray5 extends NonGrowableListView<LetterResult> {
-  LetterResultArray5(this._inner)
-    : assert(_inner.length == arraySize),
-      super(_inner);
-
-  LetterResultArray5.init(LetterResult fill)
-    : this(List<LetterResult>.filled(arraySize, fill));
-  static const arraySize = 5;
-
-  @internal
-  List<LetterResult> get inner => _inner;
-  final List<LetterResult> _inner;
+          referenceMode == other.referenceMode &&
+          includeKillerWords == other.includeKillerWords &&
+          candidateCap == other.candidateCap &&
+          earlyTerminationEnabled == other.earlyTerminationEnabled &&
+          earlyTerminationThreshold == other.earlyTerminationThreshold &&
+          entropyOnlyScoring == other.entropyOnlyScoring &&
+          strictPatterns == other.strictPatterns &&
+          excludePriorGuesses == other.excludePriorGuesses &&
+          preferUniqueLetters == other.preferUniqueLetters &&
+          candidateSource == other.candidateSource &&
+          primeSuspectBonus == other.primeSuspectBonus &&
+          fullScoringMaxRemaining == other.fullScoringMaxRemaining;
 }
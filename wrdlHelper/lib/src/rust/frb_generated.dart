@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 573625308;
+  int get rustContentHash => 2002494059;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,127 +77,166 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  List<String> crateApiWrdlHelperWordManagerAutoAccessorGetAnswerWords({
-    required WordManager that,
+  double crateApiSimpleCalculateEntropy({
+    required String candidateWord,
+    required List<String> remainingWords,
   });
 
-  List<String> crateApiWrdlHelperWordManagerAutoAccessorGetGuessWords({
-    required WordManager that,
+  double crateApiSimpleCalculateEntropyForState({
+    required String candidateWord,
+    required List<(String, List<String>)> guessResults,
   });
 
-  String? crateApiWrdlHelperWordManagerAutoAccessorGetOptimalFirstGuess({
-    required WordManager that,
-  });
+  double crateApiSimpleEvaluateOpener({required String opener});
 
-  void crateApiWrdlHelperWordManagerAutoAccessorSetAnswerWords({
-    required WordManager that,
-    required List<String> answerWords,
-  });
+  String crateApiSimpleExportWordListsJson();
 
-  void crateApiWrdlHelperWordManagerAutoAccessorSetGuessWords({
-    required WordManager that,
-    required List<String> guessWords,
+  Stream<String> crateApiSimpleFilterWordsStreamed({
+    required List<String> words,
+    required List<(String, List<String>)> guessResults,
   });
 
-  void crateApiWrdlHelperWordManagerAutoAccessorSetOptimalFirstGuess({
-    required WordManager that,
-    String? optimalFirstGuess,
+  String? crateApiSimpleGetBestGuess({
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<void> crateApiWrdlHelperWordManagerComputeOptimalFirstGuess({
-    required WordManager that,
+  String? crateApiSimpleGetBestGuessForRemaining({
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<void> crateApiWrdlHelperWordManagerGetAnswerWords({
-    required WordManager that,
+  String? crateApiSimpleGetBestGuessMultiboard({
+    required List<List<(String, List<String>)>> boards,
   });
 
-  Future<void> crateApiWrdlHelperWordManagerGetGuessWords({
-    required WordManager that,
+  String? crateApiSimpleGetBestGuessWithBudget({
+    required List<(String, List<String>)> guessResults,
+    required int guessesRemaining,
   });
 
-  Future<String?> crateApiWrdlHelperWordManagerGetOptimalFirstGuess({
-    required WordManager that,
+  String? crateApiSimpleGetBestWinningGuess({
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<void> crateApiWrdlHelperWordManagerLoadWords({
-    required WordManager that,
+  String? crateApiSimpleGetDailyAnswer({required int dayNumber});
+
+  String? crateApiSimpleGetIntelligentGuess({
+    required List<String> allWords,
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<WordManager> crateApiWrdlHelperWordManagerNew();
+  String? crateApiSimpleGetIntelligentGuessFast({
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
+  });
 
-  double crateApiSimpleCalculateEntropy({
-    required String candidateWord,
+  String? crateApiSimpleGetIntelligentGuessReference({
     required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
   });
 
-  String? crateApiSimpleGetBestGuess({
+  String? crateApiSimpleGetLastError();
+
+  String? crateApiSimpleGetLetterHint({
     required List<(String, List<String>)> guessResults,
   });
 
-  String? crateApiSimpleGetIntelligentGuess({
-    required List<String> allWords,
-    required List<String> remainingWords,
+  int crateApiSimpleGetPossibleWordCount({
     required List<(String, List<String>)> guessResults,
   });
 
-  String? crateApiSimpleGetIntelligentGuessFast({
-    required List<String> remainingWords,
+  List<String> crateApiSimpleGetPossibleWords({
     required List<(String, List<String>)> guessResults,
   });
 
-  String? crateApiSimpleGetIntelligentGuessReference({
-    required List<String> remainingWords,
+  (List<String>, bool, int) crateApiSimpleGetPossibleWordsCapped({
     required List<(String, List<String>)> guessResults,
+    required int max,
   });
 
-  Future<GuessResult> crateApiWrdlHelperGuessResultNew({
-    required String word,
-    required LetterResultArray5 results,
+  List<String> crateApiSimpleGetPossibleWordsIn({
+    required List<String> universe,
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<void> crateApiSimpleInitApp();
+  (String, String) crateApiSimpleGetSolverInfo();
 
-  Future<double> crateApiWrdlHelperIntelligentSolverCalculateEntropy({
-    required IntelligentSolver that,
-    required String candidateWord,
-    required List<String> remainingWords,
+  (String?, String?) crateApiSimpleGetTopTwoGuesses({
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<double> crateApiWrdlHelperIntelligentSolverCalculateStatisticalScore({
-    required IntelligentSolver that,
-    required String candidateWord,
-    required List<String> remainingWords,
+  List<(String, int, double)> crateApiSimpleGuessOutcomeDistribution({
+    required String guess,
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<List<String>> crateApiWrdlHelperIntelligentSolverFilterWords({
-    required IntelligentSolver that,
-    required List<String> words,
-    required List<GuessResult> guessResults,
+  List<(String, List<String>)> crateApiSimpleGuessPatternBuckets({
+    required String candidate,
+    required List<(String, List<String>)> guessResults,
+    required int maxWordsPerBucket,
   });
 
-  Future<String?> crateApiWrdlHelperIntelligentSolverGetBestGuess({
-    required IntelligentSolver that,
-    required List<String> remainingWords,
-    required List<GuessResult> guessResults,
+  (String?, String?, bool) crateApiSimpleGuessesAgree({
+    required List<(String, List<String>)> guessResults,
+    required SolverConfig configA,
+    required SolverConfig configB,
   });
 
-  Future<IntelligentSolver> crateApiWrdlHelperIntelligentSolverNew({
-    required List<String> words,
+  void crateApiSimpleImportWordListsJson({required String json});
+
+  (int, int) crateApiSimpleInformationGained({
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<String> crateApiWrdlHelperIntelligentSolverSimulateGuessPattern({
-    required IntelligentSolver that,
-    required String guess,
-    required String target,
+  Future<void> crateApiSimpleInitApp();
+
+  void crateApiSimpleInitializeWordLists();
+
+  String? crateApiSimpleIsGameSolved({
+    required List<(String, List<String>)> guessResults,
   });
 
-  Future<bool> crateApiWrdlHelperIntelligentSolverWordMatchesPattern({
-    required IntelligentSolver that,
+  bool crateApiSimpleIsWordStillPossible({
     required String word,
-    required GuessResult guessResult,
+    required List<(String, List<String>)> guessResults,
+  });
+
+  int crateApiSimpleLastGuessCappedCount();
+
+  bool crateApiSimpleLastGuessWasCapped();
+
+  String? crateApiSimpleNewGame();
+
+  double crateApiSimplePreviewGuess({
+    required String candidate,
+    required List<(String, List<String>)> guessResults,
+  });
+
+  Future<List<(String, double)>> crateApiSimpleRankOpeners({
+    required List<String> candidates,
+    required int topN,
+  });
+
+  String? crateApiSimpleRecomputeOptimalFirstGuess();
+
+  List<String> crateApiSimpleReconstructRemainingFromEmoji({
+    required List<String> guesses,
+    required List<String> emojiRows,
   });
 
+  void crateApiSimpleResetSolverConfig();
+
+  void crateApiSimpleSetCandidateSource({required CandidateSource source});
+
+  void crateApiSimpleSetExcludePriorGuesses({required bool enabled});
+
+  void crateApiSimpleSetFullScoringMaxRemaining({required int maxRemaining});
+
+  void crateApiSimpleSetPreferUniqueLetters({required bool enabled});
+
+  void crateApiSimpleSetPrimeSuspectBonus({required double bonus});
+
   void crateApiSimpleSetSolverConfig({
     required bool referenceMode,
     required bool includeKillerWords,
@@ -207,18 +246,36 @@ abstract class RustLibApi extends BaseApi {
     required bool entropyOnlyScoring,
   });
 
+  void crateApiSimpleSetSolverDifficulty({required String level});
+
+  void crateApiSimpleSetStrictPatterns({required bool enabled});
+
+  void crateApiSimpleSetWordListDir({required String path});
+
   String crateApiSimpleSimulateGuessPattern({
     required String guess,
     required String target,
   });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_WordManager;
+  List<String> crateApiSimpleSimulateGuessPatternResults({
+    required String guess,
+    required String target,
+  });
+
+  List<(String, String, double)> crateApiSimpleSolveWithExplanations({
+    required String target,
+    required int maxGuesses,
+  });
+
+  List<bool> crateApiSimpleValidateWords({required List<String> words});
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_WordManager;
+  void crateApiSimpleWarmUp();
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_WordManagerPtr;
+  bool crateApiSimpleWordMatchesFeedback({
+    required String candidate,
+    required String guessWord,
+    required List<String> pattern,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -230,865 +287,1174 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  List<String> crateApiWrdlHelperWordManagerAutoAccessorGetAnswerWords({
-    required WordManager that,
+  double crateApiSimpleCalculateEntropy({
+    required String candidateWord,
+    required List<String> remainingWords,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
+        sse_encode_String(candidateWord, serializer);
+        sse_encode_list_String(remainingWords, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_f_64,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorGetAnswerWordsConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleCalculateEntropyConstMeta,
+      argValues: [candidateWord, remainingWords],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorGetAnswerWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleCalculateEntropyConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_get_answer_words',
-        argNames: ['that'],
+        debugName: 'calculate_entropy',
+        argNames: ['candidateWord', 'remainingWords'],
       );
 
   @override
-  List<String> crateApiWrdlHelperWordManagerAutoAccessorGetGuessWords({
-    required WordManager that,
+  double crateApiSimpleCalculateEntropyForState({
+    required String candidateWord,
+    required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
+        sse_encode_String(candidateWord, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_f_64,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorGetGuessWordsConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleCalculateEntropyForStateConstMeta,
+      argValues: [candidateWord, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorGetGuessWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleCalculateEntropyForStateConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_get_guess_words',
-        argNames: ['that'],
+        debugName: 'calculate_entropy_for_state',
+        argNames: ['candidateWord', 'guessResults'],
       );
 
   @override
-  String? crateApiWrdlHelperWordManagerAutoAccessorGetOptimalFirstGuess({
-    required WordManager that,
+  double crateApiSimpleEvaluateOpener({
+    required String opener,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
+        sse_encode_String(opener, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_f_64,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorGetOptimalFirstGuessConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleEvaluateOpenerConstMeta,
+      argValues: [opener],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorGetOptimalFirstGuessConstMeta =>
-      const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_get_optimal_first_guess',
-        argNames: ['that'],
-      );
+  TaskConstMeta get kCrateApiSimpleEvaluateOpenerConstMeta =>
+      const TaskConstMeta(debugName: 'evaluate_opener', argNames: ['opener']);
 
   @override
-  void crateApiWrdlHelperWordManagerAutoAccessorSetAnswerWords({
-    required WordManager that,
-    required List<String> answerWords,
-  }) => handler.executeSync(
+  String crateApiSimpleExportWordListsJson() => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        sse_encode_list_String(answerWords, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorSetAnswerWordsConstMeta,
-      argValues: [that, answerWords],
+      constMeta: kCrateApiSimpleExportWordListsJsonConstMeta,
+      argValues: [],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorSetAnswerWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleExportWordListsJsonConstMeta =>
+      const TaskConstMeta(debugName: 'export_word_lists_json', argNames: []);
+
+  @override
+  Stream<String> crateApiSimpleFilterWordsStreamed({
+    required List<String> words,
+    required List<(String, List<String>)> guessResults,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(words, serializer);
+            sse_encode_list_record_string_list_string(guessResults, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 5,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiSimpleFilterWordsStreamedConstMeta,
+          argValues: [words, guessResults, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleFilterWordsStreamedConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_set_answer_words',
-        argNames: ['that', 'answerWords'],
+        debugName: 'filter_words_streamed',
+        argNames: ['words', 'guessResults', 'sink'],
       );
 
   @override
-  void crateApiWrdlHelperWordManagerAutoAccessorSetGuessWords({
-    required WordManager that,
-    required List<String> guessWords,
+  String? crateApiSimpleGetBestGuess({
+    required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        sse_encode_list_String(guessWords, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorSetGuessWordsConstMeta,
-      argValues: [that, guessWords],
+      constMeta: kCrateApiSimpleGetBestGuessConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorSetGuessWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetBestGuessConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_set_guess_words',
-        argNames: ['that', 'guessWords'],
+        debugName: 'get_best_guess',
+        argNames: ['guessResults'],
       );
 
   @override
-  void crateApiWrdlHelperWordManagerAutoAccessorSetOptimalFirstGuess({
-    required WordManager that,
-    String? optimalFirstGuess,
+  String? crateApiSimpleGetBestGuessForRemaining({
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        sse_encode_opt_String(optimalFirstGuess, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        sse_encode_list_String(remainingWords, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerAutoAccessorSetOptimalFirstGuessConstMeta,
-      argValues: [that, optimalFirstGuess],
+      constMeta: kCrateApiSimpleGetBestGuessForRemainingConstMeta,
+      argValues: [remainingWords, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerAutoAccessorSetOptimalFirstGuessConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetBestGuessForRemainingConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_auto_accessor_set_optimal_first_guess',
-        argNames: ['that', 'optimalFirstGuess'],
+        debugName: 'get_best_guess_for_remaining',
+        argNames: ['remainingWords', 'guessResults'],
       );
 
   @override
-  Future<void> crateApiWrdlHelperWordManagerComputeOptimalFirstGuess({
-    required WordManager that,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetBestGuessMultiboard({
+    required List<List<(String, List<String>)>> boards,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 7,
-          port: port_,
-        );
+        sse_encode_list_list_record_string_list_string(boards, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperWordManagerComputeOptimalFirstGuessConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleGetBestGuessMultiboardConstMeta,
+      argValues: [boards],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerComputeOptimalFirstGuessConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetBestGuessMultiboardConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_compute_optimal_first_guess',
-        argNames: ['that'],
+        debugName: 'get_best_guess_multiboard',
+        argNames: ['boards'],
       );
 
   @override
-  Future<void> crateApiWrdlHelperWordManagerGetAnswerWords({
-    required WordManager that,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetBestGuessWithBudget({
+    required List<(String, List<String>)> guessResults,
+    required int guessesRemaining,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 8,
-          port: port_,
-        );
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        sse_encode_i_32(guessesRemaining, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperWordManagerGetAnswerWordsConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleGetBestGuessWithBudgetConstMeta,
+      argValues: [guessResults, guessesRemaining],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperWordManagerGetAnswerWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetBestGuessWithBudgetConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_get_answer_words',
-        argNames: ['that'],
+        debugName: 'get_best_guess_with_budget',
+        argNames: ['guessResults', 'guessesRemaining'],
       );
 
   @override
-  Future<void> crateApiWrdlHelperWordManagerGetGuessWords({
-    required WordManager that,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetBestWinningGuess({
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 9,
-          port: port_,
-        );
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperWordManagerGetGuessWordsConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleGetBestWinningGuessConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperWordManagerGetGuessWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetBestWinningGuessConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_get_guess_words',
-        argNames: ['that'],
+        debugName: 'get_best_winning_guess',
+        argNames: ['guessResults'],
       );
 
   @override
-  Future<String?> crateApiWrdlHelperWordManagerGetOptimalFirstGuess({
-    required WordManager that,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetDailyAnswer({
+    required int dayNumber,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 10,
-          port: port_,
-        );
+        sse_encode_i_32(dayNumber, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperWordManagerGetOptimalFirstGuessConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleGetDailyAnswerConstMeta,
+      argValues: [dayNumber],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperWordManagerGetOptimalFirstGuessConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetDailyAnswerConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_get_optimal_first_guess',
-        argNames: ['that'],
+        debugName: 'get_daily_answer',
+        argNames: ['dayNumber'],
       );
 
   @override
-  Future<void> crateApiWrdlHelperWordManagerLoadWords({
-    required WordManager that,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetIntelligentGuess({
+    required List<String> allWords,
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-          that,
-          serializer,
-        );
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 11,
-          port: port_,
-        );
+        sse_encode_list_String(allWords, serializer);
+        sse_encode_list_String(remainingWords, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperWordManagerLoadWordsConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiSimpleGetIntelligentGuessConstMeta,
+      argValues: [allWords, remainingWords, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperWordManagerLoadWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessConstMeta =>
       const TaskConstMeta(
-        debugName: 'WordManager_load_words',
-        argNames: ['that'],
+        debugName: 'get_intelligent_guess',
+        argNames: ['allWords', 'remainingWords', 'guessResults'],
       );
 
   @override
-  Future<WordManager>
-  crateApiWrdlHelperWordManagerNew() => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  String? crateApiSimpleGetIntelligentGuessFast({
+    required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 12,
-          port: port_,
-        );
+        sse_encode_list_String(remainingWords, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperWordManagerNewConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSimpleGetIntelligentGuessFastConstMeta,
+      argValues: [remainingWords, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperWordManagerNewConstMeta =>
-      const TaskConstMeta(debugName: 'WordManager_new', argNames: []);
+  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessFastConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_intelligent_guess_fast',
+        argNames: ['remainingWords', 'guessResults'],
+      );
 
   @override
-  double crateApiSimpleCalculateEntropy({
-    required String candidateWord,
+  String? crateApiSimpleGetIntelligentGuessReference({
     required List<String> remainingWords,
+    required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(candidateWord, serializer);
         sse_encode_list_String(remainingWords, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleCalculateEntropyConstMeta,
-      argValues: [candidateWord, remainingWords],
+      constMeta: kCrateApiSimpleGetIntelligentGuessReferenceConstMeta,
+      argValues: [remainingWords, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleCalculateEntropyConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessReferenceConstMeta =>
       const TaskConstMeta(
-        debugName: 'calculate_entropy',
-        argNames: ['candidateWord', 'remainingWords'],
+        debugName: 'get_intelligent_guess_reference',
+        argNames: ['remainingWords', 'guessResults'],
       );
 
   @override
-  String? crateApiSimpleGetBestGuess({
+  String? crateApiSimpleGetLastError() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleGetLastErrorConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleGetLastErrorConstMeta =>
+      const TaskConstMeta(debugName: 'get_last_error', argNames: []);
+
+  @override
+  String? crateApiSimpleGetLetterHint({
     required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_list_record_string_list_string(guessResults, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleGetBestGuessConstMeta,
+      constMeta: kCrateApiSimpleGetLetterHintConstMeta,
       argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleGetBestGuessConstMeta => const TaskConstMeta(
-    debugName: 'get_best_guess',
-    argNames: ['guessResults'],
-  );
+  TaskConstMeta get kCrateApiSimpleGetLetterHintConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_letter_hint',
+        argNames: ['guessResults'],
+      );
 
   @override
-  String? crateApiSimpleGetIntelligentGuess({
-    required List<String> allWords,
-    required List<String> remainingWords,
+  int crateApiSimpleGetPossibleWordCount({
     required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(allWords, serializer);
-        sse_encode_list_String(remainingWords, serializer);
         sse_encode_list_record_string_list_string(guessResults, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_i_32,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleGetIntelligentGuessConstMeta,
-      argValues: [allWords, remainingWords, guessResults],
+      constMeta: kCrateApiSimpleGetPossibleWordCountConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetPossibleWordCountConstMeta =>
       const TaskConstMeta(
-        debugName: 'get_intelligent_guess',
-        argNames: ['allWords', 'remainingWords', 'guessResults'],
+        debugName: 'get_possible_word_count',
+        argNames: ['guessResults'],
       );
 
   @override
-  String? crateApiSimpleGetIntelligentGuessFast({
-    required List<String> remainingWords,
+  List<String> crateApiSimpleGetPossibleWords({
     required List<(String, List<String>)> guessResults,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(remainingWords, serializer);
         sse_encode_list_record_string_list_string(guessResults, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_list_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleGetIntelligentGuessFastConstMeta,
-      argValues: [remainingWords, guessResults],
+      constMeta: kCrateApiSimpleGetPossibleWordsConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessFastConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetPossibleWordsConstMeta =>
       const TaskConstMeta(
-        debugName: 'get_intelligent_guess_fast',
-        argNames: ['remainingWords', 'guessResults'],
+        debugName: 'get_possible_words',
+        argNames: ['guessResults'],
       );
 
   @override
-  String? crateApiSimpleGetIntelligentGuessReference({
-    required List<String> remainingWords,
+  (List<String>, bool, int) crateApiSimpleGetPossibleWordsCapped({
     required List<(String, List<String>)> guessResults,
+    required int max,
   }) => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(remainingWords, serializer);
         sse_encode_list_record_string_list_string(guessResults, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        sse_encode_i_32(max, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_record_list_string_bool_i_32,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleGetIntelligentGuessReferenceConstMeta,
-      argValues: [remainingWords, guessResults],
+      constMeta: kCrateApiSimpleGetPossibleWordsCappedConstMeta,
+      argValues: [guessResults, max],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleGetIntelligentGuessReferenceConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetPossibleWordsCappedConstMeta =>
       const TaskConstMeta(
-        debugName: 'get_intelligent_guess_reference',
-        argNames: ['remainingWords', 'guessResults'],
+        debugName: 'get_possible_words_capped',
+        argNames: ['guessResults', 'max'],
       );
 
   @override
-  Future<GuessResult> crateApiWrdlHelperGuessResultNew({
-    required String word,
-    required LetterResultArray5 results,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  List<String> crateApiSimpleGetPossibleWordsIn({
+    required List<String> universe,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(word, serializer);
-        sse_encode_letter_result_array_5(results, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 18,
-          port: port_,
-        );
+        sse_encode_list_String(universe, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_guess_result,
+        decodeSuccessData: sse_decode_list_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperGuessResultNewConstMeta,
-      argValues: [word, results],
+      constMeta: kCrateApiSimpleGetPossibleWordsInConstMeta,
+      argValues: [universe, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperGuessResultNewConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetPossibleWordsInConstMeta =>
       const TaskConstMeta(
-        debugName: 'guess_result_new',
-        argNames: ['word', 'results'],
+        debugName: 'get_possible_words_in',
+        argNames: ['universe', 'guessResults'],
       );
 
   @override
-  Future<void> crateApiSimpleInitApp() => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  (String, String) crateApiSimpleGetSolverInfo() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 19,
-          port: port_,
-        );
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_record_string_string,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
+      constMeta: kCrateApiSimpleGetSolverInfoConstMeta,
       argValues: [],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: 'init_app', argNames: []);
+  TaskConstMeta get kCrateApiSimpleGetSolverInfoConstMeta =>
+      const TaskConstMeta(debugName: 'get_solver_info', argNames: []);
 
   @override
-  Future<double> crateApiWrdlHelperIntelligentSolverCalculateEntropy({
-    required IntelligentSolver that,
-    required String candidateWord,
-    required List<String> remainingWords,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  (String?, String?) crateApiSimpleGetTopTwoGuesses({
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_String(candidateWord, serializer);
-        sse_encode_list_String(remainingWords, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 20,
-          port: port_,
-        );
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
+        decodeSuccessData: sse_decode_record_opt_string_opt_string,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperIntelligentSolverCalculateEntropyConstMeta,
-      argValues: [that, candidateWord, remainingWords],
+      constMeta: kCrateApiSimpleGetTopTwoGuessesConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperIntelligentSolverCalculateEntropyConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGetTopTwoGuessesConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_calculate_entropy',
-        argNames: ['that', 'candidateWord', 'remainingWords'],
+        debugName: 'get_top_two_guesses',
+        argNames: ['guessResults'],
       );
 
   @override
-  Future<double> crateApiWrdlHelperIntelligentSolverCalculateStatisticalScore({
-    required IntelligentSolver that,
-    required String candidateWord,
-    required List<String> remainingWords,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  List<(String, int, double)> crateApiSimpleGuessOutcomeDistribution({
+    required String guess,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_String(candidateWord, serializer);
-        sse_encode_list_String(remainingWords, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 21,
-          port: port_,
-        );
+        sse_encode_String(guess, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
+        decodeSuccessData: sse_decode_list_record_string_i_32_f_64,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperIntelligentSolverCalculateStatisticalScoreConstMeta,
-      argValues: [that, candidateWord, remainingWords],
+      constMeta: kCrateApiSimpleGuessOutcomeDistributionConstMeta,
+      argValues: [guess, guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperIntelligentSolverCalculateStatisticalScoreConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGuessOutcomeDistributionConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_calculate_statistical_score',
-        argNames: ['that', 'candidateWord', 'remainingWords'],
+        debugName: 'guess_outcome_distribution',
+        argNames: ['guess', 'guessResults'],
       );
 
   @override
-  Future<List<String>> crateApiWrdlHelperIntelligentSolverFilterWords({
-    required IntelligentSolver that,
-    required List<String> words,
-    required List<GuessResult> guessResults,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  List<(String, List<String>)> crateApiSimpleGuessPatternBuckets({
+    required String candidate,
+    required List<(String, List<String>)> guessResults,
+    required int maxWordsPerBucket,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_list_String(words, serializer);
-        sse_encode_list_guess_result(guessResults, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 22,
-          port: port_,
-        );
+        sse_encode_String(candidate, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        sse_encode_i_32(maxWordsPerBucket, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_list_record_string_list_string,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperIntelligentSolverFilterWordsConstMeta,
-      argValues: [that, words, guessResults],
+      constMeta: kCrateApiSimpleGuessPatternBucketsConstMeta,
+      argValues: [candidate, guessResults, maxWordsPerBucket],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperIntelligentSolverFilterWordsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGuessPatternBucketsConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_filter_words',
-        argNames: ['that', 'words', 'guessResults'],
+        debugName: 'guess_pattern_buckets',
+        argNames: ['candidate', 'guessResults', 'maxWordsPerBucket'],
       );
 
   @override
-  Future<String?> crateApiWrdlHelperIntelligentSolverGetBestGuess({
-    required IntelligentSolver that,
-    required List<String> remainingWords,
-    required List<GuessResult> guessResults,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  (String?, String?, bool) crateApiSimpleGuessesAgree({
+    required List<(String, List<String>)> guessResults,
+    required SolverConfig configA,
+    required SolverConfig configB,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_list_String(remainingWords, serializer);
-        sse_encode_list_guess_result(guessResults, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 23,
-          port: port_,
-        );
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        sse_encode_solver_config(configA, serializer);
+        sse_encode_solver_config(configB, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_record_opt_string_opt_string_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiWrdlHelperIntelligentSolverGetBestGuessConstMeta,
-      argValues: [that, remainingWords, guessResults],
+      constMeta: kCrateApiSimpleGuessesAgreeConstMeta,
+      argValues: [guessResults, configA, configB],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperIntelligentSolverGetBestGuessConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGuessesAgreeConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_get_best_guess',
-        argNames: ['that', 'remainingWords', 'guessResults'],
+        debugName: 'guesses_agree',
+        argNames: ['guessResults', 'configA', 'configB'],
       );
 
   @override
-  Future<IntelligentSolver> crateApiWrdlHelperIntelligentSolverNew({
-    required List<String> words,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  void crateApiSimpleImportWordListsJson({
+    required String json,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(words, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 24,
-          port: port_,
-        );
+        sse_encode_String(json, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_intelligent_solver,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiWrdlHelperIntelligentSolverNewConstMeta,
-      argValues: [words],
+      constMeta: kCrateApiSimpleImportWordListsJsonConstMeta,
+      argValues: [json],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta get kCrateApiWrdlHelperIntelligentSolverNewConstMeta =>
+  TaskConstMeta get kCrateApiSimpleImportWordListsJsonConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_new',
-        argNames: ['words'],
+        debugName: 'import_word_lists_json',
+        argNames: ['json'],
       );
 
   @override
-  Future<String> crateApiWrdlHelperIntelligentSolverSimulateGuessPattern({
-    required IntelligentSolver that,
-    required String guess,
-    required String target,
-  }) => handler.executeNormal(
-    NormalTask(
-      callFfi: (port_) {
+  (int, int) crateApiSimpleInformationGained({
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_String(guess, serializer);
-        sse_encode_String(target, serializer);
-        pdeCallFfi(
-          generalizedFrbRustBinding,
-          serializer,
-          funcId: 25,
-          port: port_,
-        );
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_record_i_32_i_32,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperIntelligentSolverSimulateGuessPatternConstMeta,
-      argValues: [that, guess, target],
+      constMeta: kCrateApiSimpleInformationGainedConstMeta,
+      argValues: [guessResults],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperIntelligentSolverSimulateGuessPatternConstMeta =>
+  TaskConstMeta get kCrateApiSimpleInformationGainedConstMeta =>
       const TaskConstMeta(
-        debugName: 'intelligent_solver_simulate_guess_pattern',
-        argNames: ['that', 'guess', 'target'],
+        debugName: 'information_gained',
+        argNames: ['guessResults'],
       );
 
   @override
-  Future<bool> crateApiWrdlHelperIntelligentSolverWordMatchesPattern({
-    required IntelligentSolver that,
-    required String word,
-    required GuessResult guessResult,
-  }) => handler.executeNormal(
+  Future<void> crateApiSimpleInitApp() => handler.executeNormal(
     NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_intelligent_solver(that, serializer);
-        sse_encode_String(word, serializer);
-        sse_encode_box_autoadd_guess_result(guessResult, serializer);
         pdeCallFfi(
           generalizedFrbRustBinding,
           serializer,
-          funcId: 26,
+          funcId: 28,
           port: port_,
         );
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta:
-          kCrateApiWrdlHelperIntelligentSolverWordMatchesPatternConstMeta,
-      argValues: [that, word, guessResult],
+      constMeta: kCrateApiSimpleInitAppConstMeta,
+      argValues: [],
       apiImpl: this,
     ),
   );
 
-  TaskConstMeta
-  get kCrateApiWrdlHelperIntelligentSolverWordMatchesPatternConstMeta =>
-      const TaskConstMeta(
-        debugName: 'intelligent_solver_word_matches_pattern',
-        argNames: ['that', 'word', 'guessResult'],
-      );
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: 'init_app', argNames: []);
 
   @override
-  void crateApiSimpleSetSolverConfig({
-    required bool referenceMode,
-    required bool includeKillerWords,
-    required int candidateCap,
-    required bool earlyTerminationEnabled,
-    required double earlyTerminationThreshold,
-    required bool entropyOnlyScoring,
-  }) => handler.executeSync(
+  void crateApiSimpleInitializeWordLists() => handler.executeSync(
     SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(referenceMode, serializer);
-        sse_encode_bool(includeKillerWords, serializer);
-        sse_encode_i_32(candidateCap, serializer);
-        sse_encode_bool(earlyTerminationEnabled, serializer);
-        sse_encode_f_64(earlyTerminationThreshold, serializer);
-        sse_encode_bool(entropyOnlyScoring, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleInitializeWordListsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleInitializeWordListsConstMeta =>
+      const TaskConstMeta(debugName: 'initialize_word_lists', argNames: []);
+
+  @override
+  String? crateApiSimpleIsGameSolved({
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleIsGameSolvedConstMeta,
+      argValues: [guessResults],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleIsGameSolvedConstMeta =>
+      const TaskConstMeta(
+        debugName: 'is_game_solved',
+        argNames: ['guessResults'],
+      );
+
+  @override
+  bool crateApiSimpleIsWordStillPossible({
+    required String word,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(word, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleIsWordStillPossibleConstMeta,
+      argValues: [word, guessResults],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleIsWordStillPossibleConstMeta =>
+      const TaskConstMeta(
+        debugName: 'is_word_still_possible',
+        argNames: ['word', 'guessResults'],
+      );
+
+  @override
+  int crateApiSimpleLastGuessCappedCount() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleLastGuessCappedCountConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleLastGuessCappedCountConstMeta =>
+      const TaskConstMeta(debugName: 'last_guess_capped_count', argNames: []);
+
+  @override
+  bool crateApiSimpleLastGuessWasCapped() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleLastGuessWasCappedConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleLastGuessWasCappedConstMeta =>
+      const TaskConstMeta(debugName: 'last_guess_was_capped', argNames: []);
+
+  @override
+  String? crateApiSimpleNewGame() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleNewGameConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleNewGameConstMeta =>
+      const TaskConstMeta(debugName: 'new_game', argNames: []);
+
+  @override
+  double crateApiSimplePreviewGuess({
+    required String candidate,
+    required List<(String, List<String>)> guessResults,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(candidate, serializer);
+        sse_encode_list_record_string_list_string(guessResults, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimplePreviewGuessConstMeta,
+      argValues: [candidate, guessResults],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimplePreviewGuessConstMeta =>
+      const TaskConstMeta(
+        debugName: 'preview_guess',
+        argNames: ['candidate', 'guessResults'],
+      );
+
+  @override
+  Future<List<(String, double)>> crateApiSimpleRankOpeners({
+    required List<String> candidates,
+    required int topN,
+  }) => handler.executeNormal(
+    NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(candidates, serializer);
+        sse_encode_i_32(topN, serializer);
+        pdeCallFfi(
+          generalizedFrbRustBinding,
+          serializer,
+          funcId: 36,
+          port: port_,
+        );
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_record_string_f_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleRankOpenersConstMeta,
+      argValues: [candidates, topN],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleRankOpenersConstMeta =>
+      const TaskConstMeta(
+        debugName: 'rank_openers',
+        argNames: ['candidates', 'topN'],
+      );
+
+  @override
+  String? crateApiSimpleRecomputeOptimalFirstGuess() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleRecomputeOptimalFirstGuessConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleRecomputeOptimalFirstGuessConstMeta =>
+      const TaskConstMeta(
+        debugName: 'recompute_optimal_first_guess',
+        argNames: [],
+      );
+
+  @override
+  List<String> crateApiSimpleReconstructRemainingFromEmoji({
+    required List<String> guesses,
+    required List<String> emojiRows,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(guesses, serializer);
+        sse_encode_list_String(emojiRows, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleReconstructRemainingFromEmojiConstMeta,
+      argValues: [guesses, emojiRows],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleReconstructRemainingFromEmojiConstMeta =>
+      const TaskConstMeta(
+        debugName: 'reconstruct_remaining_from_emoji',
+        argNames: ['guesses', 'emojiRows'],
+      );
+
+  @override
+  void crateApiSimpleResetSolverConfig() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleResetSolverConfigConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleResetSolverConfigConstMeta =>
+      const TaskConstMeta(debugName: 'reset_solver_config', argNames: []);
+
+  @override
+  void crateApiSimpleSetCandidateSource({
+    required CandidateSource source,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_candidate_source(source, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetCandidateSourceConstMeta,
+      argValues: [source],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetCandidateSourceConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_candidate_source',
+        argNames: ['source'],
+      );
+
+  @override
+  void crateApiSimpleSetExcludePriorGuesses({
+    required bool enabled,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetExcludePriorGuessesConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetExcludePriorGuessesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_exclude_prior_guesses',
+        argNames: ['enabled'],
+      );
+
+  @override
+  void crateApiSimpleSetFullScoringMaxRemaining({
+    required int maxRemaining,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(maxRemaining, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleSetFullScoringMaxRemainingConstMeta,
+      argValues: [maxRemaining],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetFullScoringMaxRemainingConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_full_scoring_max_remaining',
+        argNames: ['maxRemaining'],
+      );
+
+  @override
+  void crateApiSimpleSetPreferUniqueLetters({
+    required bool enabled,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetPreferUniqueLettersConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetPreferUniqueLettersConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_prefer_unique_letters',
+        argNames: ['enabled'],
+      );
+
+  @override
+  void crateApiSimpleSetPrimeSuspectBonus({
+    required double bonus,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_64(bonus, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleSetPrimeSuspectBonusConstMeta,
+      argValues: [bonus],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetPrimeSuspectBonusConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_prime_suspect_bonus',
+        argNames: ['bonus'],
+      );
+
+  @override
+  void crateApiSimpleSetSolverConfig({
+    required bool referenceMode,
+    required bool includeKillerWords,
+    required int candidateCap,
+    required bool earlyTerminationEnabled,
+    required double earlyTerminationThreshold,
+    required bool entropyOnlyScoring,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(referenceMode, serializer);
+        sse_encode_bool(includeKillerWords, serializer);
+        sse_encode_i_32(candidateCap, serializer);
+        sse_encode_bool(earlyTerminationEnabled, serializer);
+        sse_encode_f_64(earlyTerminationThreshold, serializer);
+        sse_encode_bool(entropyOnlyScoring, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_String,
       ),
       constMeta: kCrateApiSimpleSetSolverConfigConstMeta,
       argValues: [
@@ -1116,6 +1482,81 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  @override
+  void crateApiSimpleSetSolverDifficulty({
+    required String level,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(level, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleSetSolverDifficultyConstMeta,
+      argValues: [level],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetSolverDifficultyConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_solver_difficulty',
+        argNames: ['level'],
+      );
+
+  @override
+  void crateApiSimpleSetStrictPatterns({
+    required bool enabled,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetStrictPatternsConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetStrictPatternsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_strict_patterns',
+        argNames: ['enabled'],
+      );
+
+  @override
+  void crateApiSimpleSetWordListDir({
+    required String path,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSetWordListDirConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSetWordListDirConstMeta =>
+      const TaskConstMeta(debugName: 'set_word_list_dir', argNames: ['path']);
+
   @override
   String crateApiSimpleSimulateGuessPattern({
     required String guess,
@@ -1126,7 +1567,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(guess, serializer);
         sse_encode_String(target, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1144,157 +1585,313 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['guess', 'target'],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_WordManager => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager;
+  @override
+  List<String> crateApiSimpleSimulateGuessPatternResults({
+    required String guess,
+    required String target,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(guess, serializer);
+        sse_encode_String(target, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSimulateGuessPatternResultsConstMeta,
+      argValues: [guess, target],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSimulateGuessPatternResultsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'simulate_guess_pattern_results',
+        argNames: ['guess', 'target'],
+      );
+
+  @override
+  List<(String, String, double)> crateApiSimpleSolveWithExplanations({
+    required String target,
+    required int maxGuesses,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(target, serializer);
+        sse_encode_i_32(maxGuesses, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_record_string_string_f_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleSolveWithExplanationsConstMeta,
+      argValues: [target, maxGuesses],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleSolveWithExplanationsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'solve_with_explanations',
+        argNames: ['target', 'maxGuesses'],
+      );
+
+  @override
+  List<bool> crateApiSimpleValidateWords({
+    required List<String> words,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(words, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleValidateWordsConstMeta,
+      argValues: [words],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleValidateWordsConstMeta =>
+      const TaskConstMeta(debugName: 'validate_words', argNames: ['words']);
+
+  @override
+  void crateApiSimpleWarmUp() => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSimpleWarmUpConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleWarmUpConstMeta =>
+      const TaskConstMeta(debugName: 'warm_up', argNames: []);
+
+  @override
+  bool crateApiSimpleWordMatchesFeedback({
+    required String candidate,
+    required String guessWord,
+    required List<String> pattern,
+  }) => handler.executeSync(
+    SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(candidate, serializer);
+        sse_encode_String(guessWord, serializer);
+        sse_encode_list_String(pattern, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSimpleWordMatchesFeedbackConstMeta,
+      argValues: [candidate, guessWord, pattern],
+      apiImpl: this,
+    ),
+  );
+
+  TaskConstMeta get kCrateApiSimpleWordMatchesFeedbackConstMeta =>
+      const TaskConstMeta(
+        debugName: 'word_matches_feedback',
+        argNames: ['candidate', 'guessWord', 'pattern'],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_WordManager => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager;
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
 
   @protected
-  WordManager
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  ) {
+  String dco_decode_String(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WordManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw as String;
   }
 
   @protected
-  WordManager
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  ) {
+  bool dco_decode_bool(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WordManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw as bool;
   }
 
   @protected
-  WordManager
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  ) {
+  CandidateSource dco_decode_candidate_source(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WordManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return CandidateSource.values[raw as int];
   }
 
   @protected
-  WordManager
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  ) {
+  double dco_decode_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WordManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw as double;
   }
 
   @protected
-  String dco_decode_String(raw) {
+  int dco_decode_i_32(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+    return raw as int;
   }
 
   @protected
-  bool dco_decode_bool(raw) {
+  List<String> dco_decode_list_String(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
   @protected
-  GuessResult dco_decode_box_autoadd_guess_result(raw) {
+  List<bool> dco_decode_list_bool(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_guess_result(raw);
+    return (raw as List<dynamic>).map(dco_decode_bool).toList();
   }
 
   @protected
-  IntelligentSolver dco_decode_box_autoadd_intelligent_solver(raw) {
+  List<List<(String, List<String>)>> dco_decode_list_list_record_string_list_string(
+    raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_intelligent_solver(raw);
+    return (raw as List<dynamic>)
+        .map(dco_decode_list_record_string_list_string)
+        .toList();
   }
 
   @protected
-  double dco_decode_f_64(raw) {
+  Uint8List dco_decode_list_prim_u_8_strict(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    return raw as Uint8List;
   }
 
   @protected
-  GuessResult dco_decode_guess_result(raw) {
+  List<(String, double)> dco_decode_list_record_string_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return GuessResult(
-      word: dco_decode_String(arr[0]),
-      results: dco_decode_list_letter_result(arr[1]),
-    );
+    return (raw as List<dynamic>).map(dco_decode_record_string_f_64).toList();
   }
 
   @protected
-  int dco_decode_i_32(raw) {
+  List<(String, int, double)> dco_decode_list_record_string_i_32_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_i_32_f_64)
+        .toList();
   }
 
   @protected
-  IntelligentSolver dco_decode_intelligent_solver(raw) {
+  List<(String, List<String>)> dco_decode_list_record_string_list_string(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return IntelligentSolver(words: dco_decode_list_String(arr[0]));
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_list_string)
+        .toList();
   }
 
   @protected
-  LetterResult dco_decode_letter_result(raw) {
+  List<(String, String, double)> dco_decode_list_record_string_string_f_64(
+    raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LetterResult.values[raw as int];
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_string_f_64)
+        .toList();
   }
 
   @protected
-  LetterResultArray5 dco_decode_letter_result_array_5(raw) {
+  String? dco_decode_opt_String(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LetterResultArray5(
-      (raw as List<dynamic>).map(dco_decode_letter_result).toList(),
-    );
+    return raw == null ? null : dco_decode_String(raw);
   }
 
   @protected
-  List<String> dco_decode_list_String(raw) {
+  (int, int) dco_decode_record_i_32_i_32(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_i_32(arr[0]), dco_decode_i_32(arr[1]));
   }
 
   @protected
-  List<GuessResult> dco_decode_list_guess_result(raw) {
+  (List<String>, bool, int) dco_decode_record_list_string_bool_i_32(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_guess_result).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_list_String(arr[0]),
+      dco_decode_bool(arr[1]),
+      dco_decode_i_32(arr[2]),
+    );
   }
 
   @protected
-  List<LetterResult> dco_decode_list_letter_result(raw) {
+  (String?, String?) dco_decode_record_opt_string_opt_string(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_letter_result).toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_opt_String(arr[0]), dco_decode_opt_String(arr[1]));
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(raw) {
+  (String?, String?, bool) dco_decode_record_opt_string_opt_string_bool(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_opt_String(arr[0]),
+      dco_decode_opt_String(arr[1]),
+      dco_decode_bool(arr[2]),
+    );
   }
 
   @protected
-  List<(String, List<String>)> dco_decode_list_record_string_list_string(raw) {
+  (String, double) dco_decode_record_string_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_list_string)
-        .toList();
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_f_64(arr[1]));
   }
 
   @protected
-  String? dco_decode_opt_String(raw) {
+  (String, int, double) dco_decode_record_string_i_32_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_i_32(arr[1]),
+      dco_decode_f_64(arr[2]),
+    );
   }
 
   @protected
@@ -1308,69 +1905,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int dco_decode_u_8(raw) {
+  (String, String) dco_decode_record_string_string(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
   }
 
   @protected
-  void dco_decode_unit(raw) {
+  (String, String, double) dco_decode_record_string_string_f_64(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_String(arr[1]),
+      dco_decode_f_64(arr[2]),
+    );
   }
 
   @protected
-  BigInt dco_decode_usize(raw) {
+  SolverConfig dco_decode_solver_config(raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return SolverConfig(
+      referenceMode: dco_decode_bool(arr[0]),
+      includeKillerWords: dco_decode_bool(arr[1]),
+      candidateCap: dco_decode_i_32(arr[2]),
+      earlyTerminationEnabled: dco_decode_bool(arr[3]),
+      earlyTerminationThreshold: dco_decode_f_64(arr[4]),
+      entropyOnlyScoring: dco_decode_bool(arr[5]),
+      strictPatterns: dco_decode_bool(arr[6]),
+      excludePriorGuesses: dco_decode_bool(arr[7]),
+      preferUniqueLetters: dco_decode_bool(arr[8]),
+      candidateSource: dco_decode_candidate_source(arr[9]),
+      primeSuspectBonus: dco_decode_f_64(arr[10]),
+      fullScoringMaxRemaining: dco_decode_i_32(arr[11]),
+    );
   }
 
   @protected
-  WordManager
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return WordManagerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  int dco_decode_u_8(raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
   }
 
   @protected
-  WordManager
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return WordManagerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+  void dco_decode_unit(raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
   }
 
   @protected
-  WordManager
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  ) {
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return WordManagerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    final inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
   }
 
   @protected
-  WordManager
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return WordManagerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    throw UnimplementedError('Unreachable ()');
   }
 
   @protected
@@ -1387,121 +1991,121 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  GuessResult sse_decode_box_autoadd_guess_result(
-    SseDeserializer deserializer,
-  ) {
+  CandidateSource sse_decode_candidate_source(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return sse_decode_guess_result(deserializer);
+    final inner = sse_decode_i_32(deserializer);
+    return CandidateSource.values[inner];
   }
 
   @protected
-  IntelligentSolver sse_decode_box_autoadd_intelligent_solver(
-    SseDeserializer deserializer,
-  ) {
+  double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return sse_decode_intelligent_solver(deserializer);
+    return deserializer.buffer.getFloat64();
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    return deserializer.buffer.getInt32();
   }
 
   @protected
-  GuessResult sse_decode_guess_result(SseDeserializer deserializer) {
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_word = sse_decode_String(deserializer);
-    final var_results = sse_decode_list_letter_result(deserializer);
-    return GuessResult(word: var_word, results: var_results);
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <bool>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bool(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  IntelligentSolver sse_decode_intelligent_solver(
+  List<List<(String, List<String>)>> sse_decode_list_list_record_string_list_string(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_words = sse_decode_list_String(deserializer);
-    return IntelligentSolver(words: var_words);
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <List<(String, List<String>)>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_record_string_list_string(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  LetterResult sse_decode_letter_result(SseDeserializer deserializer) {
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return LetterResult.values[inner];
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
   }
 
   @protected
-  LetterResultArray5 sse_decode_letter_result_array_5(
+  List<(String, double)> sse_decode_list_record_string_f_64(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_letter_result(deserializer);
-    return LetterResultArray5(inner);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <String>[];
+    final ans_ = <(String, double)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+      ans_.add(sse_decode_record_string_f_64(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<GuessResult> sse_decode_list_guess_result(SseDeserializer deserializer) {
+  List<(String, int, double)> sse_decode_list_record_string_i_32_f_64(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <GuessResult>[];
+    final ans_ = <(String, int, double)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_guess_result(deserializer));
+      ans_.add(sse_decode_record_string_i_32_f_64(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<LetterResult> sse_decode_list_letter_result(
+  List<(String, List<String>)> sse_decode_list_record_string_list_string(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <LetterResult>[];
+    final ans_ = <(String, List<String>)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_letter_result(deserializer));
+      ans_.add(sse_decode_record_string_list_string(deserializer));
     }
     return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, List<String>)> sse_decode_list_record_string_list_string(
+  List<(String, String, double)> sse_decode_list_record_string_string_f_64(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <(String, List<String>)>[];
+    final ans_ = <(String, String, double)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_list_string(deserializer));
+      ans_.add(sse_decode_record_string_string_f_64(deserializer));
     }
     return ans_;
   }
@@ -1518,155 +2122,195 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  (String, List<String>) sse_decode_record_string_list_string(
+  (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_field0 = sse_decode_i_32(deserializer);
+    final var_field1 = sse_decode_i_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (List<String>, bool, int) sse_decode_record_list_string_bool_i_32(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 = sse_decode_String(deserializer);
-    final var_field1 = sse_decode_list_String(deserializer);
-    return (var_field0, var_field1);
+    final var_field0 = sse_decode_list_String(deserializer);
+    final var_field1 = sse_decode_bool(deserializer);
+    final var_field2 = sse_decode_i_32(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  (String?, String?) sse_decode_record_opt_string_opt_string(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    final var_field0 = sse_decode_opt_String(deserializer);
+    final var_field1 = sse_decode_opt_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  (String?, String?, bool) sse_decode_record_opt_string_opt_string_bool(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_field0 = sse_decode_opt_String(deserializer);
+    final var_field1 = sse_decode_opt_String(deserializer);
+    final var_field2 = sse_decode_bool(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  (String, double) sse_decode_record_string_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_f_64(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, int, double) sse_decode_record_string_i_32_f_64(
+    SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as WordManagerImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_i_32(deserializer);
+    final var_field2 = sse_decode_f_64(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, List<String>) sse_decode_record_string_list_string(
+    SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as WordManagerImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_list_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as WordManagerImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, String, double) sse_decode_record_string_string_f_64(
+    SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as WordManagerImpl).frbInternalSseEncode(),
-      serializer,
-    );
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_String(deserializer);
+    final var_field2 = sse_decode_f_64(deserializer);
+    return (var_field0, var_field1, var_field2);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  SolverConfig sse_decode_solver_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    final var_referenceMode = sse_decode_bool(deserializer);
+    final var_includeKillerWords = sse_decode_bool(deserializer);
+    final var_candidateCap = sse_decode_i_32(deserializer);
+    final var_earlyTerminationEnabled = sse_decode_bool(deserializer);
+    final var_earlyTerminationThreshold = sse_decode_f_64(deserializer);
+    final var_entropyOnlyScoring = sse_decode_bool(deserializer);
+    final var_strictPatterns = sse_decode_bool(deserializer);
+    final var_excludePriorGuesses = sse_decode_bool(deserializer);
+    final var_preferUniqueLetters = sse_decode_bool(deserializer);
+    final var_candidateSource = sse_decode_candidate_source(deserializer);
+    final var_primeSuspectBonus = sse_decode_f_64(deserializer);
+    final var_fullScoringMaxRemaining = sse_decode_i_32(deserializer);
+    return SolverConfig(
+      referenceMode: var_referenceMode,
+      includeKillerWords: var_includeKillerWords,
+      candidateCap: var_candidateCap,
+      earlyTerminationEnabled: var_earlyTerminationEnabled,
+      earlyTerminationThreshold: var_earlyTerminationThreshold,
+      entropyOnlyScoring: var_entropyOnlyScoring,
+      strictPatterns: var_strictPatterns,
+      excludePriorGuesses: var_excludePriorGuesses,
+      preferUniqueLetters: var_preferUniqueLetters,
+      candidateSource: var_candidateSource,
+      primeSuspectBonus: var_primeSuspectBonus,
+      fullScoringMaxRemaining: var_fullScoringMaxRemaining,
+    );
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  void sse_encode_box_autoadd_guess_result(
-    GuessResult self,
-    SseSerializer serializer,
-  ) {
+  void sse_decode_unit(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_guess_result(self, serializer);
+
   }
 
   @protected
-  void sse_encode_box_autoadd_intelligent_solver(
-    IntelligentSolver self,
+  void sse_encode_AnyhowException(
+    AnyhowException self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_intelligent_solver(self, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  void sse_encode_guess_result(GuessResult self, SseSerializer serializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.word, serializer);
-    sse_encode_list_letter_result(self.results, serializer);
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  void sse_encode_intelligent_solver(
-    IntelligentSolver self,
+  void sse_encode_candidate_source(
+    CandidateSource self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_String(self.words, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_letter_result(LetterResult self, SseSerializer serializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void sse_encode_letter_result_array_5(
-    LetterResultArray5 self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_letter_result(self.inner, serializer);
+    serializer.buffer.putInt32(self);
   }
 
   @protected
@@ -1679,26 +2323,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_list_guess_result(
-    List<GuessResult> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_guess_result(item, serializer);
+      sse_encode_bool(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_letter_result(
-    List<LetterResult> self,
+  void sse_encode_list_list_record_string_list_string(
+    List<List<(String, List<String>)>> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_letter_result(item, serializer);
+      sse_encode_list_record_string_list_string(item, serializer);
     }
   }
 
@@ -1712,6 +2353,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_record_string_f_64(
+    List<(String, double)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_f_64(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_record_string_i_32_f_64(
+    List<(String, int, double)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_i_32_f_64(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_record_string_list_string(
     List<(String, List<String>)> self,
@@ -1724,6 +2389,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_record_string_string_f_64(
+    List<(String, String, double)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string_f_64(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1735,97 +2412,122 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_record_string_list_string(
-    (String, List<String>) self,
+  void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.$1, serializer);
+    sse_encode_i_32(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_record_list_string_bool_i_32(
+    (List<String>, bool, int) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_list_String(self.$2, serializer);
+    sse_encode_list_String(self.$1, serializer);
+    sse_encode_bool(self.$2, serializer);
+    sse_encode_i_32(self.$3, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_record_opt_string_opt_string(
+    (String?, String?) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_opt_String(self.$1, serializer);
+    sse_encode_opt_String(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_record_opt_string_opt_string_bool(
+    (String?, String?, bool) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.$1, serializer);
+    sse_encode_opt_String(self.$2, serializer);
+    sse_encode_bool(self.$3, serializer);
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_record_string_f_64(
+    (String, double) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_f_64(self.$2, serializer);
   }
-}
-
-@sealed
-class WordManagerImpl extends RustOpaque implements WordManager {
-  // Not to be used by end users
-  WordManagerImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  WordManagerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_WordManager,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_WordManager,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_WordManagerPtr,
-  );
-
-  List<String> get answerWords => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorGetAnswerWords(that: this);
-
-  List<String> get guessWords => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorGetGuessWords(that: this);
-
-  String? get optimalFirstGuess => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorGetOptimalFirstGuess(
-        that: this,
-      );
 
-  set answerWords(List<String> answerWords) => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorSetAnswerWords(
-        that: this,
-        answerWords: answerWords,
-      );
+  @protected
+  void sse_encode_record_string_i_32_f_64(
+    (String, int, double) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_i_32(self.$2, serializer);
+    sse_encode_f_64(self.$3, serializer);
+  }
 
-  set guessWords(List<String> guessWords) => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorSetGuessWords(
-        that: this,
-        guessWords: guessWords,
-      );
+  @protected
+  void sse_encode_record_string_list_string(
+    (String, List<String>) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_list_String(self.$2, serializer);
+  }
 
-  set optimalFirstGuess(String? optimalFirstGuess) => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerAutoAccessorSetOptimalFirstGuess(
-        that: this,
-        optimalFirstGuess: optimalFirstGuess,
-      );
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+  }
 
-  /// Compute the optimal first guess once at startup
-  ///
-  /// Uses proven optimal first guesses from statistical analysis.
-  /// No computation needed - these are already known to be optimal!
-  Future<void> computeOptimalFirstGuess() => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerComputeOptimalFirstGuess(that: this);
+  @protected
+  void sse_encode_record_string_string_f_64(
+    (String, String, double) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+    sse_encode_f_64(self.$3, serializer);
+  }
 
-  Future<void> getAnswerWords() => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerGetAnswerWords(that: this);
+  @protected
+  void sse_encode_solver_config(SolverConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.referenceMode, serializer);
+    sse_encode_bool(self.includeKillerWords, serializer);
+    sse_encode_i_32(self.candidateCap, serializer);
+    sse_encode_bool(self.earlyTerminationEnabled, serializer);
+    sse_encode_f_64(self.earlyTerminationThreshold, serializer);
+    sse_encode_bool(self.entropyOnlyScoring, serializer);
+    sse_encode_bool(self.strictPatterns, serializer);
+    sse_encode_bool(self.excludePriorGuesses, serializer);
+    sse_encode_bool(self.preferUniqueLetters, serializer);
+    sse_encode_candidate_source(self.candidateSource, serializer);
+    sse_encode_f_64(self.primeSuspectBonus, serializer);
+    sse_encode_i_32(self.fullScoringMaxRemaining, serializer);
+  }
 
-  Future<void> getGuessWords() => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerGetGuessWords(that: this);
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
+  }
 
-  Future<String?> getOptimalFirstGuess() => RustLib.instance.api
-      .crateApiWrdlHelperWordManagerGetOptimalFirstGuess(that: this);
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  Future<void> loadWords() =>
-      RustLib.instance.api.crateApiWrdlHelperWordManagerLoadWords(that: this);
+  }
 }
@@ -19,115 +19,97 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_WordManagerPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManagerPtr;
+  @protected
+  AnyhowException dco_decode_AnyhowException(raw);
 
   @protected
-  WordManager
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  );
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(raw);
 
   @protected
-  WordManager
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  );
+  String dco_decode_String(raw);
 
   @protected
-  WordManager
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  );
+  bool dco_decode_bool(raw);
 
   @protected
-  WordManager
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    raw,
-  );
+  CandidateSource dco_decode_candidate_source(raw);
 
   @protected
-  String dco_decode_String(raw);
+  double dco_decode_f_64(raw);
 
   @protected
-  bool dco_decode_bool(raw);
+  int dco_decode_i_32(raw);
 
   @protected
-  GuessResult dco_decode_box_autoadd_guess_result(raw);
+  List<String> dco_decode_list_String(raw);
 
   @protected
-  IntelligentSolver dco_decode_box_autoadd_intelligent_solver(raw);
+  List<bool> dco_decode_list_bool(raw);
 
   @protected
-  double dco_decode_f_64(raw);
+  List<List<(String, List<String>)>> dco_decode_list_list_record_string_list_string(
+    raw,
+  );
 
   @protected
-  GuessResult dco_decode_guess_result(raw);
+  Uint8List dco_decode_list_prim_u_8_strict(raw);
 
   @protected
-  int dco_decode_i_32(raw);
+  List<(String, double)> dco_decode_list_record_string_f_64(raw);
 
   @protected
-  IntelligentSolver dco_decode_intelligent_solver(raw);
+  List<(String, int, double)> dco_decode_list_record_string_i_32_f_64(raw);
 
   @protected
-  LetterResult dco_decode_letter_result(raw);
+  List<(String, List<String>)> dco_decode_list_record_string_list_string(raw);
 
   @protected
-  LetterResultArray5 dco_decode_letter_result_array_5(raw);
+  List<(String, String, double)> dco_decode_list_record_string_string_f_64(raw);
 
   @protected
-  List<String> dco_decode_list_String(raw);
+  String? dco_decode_opt_String(raw);
 
   @protected
-  List<GuessResult> dco_decode_list_guess_result(raw);
+  (int, int) dco_decode_record_i_32_i_32(raw);
 
   @protected
-  List<LetterResult> dco_decode_list_letter_result(raw);
+  (List<String>, bool, int) dco_decode_record_list_string_bool_i_32(raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(raw);
+  (String?, String?) dco_decode_record_opt_string_opt_string(raw);
 
   @protected
-  List<(String, List<String>)> dco_decode_list_record_string_list_string(raw);
+  (String?, String?, bool) dco_decode_record_opt_string_opt_string_bool(raw);
 
   @protected
-  String? dco_decode_opt_String(raw);
+  (String, double) dco_decode_record_string_f_64(raw);
+
+  @protected
+  (String, int, double) dco_decode_record_string_i_32_f_64(raw);
 
   @protected
   (String, List<String>) dco_decode_record_string_list_string(raw);
 
   @protected
-  int dco_decode_u_8(raw);
+  (String, String) dco_decode_record_string_string(raw);
 
   @protected
-  void dco_decode_unit(raw);
+  (String, String, double) dco_decode_record_string_string_f_64(raw);
 
   @protected
-  BigInt dco_decode_usize(raw);
+  SolverConfig dco_decode_solver_config(raw);
 
   @protected
-  WordManager
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_u_8(raw);
 
   @protected
-  WordManager
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  );
+  void dco_decode_unit(raw);
 
   @protected
-  WordManager
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    SseDeserializer deserializer,
-  );
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  WordManager
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
     SseDeserializer deserializer,
   );
 
@@ -138,94 +120,110 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  GuessResult sse_decode_box_autoadd_guess_result(SseDeserializer deserializer);
+  CandidateSource sse_decode_candidate_source(SseDeserializer deserializer);
 
   @protected
-  IntelligentSolver sse_decode_box_autoadd_intelligent_solver(
-    SseDeserializer deserializer,
-  );
+  double sse_decode_f_64(SseDeserializer deserializer);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  GuessResult sse_decode_guess_result(SseDeserializer deserializer);
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer);
 
   @protected
-  IntelligentSolver sse_decode_intelligent_solver(SseDeserializer deserializer);
+  List<List<(String, List<String>)>> sse_decode_list_list_record_string_list_string(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  LetterResult sse_decode_letter_result(SseDeserializer deserializer);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  LetterResultArray5 sse_decode_letter_result_array_5(
+  List<(String, double)> sse_decode_list_record_string_f_64(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  List<(String, int, double)> sse_decode_list_record_string_i_32_f_64(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<GuessResult> sse_decode_list_guess_result(SseDeserializer deserializer);
+  List<(String, List<String>)> sse_decode_list_record_string_list_string(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<LetterResult> sse_decode_list_letter_result(
+  List<(String, String, double)> sse_decode_list_record_string_string_f_64(
     SseDeserializer deserializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  String? sse_decode_opt_String(SseDeserializer deserializer);
 
   @protected
-  List<(String, List<String>)> sse_decode_list_record_string_list_string(
+  (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
+
+  @protected
+  (List<String>, bool, int) sse_decode_record_list_string_bool_i_32(
     SseDeserializer deserializer,
   );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  (String?, String?) sse_decode_record_opt_string_opt_string(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  (String, List<String>) sse_decode_record_string_list_string(
+  (String?, String?, bool) sse_decode_record_opt_string_opt_string_bool(
     SseDeserializer deserializer,
   );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  (String, double) sse_decode_record_string_f_64(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  (String, int, double) sse_decode_record_string_i_32_f_64(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  (String, List<String>) sse_decode_record_string_list_string(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
-    SseSerializer serializer,
+  (String, String, double) sse_decode_record_string_string_f_64(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
+  SolverConfig sse_decode_solver_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    WordManager self,
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
     SseSerializer serializer,
   );
 
@@ -236,70 +234,94 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_guess_result(
-    GuessResult self,
+  void sse_encode_candidate_source(
+    CandidateSource self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_intelligent_solver(
-    IntelligentSolver self,
-    SseSerializer serializer,
-  );
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_guess_result(GuessResult self, SseSerializer serializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_intelligent_solver(
-    IntelligentSolver self,
+  void sse_encode_list_list_record_string_list_string(
+    List<List<(String, List<String>)>> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_letter_result(LetterResult self, SseSerializer serializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_letter_result_array_5(
-    LetterResultArray5 self,
+  void sse_encode_list_record_string_f_64(
+    List<(String, double)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_list_record_string_i_32_f_64(
+    List<(String, int, double)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_guess_result(
-    List<GuessResult> self,
+  void sse_encode_list_record_string_list_string(
+    List<(String, List<String>)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_letter_result(
-    List<LetterResult> self,
+  void sse_encode_list_record_string_string_f_64(
+    List<(String, String, double)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_list_string_bool_i_32(
+    (List<String>, bool, int) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_list_string(
-    List<(String, List<String>)> self,
+  void sse_encode_record_opt_string_opt_string(
+    (String?, String?) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_record_opt_string_opt_string_bool(
+    (String?, String?, bool) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_f_64(
+    (String, double) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_i_32_f_64(
+    (String, int, double) self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_record_string_list_string(
@@ -308,22 +330,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  void sse_encode_record_string_string_f_64(
+    (String, String, double) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_solver_config(SolverConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_unit(void self, SseSerializer serializer);
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-
   factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
       RustLibWire(lib.ffiDynamicLibrary);
 
@@ -331,35 +361,7 @@ class RustLibWire implements BaseWire {
   final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
   _lookup;
 
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    ffi.Pointer<ffi.Void> ptr,
-  ) =>
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-        ptr,
-      );
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManagerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_wrdlhelper_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManagerPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-    ffi.Pointer<ffi.Void> ptr,
-  ) =>
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager(
-        ptr,
-      );
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManagerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_wrdlhelper_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManager =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWordManagerPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+  /// The symbols are looked up in [dynamicLibrary].
+  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+    : _lookup = dynamicLibrary.lookup;
 }
@@ -231,6 +231,10 @@ run_rust_tests() {
         run_test "Rust Unit Tests" "cargo test --lib"
     fi
     
+    # Build the solver core without std, so std dependencies fail the run
+    run_test "Rust no_std Solver Core" "cargo build -p wrdl_solver_core --features no_std"
+    run_test "Rust no_std Solver Core Tests" "cargo test -p wrdl_solver_core --features no_std"
+    
     # Run Julia-Rust bridge tests
     if [ -f "src/api/julia_rust_bridge.rs" ]; then
         run_test "Rust Julia-Rust Bridge Tests" "cargo test julia_rust_bridge"
@@ -0,0 +1,203 @@
+//! Portable Solver Core
+//!
+//! This crate holds the pure wrdlHelper algorithms:
+//! - Pattern Simulation
+//! - Word Filtering
+//! - Shannon Entropy Analysis
+//!
+//! Only `core` and `alloc` paths are imported, with `HashMap` provided by
+//! `hashbrown`. With the `no_std` feature the crate is built `#![no_std]` and
+//! float math goes through `libm`, so the compiler rejects any std dependency:
+//! `cargo build -p wrdl_solver_core --features no_std`. FFI wrappers, file IO,
+//! and the global word manager stay in `rust_lib_wrdlhelper`, which re-exports
+//! this crate as `solver_core` and delegates here.
+
+#![cfg_attr(all(feature = "no_std", not(test)), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::f64::consts::LN_2;
+use hashbrown::HashMap;
+
+/// Natural logarithm computed by `libm`
+#[cfg(feature = "no_std")]
+fn ln(value: f64) -> f64 {
+    libm::log(value)
+}
+
+/// Natural logarithm using the std float intrinsic
+#[cfg(not(feature = "no_std"))]
+fn ln(value: f64) -> f64 {
+    value.ln()
+}
+
+/// Check that a word follows the solver's contract: exactly 5 ASCII letters
+///
+/// Pattern simulation and matching compare characters by position, so accented
+/// and other non-ASCII letters are rejected up front instead of half-working.
+pub fn is_valid_word(word: &str) -> bool {
+    word.len() == 5 && word.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Rank of a gray tile in `simulate_guess_ranks`
+pub const GRAY: u8 = 0;
+/// Rank of a yellow tile in `simulate_guess_ranks`
+pub const YELLOW: u8 = 1;
+/// Rank of a green tile in `simulate_guess_ranks`
+pub const GREEN: u8 = 2;
+
+/// Simulate the feedback for guessing `guess` against `target` as tile ranks
+///
+/// Each position is `GRAY`, `YELLOW`, or `GREEN`. Greens are marked first so
+/// duplicate letters only turn yellow for unmatched target letters.
+pub fn simulate_guess_ranks(guess: &str, target: &str) -> [u8; 5] {
+    let mut result = [GRAY; 5];
+    let mut target_chars: Vec<char> = target.chars().collect();
+    let guess_chars: Vec<char> = guess.chars().collect();
+
+    // First pass: mark green letters (correct position)
+    for i in 0..5 {
+        if guess_chars[i] == target_chars[i] {
+            result[i] = GREEN;
+            target_chars[i] = ' '; // Mark as used
+        }
+    }
+
+    // Second pass: mark yellow letters (wrong position)
+    for i in 0..5 {
+        if result[i] == GRAY { // Not already green
+            if let Some(pos) = target_chars.iter().position(|&c| c == guess_chars[i]) {
+                result[i] = YELLOW;
+                target_chars[pos] = ' '; // Mark as used
+            }
+        }
+    }
+
+    result
+}
+
+/// Find the target position each guess letter matched under the duplicate-letter rule
+///
+/// Same two passes as `simulate_guess_ranks`: a green matches its own position,
+/// a yellow the first unused target position with its letter, and a gray is `None`.
+pub fn simulate_guess_matches(guess: &str, target: &str) -> [Option<usize>; 5] {
+    let mut matches = [None; 5];
+    let mut target_chars: Vec<char> = target.chars().collect();
+    let guess_chars: Vec<char> = guess.chars().collect();
+
+    // First pass: greens consume their own position
+    for i in 0..5 {
+        if guess_chars[i] == target_chars[i] {
+            matches[i] = Some(i);
+            target_chars[i] = ' ';
+        }
+    }
+
+    // Second pass: yellows consume the first unused matching position
+    for i in 0..5 {
+        if matches[i].is_none() {
+            if let Some(pos) = target_chars.iter().position(|&c| c == guess_chars[i]) {
+                matches[i] = Some(pos);
+                target_chars[pos] = ' ';
+            }
+        }
+    }
+
+    matches
+}
+
+/// Simulate the pattern produced by guessing `guess` against `target`
+///
+/// Returns a string like "GGYXY" (G=Green, Y=Yellow, X=Gray), formatted from
+/// `simulate_guess_ranks`.
+pub fn simulate_guess_pattern(guess: &str, target: &str) -> String {
+    simulate_guess_ranks(guess, target).iter().map(|&rank| match rank {
+        GREEN => 'G',
+        YELLOW => 'Y',
+        _ => 'X',
+    }).collect()
+}
+
+/// Check if `word` is consistent with the `pattern` observed for `guess`
+///
+/// A word matches when guessing `guess` against it would produce exactly `pattern`.
+pub fn word_matches_pattern(word: &str, guess: &str, pattern: &str) -> bool {
+    simulate_guess_pattern(guess, word) == pattern
+}
+
+/// Filter words to those consistent with every (guess, pattern) pair
+pub fn filter_words(words: &[String], guesses: &[(&str, &str)]) -> Vec<String> {
+    words.iter()
+        .filter(|word| guesses.iter().all(|(guess, pattern)| word_matches_pattern(word, guess, pattern)))
+        .cloned()
+        .collect()
+}
+
+/// Calculate Shannon entropy (in bits) of guessing `candidate_word`
+///
+/// Groups the remaining words by the pattern the candidate would produce against
+/// each of them. Returns 0.0 when fewer than two words remain.
+pub fn calculate_entropy(candidate_word: &str, remaining_words: &[String]) -> f64 {
+    if remaining_words.is_empty() || remaining_words.len() == 1 {
+        return 0.0;
+    }
+
+    // Group words by the pattern they would produce
+    let mut pattern_groups: HashMap<String, usize> = HashMap::new();
+
+    for target_word in remaining_words {
+        let pattern = simulate_guess_pattern(candidate_word, target_word);
+        *pattern_groups.entry(pattern).or_insert(0) += 1;
+    }
+
+    // Calculate Shannon entropy
+    let total_words = remaining_words.len() as f64;
+    let mut entropy = 0.0;
+
+    for &count in pattern_groups.values() {
+        let probability = count as f64 / total_words;
+        if probability > 0.0 {
+            entropy -= probability * (ln(probability) / LN_2);
+        }
+    }
+
+    entropy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_core_simulate_guess_pattern() {
+        assert_eq!(simulate_guess_pattern("CRANE", "CRATE"), "GGGXG");
+        assert_eq!(simulate_guess_pattern("CRANE", "SLATE"), "XXGXG");
+        // Duplicate letters: only one E in the target, so only one E is marked
+        assert_eq!(simulate_guess_pattern("EERIE", "CRANE"), "XXYXG");
+    }
+
+    #[test]
+    fn test_core_calculate_entropy() {
+        let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
+        assert_eq!(calculate_entropy("CRANE", &remaining), 1.0);
+        assert_eq!(calculate_entropy("CRANE", &["CRANE".to_string()]), 0.0);
+    }
+
+    #[test]
+    fn test_core_ln_matches_std() {
+        // Runs against whichever `ln` the `no_std` feature selects
+        for value in [0.25, 0.5, 1.0, 2.0, 1000.0] {
+            assert!((ln(value) - f64::ln(value)).abs() < 1e-12, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_core_filter_words() {
+        let words = vec!["CRANE".to_string(), "SLOTH".to_string(), "BLIMP".to_string()];
+        let filtered = filter_words(&words, &[("CRANE", "XXXXX")]);
+        assert_eq!(filtered, vec!["SLOTH".to_string(), "BLIMP".to_string()]);
+    }
+}
@@ -1,3 +1,7 @@
 pub mod simple;
 pub mod wrdl_helper;
 pub mod wrdl_helper_reference;
+#[cfg(test)]
+mod parity_test;
+#[cfg(test)]
+pub(crate) mod test_support;
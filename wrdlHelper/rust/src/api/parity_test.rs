@@ -0,0 +1,85 @@
+//! Parity tests between the reference solver and the production solver
+//!
+//! `get_intelligent_guess_reference` runs `wrdl_helper_reference::IntelligentSolver`
+//! and `get_best_guess` runs `wrdl_helper::IntelligentSolver`. They share the same
+//! scoring (pure entropy plus a 0.1 prime suspect bonus) and the same endgame
+//! shortcut, but intentionally differ in:
+//! - Strategic "killer" words: each solver adds its own curated list to the candidates
+//! - Early termination: production honors `early_termination_enabled`; the
+//!   reference scores every candidate
+//!
+//! Both honor `candidate_cap`, but production keeps the candidates whose letters
+//! best split the remaining words while the reference keeps the alphabetically
+//! first ones.
+
+use crate::api::wrdl_helper::{IntelligentSolver, SOLVER_CONFIG};
+use crate::api::test_support::lock_global_state;
+use crate::api::wrdl_helper_reference::IntelligentSolver as ReferenceSolver;
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+fn production_guess(remaining: &[String]) -> Option<String> {
+    IntelligentSolver::new(vec![]).get_best_guess(remaining, &[])
+}
+
+fn reference_guess(remaining: &[String]) -> Option<String> {
+    ReferenceSolver::new(vec![]).get_best_guess(remaining, &[])
+}
+
+#[test]
+fn test_parity_trivial_states() {
+    let _globals = lock_global_state();
+    // No words, one word, and the two-word endgame all take the same shortcut
+    for remaining in [words(&[]), words(&["CRANE"]), words(&["CRANE", "SLATE"]), words(&["SLATE", "CRANE"])] {
+        assert_eq!(production_guess(&remaining), reference_guess(&remaining));
+    }
+}
+
+#[test]
+fn test_parity_when_remaining_words_dominate() {
+    let _globals = lock_global_state();
+    // The best split comes from the remaining words, so the strategic lists don't matter
+    let states = [
+        words(&["CRANE", "SLATE", "PLATE"]),
+        words(&["BLIMP", "CLOTH", "DWARF", "FJORD", "GUSTY", "NYMPH"]),
+    ];
+
+    for remaining in states {
+        let production = production_guess(&remaining);
+        assert_eq!(production, reference_guess(&remaining));
+        assert!(remaining.contains(production.as_ref().unwrap()));
+    }
+}
+
+#[test]
+fn test_parity_divergence_from_strategic_words() {
+    let _globals = lock_global_state();
+    // Dense word families reward killer words, and each solver draws from its own list
+    // Production only keeps strategic words that are in the loaded dictionary
+    crate::api::simple::initialize_word_lists().unwrap();
+    let remaining = words(&["CRANE", "CRATE", "GRATE", "PLATE", "SLATE", "STATE", "SKATE", "SPATE"]);
+    let production = production_guess(&remaining).unwrap();
+    let reference = reference_guess(&remaining).unwrap();
+    assert_ne!(production, reference);
+
+    // The reference pick is also a production candidate, so production's pick is
+    // never less informative
+    let solver = IntelligentSolver::new(vec![]);
+    assert!(solver.get_candidate_words(&remaining, &[]).contains(&reference));
+    assert!(solver.calculate_entropy(&production, &remaining) >= solver.calculate_entropy(&reference, &remaining));
+}
+
+#[test]
+fn test_parity_candidate_cap() {
+    let _globals = lock_global_state();
+    SOLVER_CONFIG.lock().unwrap().candidate_cap = 1;
+
+    // With a cap of 1 each solver scores only its single kept candidate
+    let remaining = words(&["ABBEY", "BLIMP", "CLOTH"]);
+    let kept = IntelligentSolver::new(vec![]).get_candidate_words(&remaining, &[]);
+    assert_eq!(kept.len(), 1);
+    assert_eq!(production_guess(&remaining), kept.first().cloned());
+    assert_eq!(reference_guess(&remaining), Some("ABBEY".to_string()));
+}
@@ -1,4 +1,4 @@
-/**
+/*!
  * Simple API Module for Flutter-Rust FFI Bridge
  * 
  * This module provides basic functionality for demonstrating Flutter-Rust FFI integration.
@@ -19,10 +19,20 @@
  * - Synchronous functions are suitable for quick operations
  * - For long-running operations, consider async alternatives
  * - Memory allocation is handled automatically by flutter_rust_bridge
+ * 
+ * # Logging
+ * Diagnostics (word list loading, opener selection) go through the `log` facade
+ * instead of stdout. The embedding app installs a logger to choose the verbosity.
  */
 
-use crate::api::wrdl_helper::{IntelligentSolver, GuessResult, LetterResult, WORD_MANAGER};
+use crate::api::wrdl_helper::{dedup_guess_results, dedup_preserving_order, lock_recovering, IntelligentSolver, GuessResult, LetterResult, SolverConfig, CANDIDATES_DROPPED_BY_CAP, LAST_ERROR, SOLVER_CONFIG, WORD_MANAGER};
 use crate::api::wrdl_helper_reference::IntelligentSolver as ReferenceSolver;
+use crate::constraints::MergedConstraints;
+use crate::frb_generated::StreamSink;
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 
 /**
@@ -47,81 +57,75 @@ pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
 }
 
-/**
- * NORTH STAR ARCHITECTURE: Single FFI Entry Point
- * 
- * This is the ONLY public FFI function in the North Star architecture.
- * Client sends only GameState → Server handles ALL logic → Returns best_guess
- * 
- * # Arguments
- * - `guess_results`: Vector of tuples containing (word, result_pattern)
- *   - word: The guessed word (e.g., "TARES")
- *   - result_pattern: Array of 5 result strings ["G", "Y", "X", "G", "X"]
- *     - "G" = Green (correct letter, correct position)
- *     - "Y" = Yellow (correct letter, wrong position)  
- *     - "X" = Gray (letter not in word)
- * 
- * # Returns
- * - `Option<String>`: The best word to guess next, or None if no valid guesses remain
- * 
- * # Performance
- * - Time complexity: O(n*m) where n is candidate words, m is remaining words
- * - Space complexity: O(n) for pattern analysis
- * - Target response time: < 200ms
- * - Success rate: 100% (preserved from perfect algorithm)
- * 
- * # Example
- * ```rust
- * let guess_results = vec![
- *     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
- * ];
- * let best_guess = get_best_guess(guess_results);
- * ```
- */
+/// NORTH STAR ARCHITECTURE: Single FFI Entry Point
+///
+/// This is the ONLY public FFI function in the North Star architecture.
+/// Client sends only GameState → Server handles ALL logic → Returns best_guess
+///
+/// # Arguments
+/// - `guess_results`: Vector of tuples containing (word, result_pattern)
+///   - word: The guessed word (e.g., "TARES")
+///   - result_pattern: Array of 5 result strings ["G", "Y", "X", "G", "X"]
+///     - "G" = Green (correct letter, correct position)
+///     - "Y" = Yellow (correct letter, wrong position)
+///     - "X" = Gray (letter not in word)
+///
+/// # Returns
+/// - `Option<String>`: The best word to guess next, or None if no valid guesses remain
+/// - If the last guess was all green the game is already won, and that word is
+///   returned as-is without scoring
+///
+/// # Performance
+/// - Time complexity: O(n*m) where n is candidate words, m is remaining words
+/// - Space complexity: O(n) for pattern analysis
+/// - Target response time: < 200ms
+/// - Success rate: 100% (preserved from perfect algorithm)
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_best_guess;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let best_guess = get_best_guess(guess_results);
+/// ```
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_best_guess(
     guess_results: Vec<(String, Vec<String>)>,
 ) -> Option<String> {
+    let config = lock_recovering(&SOLVER_CONFIG).clone();
+    best_guess_with_config(&guess_results, &config)
+}
+
+/// `get_best_guess` under `config` instead of `SOLVER_CONFIG`
+fn best_guess_with_config(guess_results: &[(String, Vec<String>)], config: &SolverConfig) -> Option<String> {
+    // Calls that never reach candidate selection don't truncate anything
+    CANDIDATES_DROPPED_BY_CAP.store(0, Ordering::Relaxed);
+
     // Special case: First guess (no constraints) - use optimal first guess
     if guess_results.is_empty() {
-        use crate::api::wrdl_helper::WORD_MANAGER;
-        let manager = match WORD_MANAGER.lock() {
-            Ok(manager) => manager,
-            Err(_) => return None,
-        };
-        let optimal_guess = manager.get_optimal_first_guess();
-        drop(manager); // Release lock early
-        return optimal_guess;
+        return get_optimal_first_guess();
     }
     
     // Get all words for the solver (14,855 guess words including 2,300 answer words)
     use crate::api::wrdl_helper::WORD_MANAGER;
-    let manager = match WORD_MANAGER.lock() {
-        Ok(manager) => manager,
-        Err(_) => return None,
-    };
-    let all_words = manager.get_guess_words().to_vec();
+    let manager = lock_recovering(&WORD_MANAGER);
+    let all_words = manager.get_interned_guess_words();
     drop(manager); // Release lock early
     
-    // Convert FFI format to internal format
-    let internal_guess_results: Vec<crate::api::wrdl_helper::GuessResult> = guess_results.iter()
-        .map(|(word, pattern)| {
-            let results = pattern.iter().map(|p| match p.as_str() {
-                "G" => crate::api::wrdl_helper::LetterResult::Green,
-                "Y" => crate::api::wrdl_helper::LetterResult::Yellow,
-                "X" => crate::api::wrdl_helper::LetterResult::Gray,
-                _ => crate::api::wrdl_helper::LetterResult::Gray,
-            }).collect::<Vec<_>>();
-            
-            crate::api::wrdl_helper::GuessResult {
-                word: word.clone(),
-                results: [results[0], results[1], results[2], results[3], results[4]].to_vec(),
-            }
-        })
-        .collect();
+    // Convert FFI format to internal format, dropping rows submitted twice
+    let internal_guess_results = dedup_guess_results(&parse_guess_results(guess_results, config.strict_patterns)?);
+
+    // Already solved: the winning word is the only sensible answer
+    if let Some(last) = internal_guess_results.last() {
+        if last.results.iter().all(|&result| result == LetterResult::Green) {
+            return Some(last.word.to_uppercase());
+        }
+    }
 
     // Use the EXACT same filtering logic as the working benchmark
-    let eligible_words = filter_words_with_feedback(&all_words, &internal_guess_results);
+    let eligible_words = to_owned_words(&filter_words_matching(&all_words, &internal_guess_results, config.exclude_prior_guesses));
 
     if eligible_words.is_empty() {
         return None; // No eligible words remaining
@@ -130,103 +134,290 @@ pub fn get_best_guess(
     // Use the 100% algorithm directly (bypassing the old get_intelligent_guess)
     use crate::api::wrdl_helper::IntelligentSolver;
 
-    let solver = IntelligentSolver::new(all_words);
-    solver.get_best_guess(&eligible_words, &internal_guess_results)
+    let solver = IntelligentSolver::new_from_manager();
+    solver.get_top_guesses_with_config(config, &eligible_words, &internal_guess_results, 1).into_iter().next()
 }
 
 /**
- * COPY EXACT FILTERING LOGIC FROM WORKING BENCHMARK
- * These functions were achieving 100% success rate
+ * Get the best guess for a remaining-word set the caller already has
+ * 
+ * Same scoring as `get_best_guess`, but the remaining words are taken as given
+ * (e.g. from a prior `get_possible_words` call) instead of being filtered again.
+ * Candidates are still drawn from the solver's strategic words as well.
+ * 
+ * # Arguments
+ * - `remaining_words`: Words that could still be the answer
+ * - `guess_results`: Vector of (word, pattern) tuples from game state
+ * 
+ * # Returns
+ * - The best word to guess next, or None if no words remain
  */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_best_guess_for_remaining(
+    remaining_words: Vec<String>,
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Option<String> {
+    // First guess (no constraints) - use optimal first guess, like get_best_guess
+    if guess_results.is_empty() {
+        return get_optimal_first_guess();
+    }
 
-/// Filter words based on feedback from all guesses
-fn filter_words_with_feedback(words: &[String], guess_results: &[crate::api::wrdl_helper::GuessResult]) -> Vec<String> {
-    words.iter()
-        .filter(|word| word_matches_all_feedback(word, guess_results))
-        .cloned()
-        .collect()
+    if remaining_words.is_empty() {
+        return None;
+    }
+
+    let internal_guess_results = to_internal_guess_results(&guess_results)?;
+
+    let solver = IntelligentSolver::new_from_manager();
+    solver.get_best_guess(&remaining_words, &internal_guess_results)
 }
 
-/// Check if a word matches all feedback from previous guesses
-fn word_matches_all_feedback(candidate: &str, guess_results: &[crate::api::wrdl_helper::GuessResult]) -> bool {
-    for guess_result in guess_results {
-        if !word_matches_single_feedback(candidate, guess_result) {
-            return false;
-        }
+/**
+ * Get the best guess plus the runner-up
+ * 
+ * Runs the same candidate scoring as `get_best_guess` but keeps the two highest
+ * combined scores, so the UI can offer the user a choice.
+ * 
+ * # Arguments
+ * - `guess_results`: Vector of (word, pattern) tuples from game state
+ * 
+ * # Returns
+ * - `(best, runner_up)`: The first element always equals `get_best_guess`'s output.
+ *   The runner-up is a different word, or None when no second option exists
+ *   (e.g. a single remaining word, or the first guess of the game).
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_top_two_guesses(
+    guess_results: Vec<(String, Vec<String>)>,
+) -> (Option<String>, Option<String>) {
+    // First guess (no constraints) - only the optimal opener is precomputed
+    if guess_results.is_empty() {
+        return (get_best_guess(guess_results), None);
     }
-    true
-}
 
-/// Check if a word matches feedback from a single guess
-fn word_matches_single_feedback(candidate: &str, guess_result: &crate::api::wrdl_helper::GuessResult) -> bool {
-    let candidate_chars: Vec<char> = candidate.chars().collect();
-    let guess_chars: Vec<char> = guess_result.word.chars().collect();
+    let manager = lock_recovering(&WORD_MANAGER);
+    let all_words = manager.get_interned_guess_words();
+    drop(manager); // Release lock early
 
-    // Build letter count constraints from guess
-    use std::collections::HashMap;
-    let mut min_required: HashMap<char, usize> = HashMap::new();
-    let mut banned_positions: HashMap<usize, char> = HashMap::new();
-    let mut fixed_positions: HashMap<usize, char> = HashMap::new();
-    let mut total_occurrence_cap: HashMap<char, usize> = HashMap::new();
-    
-    // First pass: fixed greens and count greens/yellows per letter
-    for i in 0..5 {
-        match guess_result.results[i] {
-            crate::api::wrdl_helper::LetterResult::Green => {
-                fixed_positions.insert(i, guess_chars[i]);
-                *min_required.entry(guess_chars[i]).or_insert(0) += 1;
-            }
-            crate::api::wrdl_helper::LetterResult::Yellow => {
-                banned_positions.insert(i, guess_chars[i]);
-                *min_required.entry(guess_chars[i]).or_insert(0) += 1;
-            }
-            crate::api::wrdl_helper::LetterResult::Gray => {}
+    let internal_guess_results = match to_internal_guess_results(&guess_results) {
+        Some(results) => results,
+        None => return (None, None),
+    };
+    let eligible_words = to_owned_words(&filter_words_with_feedback(&all_words, &internal_guess_results));
+
+    let solver = IntelligentSolver::new_from_manager();
+    let mut top = solver.get_top_guesses(&eligible_words, &internal_guess_results, 2).into_iter();
+    (top.next(), top.next())
+}
+
+/**
+ * Get the best single guess for several simultaneous boards (Quordle/Dordle)
+ * 
+ * Every board is one hidden word with its own feedback history. The remaining
+ * words of each board are computed separately, and the returned guess maximizes
+ * the summed information over all unsolved boards.
+ * 
+ * # Arguments
+ * - `boards`: One (word, pattern) history per board, in the same format as `get_best_guess`
+ * 
+ * # Returns
+ * - The best shared guess, or None if every board is solved or has no valid words left
+ * 
+ * # Notes
+ * - A board whose history contains an all-green guess is solved and ignored
+ * - When no unsolved board has any feedback yet, the optimal first guess is returned
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_best_guess_multiboard(
+    boards: Vec<Vec<(String, Vec<String>)>>,
+) -> Option<String> {
+    let mut unsolved_boards = Vec::new();
+    for board in &boards {
+        let internal_guess_results = to_internal_guess_results(board)?;
+        let solved = internal_guess_results.iter()
+            .any(|gr| gr.results.iter().all(|r| *r == LetterResult::Green));
+        if !solved {
+            unsolved_boards.push(internal_guess_results);
         }
     }
-    
-    // Second pass: for grays, if the letter also appears as green/yellow elsewhere,
-    // cap the total occurrences to that minimum (i.e., no extra occurrences)
-    for i in 0..5 {
-        if let crate::api::wrdl_helper::LetterResult::Gray = guess_result.results[i] {
-            let ch = guess_chars[i];
-            if let Some(&required) = min_required.get(&ch) {
-                // gray means no more than required occurrences across the word
-                total_occurrence_cap.insert(ch, required);
-            }
-        }
+
+    if unsolved_boards.is_empty() {
+        return None;
     }
 
-    // Check fixed positions (greens)
-    for (&pos, &expected_char) in &fixed_positions {
-        if candidate_chars[pos] != expected_char {
-            return false;
-        }
+    // Fresh boards - same opener as a single board
+    if unsolved_boards.iter().all(|board| board.is_empty()) {
+        return get_best_guess(Vec::new());
     }
 
-    // Check banned positions (yellows)
-    for (&pos, &banned_char) in &banned_positions {
-        if candidate_chars[pos] == banned_char {
-            return false;
+    let manager = lock_recovering(&WORD_MANAGER);
+    let all_words = manager.get_interned_guess_words();
+    drop(manager); // Release lock early
+
+    let boards_remaining: Vec<Vec<String>> = unsolved_boards.iter()
+        .map(|board| to_owned_words(&filter_words_with_feedback(&all_words, board)))
+        .collect();
+
+    let solver = IntelligentSolver::new_from_manager();
+    solver.get_best_guess_multiboard(&boards_remaining)
+}
+
+/// Convert FFI (word, pattern) tuples to internal guess results
+/// 
+/// Unknown tokens default to Gray. With `strict_patterns` enabled they are
+/// rejected instead: the error is recorded for `get_last_error` and None is returned.
+/// Patterns that aren't exactly 5 tokens are rejected the same way in both modes.
+fn to_internal_guess_results(guess_results: &[(String, Vec<String>)]) -> Option<Vec<GuessResult>> {
+    let strict = lock_recovering(&SOLVER_CONFIG).strict_patterns;
+    parse_guess_results(guess_results, strict)
+}
+
+/// Convert FFI tuples like `to_internal_guess_results`, with `strict` in place of `strict_patterns`
+fn parse_guess_results(guess_results: &[(String, Vec<String>)], strict: bool) -> Option<Vec<GuessResult>> {
+    let mut internal_guess_results = Vec::new();
+
+    for (word, pattern) in guess_results {
+        if let Err(message) = validate_word(word) {
+            set_last_error(message);
+            return None;
+        }
+        if pattern.len() != 5 {
+            set_last_error(format!("Pattern for '{}' has {} tokens, expected 5", word, pattern.len()));
+            return None;
         }
-    }
 
-    // Check minimum required occurrences
-    for (&ch, &min_count) in &min_required {
-        let actual_count = candidate_chars.iter().filter(|&&c| c == ch).count();
-        if actual_count < min_count {
-            return false;
+        let mut results = Vec::new();
+        for token in pattern {
+            let result = match token.as_str() {
+                "G" => LetterResult::Green,
+                "Y" => LetterResult::Yellow,
+                "X" => LetterResult::Gray,
+                _ if strict => {
+                    set_last_error(format!("Unknown pattern token '{}' for '{}'", token, word));
+                    return None;
+                }
+                _ => LetterResult::Gray,
+            };
+            results.push(result);
         }
+
+        internal_guess_results.push(GuessResult {
+            word: word.to_uppercase(),
+            results,
+        });
+    }
+
+    Some(internal_guess_results)
+}
+
+/// Enforce the solver's word contract: exactly 5 ASCII letters (A-Z, any case)
+fn validate_word(word: &str) -> Result<(), String> {
+    if crate::solver_core::is_valid_word(word) {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a 5-letter word of ASCII letters A-Z", word))
     }
+}
+
+/// Record an error message for `get_last_error`
+fn set_last_error(message: String) {
+    *lock_recovering(&LAST_ERROR) = Some(message);
+}
+
+/**
+ * Get the most recent error message
+ * 
+ * Functions that can only signal failure with None or an empty list (e.g. strict
+ * pattern validation) record the reason here.
+ * 
+ * # Returns
+ * - The most recent error message, or None if no error has occurred
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_last_error() -> Option<String> {
+    lock_recovering(&LAST_ERROR).clone()
+}
 
-    // Check total occurrence caps (grays with other occurrences)
-    for (&ch, &max_count) in &total_occurrence_cap {
-        let actual_count = candidate_chars.iter().filter(|&&c| c == ch).count();
-        if actual_count > max_count {
-            return false;
+/// Filter words based on feedback from all guesses
+/// 
+/// Uses the canonical matcher in `crate::constraints`, with the constraints of
+/// all guesses merged once up front rather than rebuilt for every word.
+/// 
+/// With `exclude_prior_guesses` enabled, previously guessed words are dropped
+/// even when they match their own feedback.
+/// 
+/// Works on owned (`String`) and interned (`Arc<str>`) word lists alike; only
+/// the matching words are cloned.
+fn filter_words_with_feedback<W: AsRef<str> + Clone>(words: &[W], guess_results: &[crate::api::wrdl_helper::GuessResult]) -> Vec<W> {
+    let exclude_prior_guesses = lock_recovering(&SOLVER_CONFIG).exclude_prior_guesses;
+    filter_words_matching(words, guess_results, exclude_prior_guesses)
+}
+
+/// Filter words like `filter_words_with_feedback`, with `exclude_prior_guesses` given explicitly
+fn filter_words_matching<W: AsRef<str> + Clone>(words: &[W], guess_results: &[crate::api::wrdl_helper::GuessResult], exclude_prior_guesses: bool) -> Vec<W> {
+    let constraints = MergedConstraints::from_guess_results(guess_results);
+    words.iter()
+        .filter(|word| word_is_possible(word.as_ref(), &constraints, guess_results, exclude_prior_guesses))
+        .cloned()
+        .collect()
+}
+
+/// Check a single word against all feedback and the `exclude_prior_guesses` option
+fn word_is_possible(word: &str, constraints: &MergedConstraints, guess_results: &[crate::api::wrdl_helper::GuessResult], exclude_prior_guesses: bool) -> bool {
+    constraints.matches(word)
+        && !(exclude_prior_guesses && guess_results.iter().any(|gr| gr.word == word))
+}
+
+/**
+ * Filter words against the game state, streaming matches as they are found
+ * 
+ * Same filtering as `get_possible_words`, but each match is sent to Dart
+ * immediately instead of collecting a `Vec`, so peak memory stays bounded for
+ * very large word lists and the UI can render progressively.
+ * 
+ * # Arguments
+ * - `words`: Words to filter
+ * - `guess_results`: Vector of (word, pattern) tuples from game state
+ * - `sink`: Receives each matching word; filtering stops once Dart closes it
+ */
+pub fn filter_words_streamed(
+    words: Vec<String>,
+    guess_results: Vec<(String, Vec<String>)>,
+    sink: StreamSink<String>,
+) {
+    for_each_matching_word(&words, &guess_results, |word| sink.add(word).is_ok());
+}
+
+/// Call `emit` with every word matching the state, stopping early if it returns false
+fn for_each_matching_word<F>(words: &[String], guess_results: &[(String, Vec<String>)], mut emit: F)
+where
+    F: FnMut(String) -> bool,
+{
+    let internal_guess_results = match to_internal_guess_results(guess_results) {
+        Some(results) => results,
+        None => return,
+    };
+    let exclude_prior_guesses = lock_recovering(&SOLVER_CONFIG).exclude_prior_guesses;
+    let constraints = MergedConstraints::from_guess_results(&internal_guess_results);
+
+    for word in words {
+        if word_is_possible(word, &constraints, &internal_guess_results, exclude_prior_guesses) && !emit(word.clone()) {
+            return;
         }
     }
+}
+
+/// Count the guess words still consistent with the state
+/// 
+/// This is the remaining set `get_best_guess` scores, used by benchmark traces.
+pub(crate) fn count_remaining_guess_words(guess_results: &[GuessResult]) -> usize {
+    let all_words = lock_recovering(&WORD_MANAGER).get_interned_guess_words();
+    filter_words_with_feedback(&all_words, guess_results).len()
+}
 
-    true
+/// Convert interned words back to owned strings for scoring and the FFI boundary
+fn to_owned_words(words: &[Arc<str>]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
 }
 
 
@@ -238,17 +429,28 @@ fn word_matches_single_feedback(candidate: &str, guess_result: &crate::api::wrdl
 
 
 
+
 // ============================================================================
 // wrdlHelper Intelligent Solver FFI Functions
 // ============================================================================
 
 
+/// Directory containing the word list assets, relative to the crate by default
+pub(crate) static WORD_LIST_DIR: Lazy<Mutex<PathBuf>> = Lazy::new(|| {
+    Mutex::new(PathBuf::from("../assets/word_lists"))
+});
+
+/// Full path of a word list file inside the configured asset directory
+fn word_list_path(file_name: &str) -> PathBuf {
+    lock_recovering(&WORD_LIST_DIR).join(file_name)
+}
+
 /// Load answer words directly from Rust assets (same as benchmark)
 fn load_answer_words_from_assets() -> Result<Vec<String>, String> {
-    let word_list_path = "../assets/word_lists/official_wordle_words.json";
+    let word_list_path = word_list_path("official_wordle_words.json");
     
-    if std::path::Path::new(word_list_path).exists() {
-        let content = std::fs::read_to_string(word_list_path)
+    if word_list_path.exists() {
+        let content = std::fs::read_to_string(&word_list_path)
             .map_err(|e| format!("Failed to read word list file: {}", e))?;
         let word_data: serde_json::Value = serde_json::from_str(&content)
             .map_err(|e| format!("Failed to parse JSON: {}", e))?;
@@ -256,38 +458,242 @@ fn load_answer_words_from_assets() -> Result<Vec<String>, String> {
         if let Some(answers) = word_data.get("answer_words").and_then(|v| v.as_array()) {
             let answer_words: Vec<String> = answers
                 .iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_uppercase()))
-                .collect();
-            println!("📚 Loaded {} answer words from {}", answer_words.len(), word_list_path);
+                .filter_map(|v| v.as_str())
+                .map(|word| validate_word(word).map(|()| word.to_uppercase()))
+                .collect::<Result<_, _>>()?;
+            log::info!("📚 Loaded {} answer words from {}", answer_words.len(), word_list_path.display());
             return Ok(answer_words);
         }
     }
     
-    Err(format!("Failed to load answer words from {}", word_list_path))
+    Err(format!("Failed to load answer words from {}", word_list_path.display()))
 }
 
 /// Load guess words directly from Rust assets (same as benchmark)
 fn load_guess_words_from_assets() -> Result<Vec<String>, String> {
-    let word_list_path = "../assets/word_lists/official_guess_words.txt";
+    let word_list_path = word_list_path("official_guess_words.txt");
     
-    if std::path::Path::new(word_list_path).exists() {
-        let content = std::fs::read_to_string(word_list_path)
+    if word_list_path.exists() {
+        let content = std::fs::read_to_string(&word_list_path)
             .map_err(|e| format!("Failed to read word list file: {}", e))?;
         
-        let all_words: Vec<String> = content
-            .lines()
-            .map(|line| line.trim().to_uppercase())
-            .filter(|word| !word.is_empty() && word.len() == 5)
-            .collect();
+        // Malformed lines are skipped rather than failing the whole list
+        let mut all_words = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let word = line.trim();
+            if word.is_empty() {
+                continue;
+            }
+            match validate_word(word) {
+                Ok(()) => all_words.push(word.to_uppercase()),
+                Err(error) => log::warn!("⚠️ Skipping line {} of {}: {}", index + 1, word_list_path.display(), error),
+            }
+        }
         
-        println!("📚 Loaded {} guess words from {}", all_words.len(), word_list_path);
+        log::info!("📚 Loaded {} guess words from {}", all_words.len(), word_list_path.display());
         return Ok(all_words);
     }
     
-    Err(format!("Failed to load guess words from {}", word_list_path))
+    Err(format!("Failed to load guess words from {}", word_list_path.display()))
+}
+
+/**
+ * Set the directory the word list assets are loaded from
+ * 
+ * Defaults to `../assets/word_lists`. Call before `initialize_word_lists` when
+ * the app bundles its assets elsewhere.
+ * 
+ * # Arguments
+ * - `path`: Directory containing `official_wordle_words.json` and `official_guess_words.txt`
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_word_list_dir(path: String) {
+    *lock_recovering(&WORD_LIST_DIR) = PathBuf::from(path);
+}
+
+/**
+ * Load the word lists from the assets into the global word manager
+ * 
+ * Reads the answer and guess lists once, interns them, and computes the optimal
+ * first guess. All solver functions then work from memory. Calling it again
+ * reloads the lists from disk.
+ * 
+ * # Returns
+ * - `Ok(())` on success, or an error describing which list failed to load
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn initialize_word_lists() -> Result<(), String> {
+    let answer_words = load_answer_words_from_assets()?;
+    let guess_words = load_guess_words_from_assets()?;
+
+    let mut manager = lock_recovering(&WORD_MANAGER);
+    manager.set_words(answer_words, guess_words);
+    Ok(())
+}
+
+/**
+ * Export the word lists currently held in memory as JSON
+ * 
+ * Produces `{ "answer_words": [...], "guess_words": [...] }` so the app can
+ * snapshot the lists for backup or offline use.
+ * 
+ * # Returns
+ * - The JSON blob (both lists empty if the word manager is unavailable)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_word_lists_json() -> String {
+    let (answer_words, guess_words) = {
+        let manager = lock_recovering(&WORD_MANAGER);
+        (manager.get_answer_words().to_vec(), manager.get_guess_words().to_vec())
+    };
+    serde_json::json!({
+        "answer_words": answer_words,
+        "guess_words": guess_words,
+    }).to_string()
+}
+
+/**
+ * Replace the word lists in memory from a JSON blob
+ * 
+ * Accepts the format produced by `export_word_lists_json`. Every word must be
+ * 5 ASCII letters and the answer list must not be empty. Words are uppercased,
+ * and nothing is changed if validation fails.
+ * 
+ * # Arguments
+ * - `json`: `{ "answer_words": [...], "guess_words": [...] }`
+ * 
+ * # Returns
+ * - `Ok(())` on success, or an error describing the first invalid entry
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_word_lists_json(json: String) -> Result<(), String> {
+    let word_data: serde_json::Value = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let answer_words = parse_word_array(&word_data, "answer_words")?;
+    let guess_words = parse_word_array(&word_data, "guess_words")?;
+
+    if answer_words.is_empty() {
+        return Err("answer_words must not be empty".to_string());
+    }
+
+    let mut manager = lock_recovering(&WORD_MANAGER);
+    manager.set_words(answer_words, guess_words);
+    Ok(())
+}
+
+/// Read a validated, uppercased word array from a JSON object
+fn parse_word_array(word_data: &serde_json::Value, key: &str) -> Result<Vec<String>, String> {
+    let entries = word_data.get(key)
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| format!("Missing '{}' array", key))?;
+
+    entries.iter().enumerate().map(|(index, entry)| {
+        let word = entry.as_str()
+            .ok_or_else(|| format!("{}[{}] is not a string", key, index))?;
+        validate_word(word).map_err(|message| format!("{}[{}]: {}", key, index, message))?;
+        Ok(word.to_uppercase())
+    }).collect()
+}
+
+/**
+ * Check several typed guesses in one call
+ *
+ * A word is a legal guess if it is 5 ASCII letters and in the loaded guess list
+ * (any case).
+ *
+ * # Arguments
+ * - `words`: Words to check
+ *
+ * # Returns
+ * - One flag per input word, in the same order (all false if the word lists
+ *   can't be loaded)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn validate_words(words: Vec<String>) -> Vec<bool> {
+    let guess_words = match get_guess_words() {
+        Ok(guess_words) => guess_words,
+        Err(e) => {
+            set_last_error(e);
+            return vec![false; words.len()];
+        }
+    };
+    let known: std::collections::HashSet<&str> = guess_words.iter().map(|word| word.as_ref()).collect();
+
+    words.iter()
+        .map(|word| crate::solver_core::is_valid_word(word) && known.contains(word.to_ascii_uppercase().as_str()))
+        .collect()
+}
+
+/// Number of solver calls that had to load the word lists on first use
+static LAZY_WORD_LIST_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+/**
+ * Prepare everything the solver builds on first use
+ * 
+ * Loads the word lists (interning the guess list) and makes sure the optimal
+ * first guess is cached, so the first `get_best_guess` doesn't pay for it.
+ * Intended to run while the app shows its splash screen; the time taken is logged.
+ * 
+ * # Returns
+ * - `Ok(())` once everything is ready, or the error from loading the word lists
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn warm_up() -> Result<(), String> {
+    let start = std::time::Instant::now();
+    ensure_word_lists_loaded()?;
+
+    let mut manager = lock_recovering(&WORD_MANAGER);
+    if manager.get_optimal_first_guess().is_none() {
+        manager.compute_optimal_first_guess();
+    }
+    drop(manager);
+    Lazy::force(&SOLVER_CONFIG);
+
+    log::info!("🔥 Warm-up finished in {:?}", start.elapsed());
+    Ok(())
+}
+
+/// Load the word lists on first use if `initialize_word_lists` hasn't run yet
+/// 
+/// With the `embedded-words` feature, the compiled-in lists are used when the
+/// asset files can't be read.
+pub(crate) fn ensure_word_lists_loaded() -> Result<(), String> {
+    let loaded = !lock_recovering(&WORD_MANAGER).get_guess_words().is_empty();
+    if loaded {
+        return Ok(());
+    }
+    LAZY_WORD_LIST_LOADS.fetch_add(1, Ordering::Relaxed);
+
+    // Builds with the lists compiled in still work when the asset files are missing
+    #[cfg(feature = "embedded-words")]
+    if let Err(e) = initialize_word_lists() {
+        log::warn!("⚠️ {}; using the embedded word lists", e);
+        return lock_recovering(&WORD_MANAGER).load_words();
+    }
+    #[cfg(not(feature = "embedded-words"))]
+    initialize_word_lists()?;
+
+    Ok(())
+}
+
+/// Get the answer words from memory, loading the assets once if needed
+fn get_answer_words() -> Result<Vec<String>, String> {
+    ensure_word_lists_loaded()?;
+    let manager = lock_recovering(&WORD_MANAGER);
+    Ok(manager.get_answer_words().to_vec())
 }
 
+/// Get the interned guess words from memory, loading the assets once if needed
+fn get_guess_words() -> Result<Arc<[Arc<str>]>, String> {
+    ensure_word_lists_loaded()?;
+    let manager = lock_recovering(&WORD_MANAGER);
+    Ok(manager.get_interned_guess_words())
+}
 
+/// Get the precomputed optimal first guess
+fn get_optimal_first_guess() -> Option<String> {
+    lock_recovering(&WORD_MANAGER).get_optimal_first_guess()
+}
 
 
 
@@ -314,22 +720,21 @@ pub fn get_intelligent_guess_fast(
     remaining_words: Vec<String>,
     guess_results: Vec<(String, Vec<String>)>, // (word, pattern) where pattern is ["G", "Y", "X", ...]
 ) -> Option<String> {
-    use crate::api::wrdl_helper::{WORD_MANAGER, IntelligentSolver};
+    use crate::api::wrdl_helper::IntelligentSolver;
     
     if remaining_words.is_empty() {
         return None;
     }
 
-    // Get words from global manager
-    let manager = WORD_MANAGER.lock().ok()?;
-    let all_words = manager.get_guess_words().to_vec();
-    drop(manager); // Release lock early
-    
-    let solver = IntelligentSolver::new(all_words);
+    let solver = IntelligentSolver::new_from_manager();
     
     // Convert FFI guess results to internal format
     let mut internal_guess_results = Vec::new();
     for (word, pattern) in guess_results {
+        if pattern.len() != 5 {
+            set_last_error(format!("Pattern for '{}' has {} tokens, expected 5", word, pattern.len()));
+            return None;
+        }
         let mut results = Vec::new();
         for letter_result in pattern {
             // Accept both compact (G,Y,X) and verbose (Green, Yellow, Gray), case-insensitive
@@ -378,16 +783,16 @@ pub fn get_intelligent_guess_reference(
         return None;
     }
 
-    // Get words from global manager to match the benchmark approach
-    let manager = WORD_MANAGER.lock().ok()?;
-    let all_words = manager.get_guess_words().to_vec();
-    drop(manager); // Release lock early
-    
-    let solver = ReferenceSolver::new(all_words);
+    // The reference solver keeps no word list, so the global list isn't copied
+    let solver = ReferenceSolver::new(Vec::new());
     
     // Convert FFI guess results to internal format
     let mut internal_guess_results = Vec::new();
     for (word, pattern) in guess_results {
+        if pattern.len() != 5 {
+            set_last_error(format!("Pattern for '{}' has {} tokens, expected 5", word, pattern.len()));
+            return None;
+        }
         let mut results = Vec::new();
         for letter_result in pattern {
             // Accept both compact (G,Y,X) and verbose (Green, Yellow, Gray), case-insensitive
@@ -438,25 +843,13 @@ pub fn get_intelligent_guess(
         return None;
     }
 
+    // Merged word sources can contain duplicates - dedup before scoring
+    let remaining_words = dedup_preserving_order(&remaining_words);
+
     let solver = IntelligentSolver::new(all_words);
     
     // Convert FFI guess results to internal format
-    let mut internal_guess_results = Vec::new();
-    for (word, pattern) in guess_results {
-        let mut results = Vec::new();
-        for letter_result in pattern {
-            let result = match letter_result.as_str() {
-                "G" => LetterResult::Green,
-                "Y" => LetterResult::Yellow,
-                "X" => LetterResult::Gray,
-                _ => LetterResult::Gray, // Default to gray for unknown patterns
-            };
-            results.push(result);
-        }
-        internal_guess_results.push(GuessResult::new(word, [
-            results[0], results[1], results[2], results[3], results[4]
-        ]));
-    }
+    let internal_guess_results = to_internal_guess_results(&guess_results)?;
     
     solver.get_best_guess(&remaining_words, &internal_guess_results)
 }
@@ -481,53 +874,165 @@ pub fn get_intelligent_guess(
  */
 #[flutter_rust_bridge::frb(sync)]
 pub fn calculate_entropy(candidate_word: String, remaining_words: Vec<String>) -> f64 {
+    // Duplicate targets would inflate their pattern buckets
+    let remaining_words = dedup_preserving_order(&remaining_words);
+
     let solver = IntelligentSolver::new(vec![]);
     solver.calculate_entropy(&candidate_word, &remaining_words)
 }
 
 /**
- * Simulate guess pattern for testing
+ * Calculate entropy for a candidate word against a game state
  * 
- * This function simulates what pattern would result from guessing
- * a word against a target word.
+ * Unlike `calculate_entropy`, the remaining words are derived from the state
+ * with the same filtering as `get_best_guess`, so the caller can't score
+ * against a stale or hand-edited word list.
  * 
  * # Arguments
- * - `guess`: The word being guessed
- * - `target`: The target word
+ * - `candidate_word`: The word to analyze
+ * - `guess_results`: Vector of (word, pattern) tuples from game state
  * 
  * # Returns
- * Pattern string like "GGYXY" (G=Green, Y=Yellow, X=Gray)
+ * Entropy value (higher = more information), or 0.0 if the state is invalid
  * 
  * # Performance
- * - Time complexity: O(1) for 5-letter words
- * - Space complexity: O(1)
+ * - Time complexity: O(n) where n is the guess word list
  */
+#[flutter_rust_bridge::frb(sync)]
+pub fn calculate_entropy_for_state(
+    candidate_word: String,
+    guess_results: Vec<(String, Vec<String>)>,
+) -> f64 {
+    let all_words = lock_recovering(&WORD_MANAGER).get_interned_guess_words();
+
+    let internal_guess_results = match to_internal_guess_results(&guess_results) {
+        Some(results) => results,
+        None => return 0.0,
+    };
+    let remaining_words = to_owned_words(&filter_words_with_feedback(&all_words, &internal_guess_results));
+
+    let solver = IntelligentSolver::new(vec![]);
+    solver.calculate_entropy(&candidate_word.to_uppercase(), &remaining_words)
+}
 
 /**
- * Get all possible remaining words based on current constraints
+ * Evaluate how informative a custom opener is
  * 
- * This function returns all words that could still be the answer
- * based on the current game state and constraints.
+ * Returns the opener's entropy against the full answer list, so the UI can
+ * compare a user's own first guess with the recommended one.
  * 
  * # Arguments
- * * `guess_results` - Vector of tuples containing (word, result_pattern)
- *   - word: The guessed word (e.g., "TARES")
- *   - result_pattern: Vector of result strings (e.g., ["G", "Y", "Y", "X", "X"])
- *     - "G" = Green (correct letter, correct position)
- *     - "Y" = Yellow (correct letter, wrong position)  
- *     - "X" = Gray (letter not in word)
+ * - `opener`: The first guess to evaluate (case-insensitive)
  * 
  * # Returns
- * * `Vec<String>` - All possible remaining answer words
+ * - Expected information in bits (higher is better), or 0.0 if the word
+ *   lists can't be loaded
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn evaluate_opener(opener: String) -> f64 {
+    let answer_words = match get_answer_words() {
+        Ok(words) => words,
+        Err(_) => return 0.0,
+    };
+
+    let solver = IntelligentSolver::new_from_manager();
+    solver.calculate_entropy(&opener.to_uppercase(), &answer_words)
+}
+
+/// Guess words `rank_openers` scores when no candidates are given
+/// 
+/// Scoring the whole guess list against every answer takes seconds, so only
+/// the words whose letters best split the answers are scored.
+const DEFAULT_OPENER_POOL: usize = 500;
+
+/**
+ * Rank openers by entropy against the full answer list
  * 
- * # Example
- * ```rust
- * let guess_results = vec![
- *     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
- * ];
- * let possible_words = get_possible_words(guess_results);
- * ```
+ * Backs a "best openers" screen. Every candidate is scored against all answer
+ * words, so this is heavy: it runs asynchronously off the UI thread, and the
+ * result is best cached.
+ * 
+ * # Arguments
+ * - `candidates`: Openers to rank (case-insensitive); invalid words are skipped.
+ *   When empty, the 500 loaded guess words whose letters best split the answers
+ *   are ranked
+ * - `top_n`: Maximum number of openers to return
+ * 
+ * # Returns
+ * - (word, entropy in bits) pairs, best first, ties broken alphabetically.
+ *   Empty if `top_n` is not positive or the word lists can't be loaded
+ */
+pub fn rank_openers(candidates: Vec<String>, top_n: i32) -> Vec<(String, f64)> {
+    if top_n <= 0 {
+        return Vec::new();
+    }
+    let answer_words = match get_answer_words() {
+        Ok(words) => words,
+        Err(_) => return Vec::new(),
+    };
+
+    let candidates: Vec<String> = if candidates.is_empty() {
+        match get_guess_words() {
+            Ok(words) => IntelligentSolver::best_letter_splitters(to_owned_words(&words), &answer_words, DEFAULT_OPENER_POOL),
+            Err(_) => return Vec::new(),
+        }
+    } else {
+        let uppercased: Vec<String> = candidates.iter()
+            .map(|word| word.to_uppercase())
+            .filter(|word| crate::solver_core::is_valid_word(word))
+            .collect();
+        dedup_preserving_order(&uppercased)
+    };
+
+    let solver = IntelligentSolver::new_from_manager();
+    solver.score_all_candidates(&candidates, &answer_words, top_n as usize, true)
+}
+
+/**
+ * Simulate guess pattern for testing
+ * 
+ * This function simulates what pattern would result from guessing
+ * a word against a target word.
+ * 
+ * # Arguments
+ * - `guess`: The word being guessed
+ * - `target`: The target word
+ * 
+ * # Returns
+ * Pattern string like "GGYXY" (G=Green, Y=Yellow, X=Gray)
+ * 
+ * # Performance
+ * - Time complexity: O(1) for 5-letter words
+ * - Space complexity: O(1)
  */
+
+/// Get all possible remaining words based on current constraints
+///
+/// This function returns all words that could still be the answer
+/// based on the current game state and constraints. Words come from the
+/// in-memory word manager; the assets are only read if `initialize_word_lists`
+/// hasn't run yet.
+///
+/// # Arguments
+/// * `guess_results` - Vector of tuples containing (word, result_pattern)
+///   - word: The guessed word (e.g., "TARES")
+///   - result_pattern: Vector of result strings (e.g., ["G", "Y", "Y", "X", "X"])
+///     - "G" = Green (correct letter, correct position)
+///     - "Y" = Yellow (correct letter, wrong position)
+///     - "X" = Gray (letter not in word)
+///
+/// # Returns
+/// * `Vec<String>` - All possible remaining answer words
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_possible_words;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let possible_words = get_possible_words(guess_results);
+/// ```
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_possible_words(
     guess_results: Vec<(String, Vec<String>)>,
@@ -546,49 +1051,121 @@ pub fn get_possible_words(
         Err(_) => return Vec::new(),
     };
 
-    // Convert FFI format to internal format
-    let internal_guess_results: Vec<crate::api::wrdl_helper::GuessResult> = guess_results.iter()
-        .map(|(word, pattern)| {
-            let results = pattern.iter().map(|p| match p.as_str() {
-                "G" => crate::api::wrdl_helper::LetterResult::Green,
-                "Y" => crate::api::wrdl_helper::LetterResult::Yellow,
-                "X" => crate::api::wrdl_helper::LetterResult::Gray,
-                _ => crate::api::wrdl_helper::LetterResult::Gray,
-            }).collect::<Vec<_>>();
-
-            crate::api::wrdl_helper::GuessResult {
-                word: word.clone(),
-                results: [results[0], results[1], results[2], results[3], results[4]].to_vec(),
-            }
-        })
-        .collect();
+    // Convert FFI format to internal format, dropping rows submitted twice
+    let internal_guess_results = match to_internal_guess_results(&guess_results) {
+        Some(results) => dedup_guess_results(&results),
+        None => return Vec::new(),
+    };
 
     // Use the same filtering logic as get_best_guess
-    filter_words_with_feedback(&all_words, &internal_guess_results)
+    to_owned_words(&filter_words_with_feedback(&all_words, &internal_guess_results))
 }
 
 /**
- * Get count of possible remaining words based on current constraints
+ * Get the possible words from a caller-supplied answer pool
  * 
- * This function returns the count of words that could still be the answer
- * based on the current game state and constraints. This is a lightweight
- * alternative to get_possible_words() for UI updates.
+ * Same filtering as `get_possible_words`, but over `universe` instead of the
+ * loaded word lists, for variants with a themed answer pool. `WORD_MANAGER` is
+ * not touched.
  * 
  * # Arguments
- * * `guess_results` - Vector of tuples containing (word, result_pattern)
+ * - `universe`: Candidate answers (any case)
+ * - `guess_results`: Previous guess results with patterns
  * 
  * # Returns
- * * `i32` - Count of possible remaining answer words
+ * - The words of `universe` (uppercased, in order) consistent with every guess,
+ *   or empty if an entry isn't a 5-letter word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_possible_words_in(
+    universe: Vec<String>,
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Vec<String> {
+    if let Some(message) = universe.iter().find_map(|word| validate_word(word).err()) {
+        set_last_error(message);
+        return Vec::new();
+    }
+    let universe: Vec<String> = universe.iter().map(|word| word.to_uppercase()).collect();
+
+    match to_internal_guess_results(&guess_results) {
+        Some(internal_guess_results) => filter_words_with_feedback(&universe, &internal_guess_results),
+        None => Vec::new(),
+    }
+}
+
+/**
+ * Get the possible words for a shared Wordle result
  * 
- * # Example
- * ```rust
- * let guess_results = vec![
- *     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
- * ];
- * let count = get_possible_word_count(guess_results);
- * ```
+ * Shared games are emoji grids, one row per guess. Each row is converted to a
+ * G/Y/X pattern and paired with the matching guess, then filtered like
+ * `get_possible_words`. Both the standard (🟩 🟨) and high-contrast (🟧 🟦)
+ * colors are accepted, with ⬛ or ⬜ for gray; surrounding whitespace is ignored.
+ * 
+ * # Arguments
+ * - `guesses`: The guessed words, in order
+ * - `emoji_rows`: One emoji row per guess, e.g. "🟩⬛🟨⬛⬛"
+ * 
+ * # Returns
+ * - The possible words, or empty if the counts differ or a row isn't 5 known
+ *   emoji (see `get_last_error`)
  */
 #[flutter_rust_bridge::frb(sync)]
+pub fn reconstruct_remaining_from_emoji(guesses: Vec<String>, emoji_rows: Vec<String>) -> Vec<String> {
+    if guesses.len() != emoji_rows.len() {
+        set_last_error(format!("Got {} guesses but {} emoji rows", guesses.len(), emoji_rows.len()));
+        return Vec::new();
+    }
+
+    let mut guess_results = Vec::new();
+    for (guess, row) in guesses.into_iter().zip(&emoji_rows) {
+        match emoji_row_to_pattern(row) {
+            Ok(pattern) => guess_results.push((guess, pattern)),
+            Err(message) => {
+                set_last_error(message);
+                return Vec::new();
+            }
+        }
+    }
+    get_possible_words(guess_results)
+}
+
+/// Convert one shared-result emoji row into "G"/"Y"/"X" tokens
+fn emoji_row_to_pattern(row: &str) -> Result<Vec<String>, String> {
+    let pattern = row.trim().chars().map(|emoji| match emoji {
+        '🟩' | '🟧' => Ok("G".to_string()),
+        '🟨' | '🟦' => Ok("Y".to_string()),
+        '⬛' | '⬜' => Ok("X".to_string()),
+        other => Err(format!("Unknown emoji '{}' in row '{}'", other, row)),
+    }).collect::<Result<Vec<_>, _>>()?;
+
+    if pattern.len() != 5 {
+        return Err(format!("Emoji row '{}' has {} tiles, expected 5", row, pattern.len()));
+    }
+    Ok(pattern)
+}
+
+/// Get count of possible remaining words based on current constraints
+///
+/// This function returns the count of words that could still be the answer
+/// based on the current game state and constraints. This is a lightweight
+/// alternative to get_possible_words() for UI updates.
+///
+/// # Arguments
+/// * `guess_results` - Vector of tuples containing (word, result_pattern)
+///
+/// # Returns
+/// * `i32` - Count of possible remaining answer words
+///
+/// # Example
+/// ```no_run
+/// use rust_lib_wrdlhelper::api::simple::get_possible_word_count;
+///
+/// let guess_results = vec![
+///     ("TARES".to_string(), vec!["G".to_string(), "Y".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])
+/// ];
+/// let count = get_possible_word_count(guess_results);
+/// ```
+#[flutter_rust_bridge::frb(sync)]
 pub fn get_possible_word_count(
     guess_results: Vec<(String, Vec<String>)>,
 ) -> i32 {
@@ -597,227 +1174,1943 @@ pub fn get_possible_word_count(
 }
 
 /**
- * Get best guess from game state (SINGLE SERVER FUNCTION)
+ * Check whether a single word is still possible
  * 
- * This is the main entry point for the client-server architecture.
- * Takes game state, handles all filtering and algorithm logic internally,
- * and returns the best guess.
+ * Agrees with membership in `get_possible_words` but checks only `word`, so a UI
+ * can grey out impossible words in a long list without building the full
+ * possible-words list for each one.
  * 
  * # Arguments
- * - `guess_results`: Vector of (word, pattern) tuples from game state
+ * - `word`: Word to check (any case)
+ * - `guess_results`: Previous guess results with patterns
  * 
  * # Returns
- * - Best guess word, or None if no valid guess available
- * 
- * # Architecture
- * - Client sends: get_best_guess(gameState)
- * - Server handles: Filter words + Run algorithms + Return best guess
+ * - `true` if `word` would be in `get_possible_words(guess_results)`
  */
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_best_guess(
+pub fn is_word_still_possible(
+    word: String,
     guess_results: Vec<(String, Vec<String>)>,
-) -> Option<String> {
-    // Special case: First guess (no constraints) - use optimal first guess
+) -> bool {
+    let word = word.to_uppercase();
+    if ensure_word_lists_loaded().is_err() {
+        return false;
+    }
+
+    // With no constraints every answer word is possible
     if guess_results.is_empty() {
-        return get_optimal_first_guess();
+        return lock_recovering(&WORD_MANAGER).is_possible_answer(&word);
     }
-    
-    // Get all words for the solver (14,855 guess words including 2,300 answer words)
-    let all_words = match get_guess_words() {
-        Ok(words) => words,
-        Err(_) => return None,
+
+    let internal_guess_results = match to_internal_guess_results(&guess_results) {
+        Some(results) => results,
+        None => return false,
     };
-    
-    // COPY EXACT LOGIC FROM WORKING BENCHMARK (98-99% success rate)
-    // Convert FFI format to internal format
-    let internal_guess_results: Vec<crate::api::wrdl_helper::GuessResult> = guess_results.iter()
-        .map(|(word, pattern)| {
-            let results = pattern.iter().map(|p| match p.as_str() {
-                "G" => crate::api::wrdl_helper::LetterResult::Green,
-                "Y" => crate::api::wrdl_helper::LetterResult::Yellow,
-                "X" => crate::api::wrdl_helper::LetterResult::Gray,
-                _ => crate::api::wrdl_helper::LetterResult::Gray,
-            }).collect::<Vec<_>>();
-            
-            crate::api::wrdl_helper::GuessResult {
-                word: word.clone(),
-                results: [results[0], results[1], results[2], results[3], results[4]].to_vec(),
-            }
-        })
-        .collect();
-    
-    // Use the EXACT same filtering logic as the working benchmark
-    let eligible_words = filter_words_with_feedback(&all_words, &internal_guess_results);
-    
-    if eligible_words.is_empty() {
-        return None; // No eligible words remaining
-    }
-    
-    // Use the 98.2% algorithm directly (bypassing the old get_intelligent_guess)
-    use crate::api::wrdl_helper::IntelligentSolver;
-    
-    let solver = IntelligentSolver::new(all_words);
-    solver.get_best_guess(&eligible_words, &internal_guess_results)
+    let exclude_prior_guesses = lock_recovering(&SOLVER_CONFIG).exclude_prior_guesses;
+
+    crate::constraints::word_matches_all_feedback(&word, &internal_guess_results)
+        && !(exclude_prior_guesses && internal_guess_results.iter().any(|gr| gr.word == word))
+        && lock_recovering(&WORD_MANAGER).is_allowed_guess(&word)
 }
 
 /**
- * COPY EXACT FILTERING LOGIC FROM WORKING BENCHMARK
- * These functions were achieving 98-99% success rate
+ * Check one word against the feedback of a single guess
+ * 
+ * A lightweight alternative to `is_word_still_possible` when only one guess
+ * matters: no word lists are loaded and dictionary membership isn't checked.
+ * 
+ * # Arguments
+ * - `candidate`: Word to check (any case)
+ * - `guess_word`: The guessed word (any case)
+ * - `pattern`: Feedback for the guess as 5 tokens: "G", "Y" or "X" (any case)
+ * 
+ * # Returns
+ * - `true` if `candidate` could be the answer given this feedback; `false` for
+ *   invalid input, with the reason available from `get_last_error`
  */
+#[flutter_rust_bridge::frb(sync)]
+pub fn word_matches_feedback(
+    candidate: String,
+    guess_word: String,
+    pattern: Vec<String>,
+) -> bool {
+    let candidate = candidate.to_uppercase();
+    let guess_word = guess_word.to_uppercase();
+    if let Err(message) = validate_word(&candidate) {
+        set_last_error(message);
+        return false;
+    }
 
-/// Filter words based on feedback from all guesses
-fn filter_words_with_feedback(words: &[String], guess_results: &[crate::api::wrdl_helper::GuessResult]) -> Vec<String> {
-    words.iter()
-        .filter(|word| word_matches_all_feedback(word, guess_results))
-        .cloned()
-        .collect()
-}
-
-/// Check if a word matches all feedback from previous guesses
-fn word_matches_all_feedback(candidate: &str, guess_results: &[crate::api::wrdl_helper::GuessResult]) -> bool {
-    for guess_result in guess_results {
-        if !word_matches_single_feedback(candidate, guess_result) {
-            return false;
-        }
+    let pattern = pattern.iter().map(|token| token.to_uppercase()).collect();
+    match to_internal_guess_results(&[(guess_word, pattern)]) {
+        Some(guess_results) => crate::constraints::word_matches_single_feedback(&candidate, &guess_results[0]),
+        None => false,
     }
-    true
 }
 
-/// Check if a word matches feedback from a single guess
-fn word_matches_single_feedback(candidate: &str, guess_result: &crate::api::wrdl_helper::GuessResult) -> bool {
-    let candidate_chars: Vec<char> = candidate.chars().collect();
-    let guess_chars: Vec<char> = guess_result.word.chars().collect();
-
-    // Check green letters (exact position matches)
-    for i in 0..5 {
-        if guess_result.results[i] == crate::api::wrdl_helper::LetterResult::Green {
-            if candidate_chars[i] != guess_chars[i] {
-                return false;
-            }
-        }
+/**
+ * Get how many possible words the most recent guess eliminated
+ * 
+ * For a post-guess message like "that guess eliminated 1,842 words!". Both
+ * counts are `get_possible_word_count` values: before uses every guess but the
+ * last, after uses all of them.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns, most recent last
+ * 
+ * # Returns
+ * - `(before, after)`: Possible words before and after the last guess. With no
+ *   guesses both are the full answer count.
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn information_gained(
+    guess_results: Vec<(String, Vec<String>)>,
+) -> (i32, i32) {
+    let after = get_possible_word_count(guess_results.clone());
+    if guess_results.is_empty() {
+        return (after, after);
     }
+    let before = get_possible_word_count(guess_results[..guess_results.len() - 1].to_vec());
+    (before, after)
+}
 
-    // Check yellow letters (letter exists but not in this position)
-    for i in 0..5 {
-        if guess_result.results[i] == crate::api::wrdl_helper::LetterResult::Yellow {
-            let letter = guess_chars[i];
-            // Letter can't be in the same position
-            if candidate_chars[i] == letter {
-                return false;
-            }
-            // Letter must exist somewhere else
-            if !candidate_chars.contains(&letter) {
-                return false;
-            }
-        }
+/**
+ * Get the answer once the game is effectively solved
+ * 
+ * Lets the UI auto-fill the answer as soon as the constraints pin it down,
+ * even before it has been guessed.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns
+ * 
+ * # Returns
+ * - `Some(word)` when exactly one possible word remains, otherwise None
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_game_solved(
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Option<String> {
+    let mut possible_words = get_possible_words(guess_results);
+    if possible_words.len() == 1 {
+        possible_words.pop()
+    } else {
+        None
     }
+}
 
-    // Check gray letters (letter doesn't exist or we have enough)
-    use std::collections::HashMap;
-    let mut required_counts: HashMap<char, usize> = HashMap::new();
-    for i in 0..5 {
-        if guess_result.results[i] == crate::api::wrdl_helper::LetterResult::Green || 
-           guess_result.results[i] == crate::api::wrdl_helper::LetterResult::Yellow {
-            let letter = guess_chars[i];
-            *required_counts.entry(letter).or_insert(0) += 1;
-        }
-    }
+/**
+ * Get a single-letter hint
+ * 
+ * A gentler alternative to `get_best_guess`: the letter not yet tried that
+ * appears in the most remaining possible words. Letters from previous guesses
+ * are skipped, since their presence or absence is already known.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns
+ * 
+ * # Returns
+ * - The hint letter (e.g. "O"), alphabetically first on ties, or None if no
+ *   words remain or every letter in them has been tried
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_letter_hint(
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Option<String> {
+    let tried_letters: std::collections::HashSet<char> = guess_results.iter()
+        .flat_map(|(word, _)| word.to_uppercase().chars().collect::<Vec<_>>())
+        .collect();
 
-    for i in 0..5 {
-        if guess_result.results[i] == crate::api::wrdl_helper::LetterResult::Gray {
-            let letter = guess_chars[i];
-            let required_count = required_counts.get(&letter).copied().unwrap_or(0);
-            let actual_count = candidate_chars.iter().filter(|&&c| c == letter).count();
-            
-            if actual_count > required_count {
-                return false;
-            }
+    let mut letter_counts: std::collections::HashMap<char, usize> = std::collections::HashMap::new();
+    for word in get_possible_words(guess_results) {
+        let letters: std::collections::BTreeSet<char> = word.chars().collect();
+        for letter in letters.into_iter().filter(|letter| !tried_letters.contains(letter)) {
+            *letter_counts.entry(letter).or_insert(0) += 1;
         }
     }
 
-    true
+    // Highest count wins, the alphabetically first letter on ties
+    letter_counts.into_iter()
+        .max_by(|(letter_a, count_a), (letter_b, count_b)| count_a.cmp(count_b).then(letter_b.cmp(letter_a)))
+        .map(|(letter, _)| letter.to_string())
 }
 
+/**
+ * Get at most `max` possible words, plus whether the list was truncated
+ * 
+ * Early in a game thousands of words are still possible, but the UI only shows a
+ * few. This avoids sending the whole list across FFI on every keystroke.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns
+ * - `max`: Maximum number of words to return (negative values are treated as 0)
+ * 
+ * # Returns
+ * - `(words, truncated, total)`: The first `max` possible words in alphabetical
+ *   order, whether any were left out, and the total number of possible words
+ */
 #[flutter_rust_bridge::frb(sync)]
-pub fn simulate_guess_pattern(guess: String, target: String) -> String {
-    let solver = IntelligentSolver::new(vec![]);
-    solver.simulate_guess_pattern(&guess, &target)
+pub fn get_possible_words_capped(
+    guess_results: Vec<(String, Vec<String>)>,
+    max: i32,
+) -> (Vec<String>, bool, i32) {
+    let mut possible_words = get_possible_words(guess_results);
+    let total = possible_words.len();
+    possible_words.sort();
+    possible_words.truncate(max.max(0) as usize);
+    let truncated = possible_words.len() < total;
+    (possible_words, truncated, total as i32)
 }
 
 /**
- * Set solver configuration
+ * Get the possible answer most worth guessing when it has to be able to win now
  * 
- * This function sets the global solver configuration for all subsequent operations.
+ * Only answer words consistent with the feedback are considered, so the guess
+ * can always be correct this turn. Among them it picks the one that leaves the
+ * fewest answers on average if it turns out to be wrong: a two-ply search
+ * restricted to the possible answers.
+ * 
+ * With no guesses yet this is the precomputed opener, as in `get_best_guess`:
+ * the search over the whole answer list would take over a second.
  * 
  * # Arguments
- * - `config`: Configuration struct with all solver settings
+ * - `guess_results`: Previous guess results with patterns
  * 
- * # Performance
- * - Time complexity: O(1)
- * - Space complexity: O(1)
+ * # Returns
+ * - The chosen word, alphabetically first on ties, or None if no answers remain
  */
 #[flutter_rust_bridge::frb(sync)]
-pub fn set_solver_config(
-    reference_mode: bool,
-    include_killer_words: bool,
-    candidate_cap: i32,
-    early_termination_enabled: bool,
-    early_termination_threshold: f64,
-    entropy_only_scoring: bool,
-) {
-    use crate::api::wrdl_helper::{SOLVER_CONFIG, SolverConfig};
-    
-    let mut config = SOLVER_CONFIG.lock().unwrap();
-    *config = SolverConfig {
-        reference_mode,
-        include_killer_words,
-        candidate_cap,
-        early_termination_enabled,
-        early_termination_threshold,
-        entropy_only_scoring,
-    };
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-
-
+pub fn get_best_winning_guess(
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Option<String> {
+    ensure_word_lists_loaded().ok()?;
+    if guess_results.is_empty() {
+        return get_optimal_first_guess();
+    }
 
+    let internal_guess_results = dedup_guess_results(&to_internal_guess_results(&guess_results)?);
+    let mut possible_answers = lock_recovering(&WORD_MANAGER).get_possible_answers(&internal_guess_results);
+    possible_answers.sort();
 
+    // `min_by` keeps the first of equal scores, i.e. the alphabetically first word
+    possible_answers.iter()
+        .map(|guess| (guess, expected_remaining_if_wrong(guess, &possible_answers)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(guess, _)| guess.clone())
+}
 
+/**
+ * Get the best guess given how many guesses the player has left
+ * 
+ * On the last allowed guess a word that can't be the answer guarantees a loss,
+ * however informative it is. So with one guess (or none) left the choice is
+ * restricted to possible answers via `get_best_winning_guess`, whatever the
+ * solver config says; otherwise this is `get_best_guess`.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns
+ * - `guesses_remaining`: Guesses the player has left, including this one
+ * 
+ * # Returns
+ * - The word to guess next, or None if no words remain
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_best_guess_with_budget(
+    guess_results: Vec<(String, Vec<String>)>,
+    guesses_remaining: i32,
+) -> Option<String> {
+    if guesses_remaining <= 1 {
+        get_best_winning_guess(guess_results)
+    } else {
+        get_best_guess(guess_results)
+    }
+}
 
+/// Average number of words still possible after guessing `guess` and missing
+/// 
+/// Each other possible word is equally likely to be the answer, and leaves
+/// every word that shares its feedback pattern.
+fn expected_remaining_if_wrong(guess: &str, possible_words: &[String]) -> f64 {
+    let mut bucket_sizes: std::collections::HashMap<[u8; 5], usize> = std::collections::HashMap::new();
+    for target in possible_words.iter().filter(|target| target.as_str() != guess) {
+        *bucket_sizes.entry(crate::solver_core::simulate_guess_ranks(guess, target)).or_insert(0) += 1;
+    }
 
+    let misses: usize = bucket_sizes.values().sum();
+    if misses == 0 {
+        return 0.0;
+    }
+    bucket_sizes.values().map(|&size| (size * size) as f64).sum::<f64>() / misses as f64
+}
 
+/**
+ * Get the distribution of feedback patterns a guess would produce
+ * 
+ * For every pattern the guess could produce against the remaining possible
+ * words, reports how many words produce it and how likely it is. This is the
+ * data behind entropy, surfaced directly for an educational chart.
+ * 
+ * # Arguments
+ * - `guess`: Word to evaluate
+ * - `guess_results`: Previous guess results with patterns
+ * 
+ * # Returns
+ * - `(pattern, count, probability)` per pattern (e.g. ("XYXXG", 12, 0.05)),
+ *   most likely first; empty if no words remain or `guess` isn't a 5-letter
+ *   word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn guess_outcome_distribution(
+    guess: String,
+    guess_results: Vec<(String, Vec<String>)>,
+) -> Vec<(String, i32, f64)> {
+    if let Err(message) = validate_word(&guess) {
+        set_last_error(message);
+        return Vec::new();
+    }
+    let guess = guess.to_uppercase();
+    let remaining_words = get_possible_words(guess_results);
+    if remaining_words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut pattern_counts: std::collections::HashMap<String, i32> = std::collections::HashMap::new();
+    for target in &remaining_words {
+        *pattern_counts.entry(crate::solver_core::simulate_guess_pattern(&guess, target)).or_insert(0) += 1;
+    }
+
+    let total = remaining_words.len() as f64;
+    let mut distribution: Vec<(String, i32, f64)> = pattern_counts.into_iter()
+        .map(|(pattern, count)| (pattern, count, count as f64 / total))
+        .collect();
+    // Most likely first, ties in pattern order so the chart is stable
+    distribution.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    distribution
+}
+
+/**
+ * Group the possible words by the feedback a guess would produce
+ * 
+ * The words behind `guess_outcome_distribution`, for a "decision tree" view:
+ * each pattern the candidate could produce with the words that would remain.
+ * 
+ * # Arguments
+ * - `candidate`: Word to evaluate
+ * - `guess_results`: Previous guess results with patterns
+ * - `max_words_per_bucket`: Maximum words listed per pattern, to bound the payload
+ *   (negative values are treated as 0)
+ * 
+ * # Returns
+ * - `(pattern, words)` per pattern, largest bucket first (ties in pattern order),
+ *   with words in alphabetical order; empty if no words remain or `candidate`
+ *   isn't a 5-letter word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn guess_pattern_buckets(
+    candidate: String,
+    guess_results: Vec<(String, Vec<String>)>,
+    max_words_per_bucket: i32,
+) -> Vec<(String, Vec<String>)> {
+    if let Err(message) = validate_word(&candidate) {
+        set_last_error(message);
+        return Vec::new();
+    }
+    let candidate = candidate.to_uppercase();
+    let mut remaining_words = get_possible_words(guess_results);
+    remaining_words.sort();
+
+    let mut buckets: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    for target in remaining_words {
+        buckets.entry(crate::solver_core::simulate_guess_pattern(&candidate, &target)).or_default().push(target);
+    }
+
+    let mut buckets: Vec<(String, Vec<String>)> = buckets.into_iter().collect();
+    buckets.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+    for (_, words) in &mut buckets {
+        words.truncate(max_words_per_bucket.max(0) as usize);
+    }
+    buckets
+}
+
+/**
+ * Preview how many words a guess is expected to leave
+ * 
+ * The same information as entropy, in words instead of bits, for a UI hint
+ * like "if you guess X, you'll likely have ~N words left". Each pattern leaves
+ * its bucket of words, weighted by how likely the pattern is.
+ * 
+ * # Arguments
+ * - `candidate`: Word to preview (case-insensitive)
+ * - `guess_results`: Previous guess results with patterns
+ * 
+ * # Returns
+ * - Expected number of possible words after guessing `candidate` (lower is
+ *   better), or 0.0 if no words remain or `candidate` isn't a 5-letter word
+ *   (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_guess(
+    candidate: String,
+    guess_results: Vec<(String, Vec<String>)>,
+) -> f64 {
+    guess_outcome_distribution(candidate, guess_results).iter()
+        .map(|&(_, count, probability)| count as f64 * probability)
+        .sum()
+}
+
+/**
+ * Simulate the feedback for a guess against a known answer
+ * 
+ * # Arguments
+ * - `guess`: The guessed word (case-insensitive)
+ * - `target`: The answer word (case-insensitive)
+ * 
+ * # Returns
+ * - The pattern as five characters, e.g. "GYXXG"; empty if either word isn't
+ *   a 5-letter word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn simulate_guess_pattern(guess: String, target: String) -> String {
+    if let Err(message) = validate_word(&guess).and_then(|()| validate_word(&target)) {
+        set_last_error(message);
+        return String::new();
+    }
+    // Normalize case to match the uppercased word lists
+    let guess = guess.to_uppercase();
+    let target = target.to_uppercase();
+
+    let solver = IntelligentSolver::new(vec![]);
+    solver.simulate_guess_pattern(&guess, &target)
+}
+
+/**
+ * Simulate the feedback for a guess as one token per letter
+ * 
+ * Same feedback as `simulate_guess_pattern`, already split into the
+ * `["G", "Y", "X", ...]` form `get_best_guess` and friends accept.
+ * 
+ * # Arguments
+ * - `guess`: The guessed word (case-insensitive)
+ * - `target`: The answer word (case-insensitive)
+ * 
+ * # Returns
+ * - Five tokens: "G" (Green), "Y" (Yellow), or "X" (Gray); empty if either
+ *   word isn't a 5-letter word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn simulate_guess_pattern_results(guess: String, target: String) -> Vec<String> {
+    if let Err(message) = validate_word(&guess).and_then(|()| validate_word(&target)) {
+        set_last_error(message);
+        return Vec::new();
+    }
+    let solver = IntelligentSolver::new_from_manager();
+    solver.pattern_results(&guess.to_uppercase(), &target.to_uppercase())
+        .into_iter()
+        .map(LetterResult::to_token)
+        .collect()
+}
+
+/**
+ * Recompute the cached optimal first guess
+ * 
+ * The optimal first guess is computed once when word lists load. Call this after
+ * replacing the word lists to refresh it without restarting the app.
+ * 
+ * # Returns
+ * - The new optimal first guess, or None if the manager is unavailable or no
+ *   known opener is in the current guess list
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn recompute_optimal_first_guess() -> Option<String> {
+    let mut manager = lock_recovering(&WORD_MANAGER);
+    manager.compute_optimal_first_guess();
+    manager.get_optimal_first_guess()
+}
+
+/**
+ * Play a game against a known target and explain every guess
+ * 
+ * Uses the same guesses as `get_best_guess` and tags each with a short reason,
+ * for a tutorial that narrates the solver's thinking:
+ * - "optimal opener": The precomputed first guess
+ * - "only one word left": The constraints pin down the answer
+ * - "highest entropy among remaining": The most informative possible answer
+ * - "highest entropy, not a possible answer": A throwaway guess that splits the
+ *   remaining words best
+ * 
+ * # Arguments
+ * - `target`: The answer to solve for (any case)
+ * - `max_guesses`: Maximum number of guesses (negative values are treated as 0)
+ * 
+ * # Returns
+ * - `(guess, rationale, entropy)` per guess, where entropy is in bits over the
+ *   words still possible before the guess. Stops at the target or after
+ *   `max_guesses`; empty if `target` isn't a 5-letter word (see `get_last_error`)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn solve_with_explanations(target: String, max_guesses: i32) -> Vec<(String, String, f64)> {
+    if let Err(message) = validate_word(&target) {
+        set_last_error(message);
+        return Vec::new();
+    }
+    let target = target.to_uppercase();
+    let solver = IntelligentSolver::new_from_manager();
+    let mut guess_results: Vec<(String, Vec<String>)> = Vec::new();
+    let mut steps = Vec::new();
+
+    for _ in 0..max_guesses.max(0) {
+        let remaining_words = get_possible_words(guess_results.clone());
+        let guess = match get_best_guess(guess_results.clone()) {
+            Some(guess) => guess,
+            None => break,
+        };
+
+        let rationale = if guess_results.is_empty() {
+            "optimal opener"
+        } else if remaining_words.len() == 1 {
+            "only one word left"
+        } else if remaining_words.contains(&guess) {
+            "highest entropy among remaining"
+        } else {
+            "highest entropy, not a possible answer"
+        };
+        let entropy = solver.calculate_entropy(&guess, &remaining_words);
+        steps.push((guess.clone(), rationale.to_string(), entropy));
+
+        if guess == target {
+            break;
+        }
+        let pattern = simulate_guess_pattern_results(guess.clone(), target.clone());
+        guess_results.push((guess, pattern));
+    }
+
+    steps
+}
+
+/**
+ * Start a new game
+ *
+ * Clears per-game state (currently the last recorded error) while keeping the
+ * loaded word lists, so the next `get_best_guess` sees a fresh board. Loads the
+ * word lists first if they aren't loaded yet.
+ *
+ * # Returns
+ * - The optimal first guess for the new game, or None if it is unavailable
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn new_game() -> Option<String> {
+    *lock_recovering(&LAST_ERROR) = None;
+    if let Err(e) = ensure_word_lists_loaded() {
+        set_last_error(e);
+        return None;
+    }
+    get_optimal_first_guess()
+}
+
+/// Seed of the shuffled answer order behind `get_daily_answer`
+/// 
+/// Changing it changes every player's daily word, so it should stay fixed.
+const DAILY_ANSWER_SEED: u64 = 20220619;
+
+/**
+ * Get the answer word for a daily challenge
+ * 
+ * Maps a day number onto a seeded shuffle of the answer list as
+ * `shuffled[day_number % len]`, so every player sees the same word on the same
+ * day without a server, and consecutive days aren't alphabetical neighbors.
+ * Negative day numbers wrap around from the end of the list.
+ * 
+ * # Arguments
+ * - `day_number`: Index of the day (e.g. days since the app's launch date)
+ * 
+ * # Returns
+ * - The answer word for that day, or None if the word lists aren't loaded
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_daily_answer(day_number: i32) -> Option<String> {
+    let mut manager = lock_recovering(&WORD_MANAGER);
+    let answer_words = manager.shuffled_answer_slice(DAILY_ANSWER_SEED);
+    if answer_words.is_empty() {
+        return None;
+    }
+    let index = (day_number as i64).rem_euclid(answer_words.len() as i64) as usize;
+    Some(answer_words[index].clone())
+}
+
+/// Largest accepted early termination threshold, in bits
+/// 
+/// No guess carries more than log2(243) ≈ 7.9 bits, so thresholds above that never
+/// trigger. The default of 10.0 relies on this; the limit just leaves headroom.
+const MAX_EARLY_TERMINATION_THRESHOLD: f64 = 16.0;
+
+/**
+ * Set solver configuration
+ * 
+ * This function sets the global solver configuration for all subsequent operations.
+ * 
+ * # Arguments
+ * - `config`: Configuration struct with all solver settings
+ * 
+ * # Candidate Cap
+ * `candidate_cap` is clamped to a minimum of 1. A cap of 0 would otherwise leave
+ * the solver with no candidates to score, so it returns a guess whenever words remain.
+ * 
+ * # Errors
+ * Returns an error, leaving the current config untouched, if:
+ * - `candidate_cap` is negative
+ * - `early_termination_threshold` is NaN, infinite, negative, or above 16 bits
+ * 
+ * Callers that ignored the old `()` return keep working; valid input behaves
+ * exactly as before.
+ * 
+ * # Performance
+ * - Time complexity: O(1)
+ * - Space complexity: O(1)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_solver_config(
+    reference_mode: bool,
+    include_killer_words: bool,
+    candidate_cap: i32,
+    early_termination_enabled: bool,
+    early_termination_threshold: f64,
+    entropy_only_scoring: bool,
+) -> Result<(), String> {
+    use crate::api::wrdl_helper::SolverConfig;
+
+    if candidate_cap < 0 {
+        return Err(format!("Candidate cap must not be negative, got {}", candidate_cap));
+    }
+    if !(0.0..=MAX_EARLY_TERMINATION_THRESHOLD).contains(&early_termination_threshold) {
+        return Err(format!(
+            "Early termination threshold must be between 0 and {} bits, got {}",
+            MAX_EARLY_TERMINATION_THRESHOLD, early_termination_threshold
+        ));
+    }
+    
+    let mut config = lock_recovering(&SOLVER_CONFIG);
+    *config = SolverConfig {
+        reference_mode,
+        include_killer_words,
+        candidate_cap: candidate_cap.max(1),
+        early_termination_enabled,
+        early_termination_threshold,
+        entropy_only_scoring,
+        ..config.clone()
+    };
+    Ok(())
+}
+
+/**
+ * Set the solver configuration from a difficulty preset
+ * 
+ * A friendlier alternative to `set_solver_config` for casual users:
+ * - "easy": Only guesses words that are still possible, scores at most 50
+ *   candidates, and stops at the first new best guess with 3.0 bits
+ * - "balanced": The default configuration: killer words, at most 100 candidates
+ * - "max": Killer words, every candidate scored
+ * 
+ * All presets use pure entropy scoring. Game rule settings (`strict_patterns`,
+ * `exclude_prior_guesses`), `prefer_unique_letters`, `prime_suspect_bonus`, and
+ * `full_scoring_max_remaining` are left as they are.
+ * 
+ * # Arguments
+ * - `level`: "easy", "balanced", or "max" (case-insensitive)
+ * 
+ * # Returns
+ * - `Ok(())` on success, or an error for an unknown level (config unchanged)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_solver_difficulty(level: String) -> Result<(), String> {
+    let mut config = lock_recovering(&SOLVER_CONFIG);
+    *config = difficulty_preset(&level, &config)
+        .ok_or_else(|| format!("Unknown difficulty '{}': expected easy, balanced, or max", level))?;
+    Ok(())
+}
+
+/**
+ * Reset the solver configuration to its defaults
+ * 
+ * Restores every setting, including the game rule settings that
+ * `set_solver_config` and `set_solver_difficulty` leave untouched.
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_solver_config() {
+    *lock_recovering(&SOLVER_CONFIG) = crate::api::wrdl_helper::SolverConfig::default();
+}
+
+/**
+ * Compare the best guesses of two solver configs on the same state
+ * 
+ * Computes `get_best_guess` under each config without touching the global
+ * solver config, so concurrent solver calls are unaffected. The configs are used
+ * as given; `candidate_cap` is still floored at 1 by the solver.
+ * 
+ * # Arguments
+ * - `guess_results`: Previous guess results with patterns
+ * - `config_a`, `config_b`: The configs to compare
+ * 
+ * # Returns
+ * - `(guess_a, guess_b, agree)`: The best guess under each config and whether they match
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn guesses_agree(
+    guess_results: Vec<(String, Vec<String>)>,
+    config_a: crate::api::wrdl_helper::SolverConfig,
+    config_b: crate::api::wrdl_helper::SolverConfig,
+) -> (Option<String>, Option<String>, bool) {
+    let guess_a = best_guess_with_config(&guess_results, &config_a);
+    let guess_b = best_guess_with_config(&guess_results, &config_b);
+    let agree = guess_a == guess_b;
+    (guess_a, guess_b, agree)
+}
+
+/// Build the config for a difficulty preset, keeping the game rule settings of `current`
+fn difficulty_preset(level: &str, current: &crate::api::wrdl_helper::SolverConfig) -> Option<crate::api::wrdl_helper::SolverConfig> {
+    use crate::api::wrdl_helper::{CandidateSource, SolverConfig};
+
+    let (include_killer_words, candidate_source, candidate_cap, early_termination_enabled, early_termination_threshold) =
+        match level.to_ascii_lowercase().as_str() {
+            "easy" => (false, CandidateSource::RemainingOnly, 50, true, 3.0),
+            "balanced" => (true, CandidateSource::RemainingPlusStrategic, 100, false, 10.0),
+            "max" => (true, CandidateSource::RemainingPlusStrategic, i32::MAX, false, 10.0),
+            _ => return None,
+        };
+
+    Some(SolverConfig {
+        reference_mode: false,
+        include_killer_words,
+        candidate_cap,
+        early_termination_enabled,
+        early_termination_threshold,
+        entropy_only_scoring: true,
+        candidate_source,
+        ..current.clone()
+    })
+}
+
+/**
+ * Get the solver algorithm identifier and active configuration
+ * 
+ * Lets the app log exactly which solver variant produced a suggestion, which
+ * makes support reports reproducible.
+ * 
+ * # Returns
+ * - `(algorithm, config)`: The algorithm name and crate version (e.g.
+ *   "wrdlhelper-entropy/0.1.0"), and a compact serialization of the active
+ *   `SolverConfig` (e.g. "ref=0,killer=1,cap=100,...")
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_solver_info() -> (String, String) {
+    let algorithm = format!("wrdlhelper-entropy/{}", env!("CARGO_PKG_VERSION"));
+    let config = lock_recovering(&SOLVER_CONFIG).to_string();
+    (algorithm, config)
+}
+
+/**
+ * Check whether `candidate_cap` cut candidates from the last `get_best_guess`
+ * 
+ * A capped search may miss the globally best guess, so this helps tune
+ * `candidate_cap` against accuracy. The cap (100 by default) is the only limit on
+ * how many candidates are scored. Stopping early on an unbeatable score or through
+ * `early_termination_enabled` isn't counted, since it can't miss a better guess
+ * (early termination trades that risk for speed on purpose). First guesses,
+ * solved boards, and endgames never select candidates and always report false.
+ * 
+ * # Returns
+ * - `true` if the most recent `get_best_guess` dropped candidates
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn last_guess_was_capped() -> bool {
+    last_guess_capped_count() > 0
+}
+
+/**
+ * Get how many candidates `candidate_cap` cut from the last `get_best_guess`
+ * 
+ * # Returns
+ * - The number of candidates dropped, or 0 if the call wasn't capped
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn last_guess_capped_count() -> i32 {
+    CANDIDATES_DROPPED_BY_CAP.load(Ordering::Relaxed).min(i32::MAX as usize) as i32
+}
+
+/**
+ * Enable or disable strict pattern validation
+ * 
+ * When enabled, `get_best_guess`, `get_intelligent_guess`, and `get_possible_words`
+ * reject patterns containing anything other than "G", "Y", or "X" (or not exactly
+ * 5 tokens). They return None/empty and record the reason for `get_last_error`,
+ * instead of treating unknown tokens as Gray. Disabled by default.
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_strict_patterns(enabled: bool) {
+    lock_recovering(&SOLVER_CONFIG).strict_patterns = enabled;
+}
+
+/**
+ * Enable or disable excluding previously guessed words
+ * 
+ * For Wordle variants where the answer can never repeat an earlier guess. When
+ * enabled, every word already in `guess_results` is removed from the possible
+ * answers and from the candidate guesses. Disabled by default.
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_exclude_prior_guesses(enabled: bool) {
+    lock_recovering(&SOLVER_CONFIG).exclude_prior_guesses = enabled;
+}
+
+/**
+ * Enable or disable favoring unique-letter words in the opening
+ * 
+ * When enabled, words with five distinct letters get a small bonus during the
+ * first two guesses, mirroring the human habit of maximizing letter coverage
+ * early. Disabled by default.
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_prefer_unique_letters(enabled: bool) {
+    lock_recovering(&SOLVER_CONFIG).prefer_unique_letters = enabled;
+}
+
+/**
+ * Choose which words the solver considers as guesses
+ * 
+ * - `RemainingOnly`: Only words that could still be the answer, for players who
+ *   never want a throwaway guess
+ * - `RemainingPlusStrategic`: Remaining words plus the curated strategic words (default)
+ * - `FullDictionary`: Every allowed guess, subject to `candidate_cap`
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_candidate_source(source: crate::api::wrdl_helper::CandidateSource) {
+    lock_recovering(&SOLVER_CONFIG).candidate_source = source;
+}
+
+/**
+ * Set the score bonus for guesses that could still be the answer
+ * 
+ * The default of 0.1 bits lets a possible answer win near-ties against slightly
+ * more informative throwaway words. 0.0 disables it for pure information
+ * maximization.
+ * 
+ * # Returns
+ * - `Ok(())` on success, or an error for a negative or non-finite bonus (config unchanged)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_prime_suspect_bonus(bonus: f64) -> Result<(), String> {
+    if !(bonus.is_finite() && bonus >= 0.0) {
+        return Err(format!("Prime suspect bonus must be a non-negative number, got {}", bonus));
+    }
+    lock_recovering(&SOLVER_CONFIG).prime_suspect_bonus = bonus;
+    Ok(())
+}
+
+/**
+ * Set the largest remaining-word count that gets full entropy scoring
+ * 
+ * With more words left, the solver ranks its strategic shortlist by letter
+ * frequency instead of scoring every candidate's entropy: much faster early in
+ * a game, at a small cost in accuracy. Defaults to `i32::MAX` (always score fully).
+ * 
+ * # Returns
+ * - `Ok(())` on success, or an error for a negative limit (config unchanged)
+ */
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_full_scoring_max_remaining(max_remaining: i32) -> Result<(), String> {
+    if max_remaining < 0 {
+        return Err(format!("Full scoring limit must not be negative, got {}", max_remaining));
+    }
+    lock_recovering(&SOLVER_CONFIG).full_scoring_max_remaining = max_remaining;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_support::lock_global_state;
+
+
+
+
+
+
+
+
+
+
+
+    #[test]
+    fn test_init_app() {
+        // This test ensures init_app doesn't panic
+        init_app();
+    }
+
+
+    // ============================================================================
+    // wrdlHelper Tests
+    // ============================================================================
+
+    #[test]
+    fn test_get_intelligent_guess_basic() {
+        let _globals = lock_global_state();
+        let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
+        let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let guess_results = vec![];
+        
+        let result = get_intelligent_guess(all_words, remaining, guess_results);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_duplicate_remaining_words_are_ignored() {
+        let _globals = lock_global_state();
+        let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string(), "PLATE".to_string()];
+        let deduped = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
+        let duplicated = vec![
+            "CRANE".to_string(), "SLATE".to_string(), "SLATE".to_string(), "CRATE".to_string(), "SLATE".to_string(),
+        ];
+
+        assert_eq!(
+            get_intelligent_guess(all_words.clone(), duplicated.clone(), vec![]),
+            get_intelligent_guess(all_words, deduped.clone(), vec![])
+        );
+        assert_eq!(
+            calculate_entropy("CRANE".to_string(), duplicated),
+            calculate_entropy("CRANE".to_string(), deduped)
+        );
+    }
+
+    #[test]
+    fn test_get_top_two_guesses() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let guess_results = vec![
+            ("TARES".to_string(), vec!["X".to_string(), "Y".to_string(), "X".to_string(), "X".to_string(), "X".to_string()])
+        ];
+
+        let (best, runner_up) = get_top_two_guesses(guess_results.clone());
+        let best = best.expect("best guess");
+        let runner_up = runner_up.expect("runner-up guess");
+
+        assert_eq!(Some(best.clone()), get_best_guess(guess_results));
+        assert_ne!(best, runner_up);
+        assert!(WORD_MANAGER.lock().unwrap().get_guess_words().contains(&runner_up));
+    }
+
+    #[test]
+    fn test_recovers_from_poisoned_globals() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "CUPPA".to_string()))];
+        let expected = get_best_guess(state.clone());
+
+        // Panic while holding both global locks
+        std::thread::scope(|scope| {
+            let result = scope.spawn(|| {
+                let _manager = WORD_MANAGER.lock().unwrap();
+                let _config = SOLVER_CONFIG.lock().unwrap();
+                panic!("simulated panic while holding the solver locks");
+            }).join();
+            assert!(result.is_err());
+        });
+        assert!(WORD_MANAGER.is_poisoned());
+        assert!(SOLVER_CONFIG.is_poisoned());
+
+        assert_eq!(get_best_guess(state.clone()), expected);
+        assert!(!get_possible_words(state).is_empty());
+        set_strict_patterns(false);
+        assert!(!WORD_MANAGER.is_poisoned());
+        assert!(!SOLVER_CONFIG.is_poisoned());
+    }
+
+    #[test]
+    fn test_set_solver_config_rejects_invalid_values() {
+        let _globals = lock_global_state();
+        let info = get_solver_info().1;
+
+        assert!(set_solver_config(false, true, -1, false, 10.0, true).is_err());
+        assert!(set_solver_config(false, true, 100, true, f64::NAN, true).is_err());
+        assert!(set_solver_config(false, true, 100, true, f64::INFINITY, true).is_err());
+        assert!(set_solver_config(false, true, 100, true, -0.5, true).is_err());
+        assert!(set_solver_config(false, true, 100, true, 100.0, true).is_err());
+        // Rejected values leave the config untouched
+        assert_eq!(get_solver_info().1, info);
+
+        assert!(set_solver_config(false, true, 100, true, 4.5, true).is_ok());
+        assert_eq!(SOLVER_CONFIG.lock().unwrap().early_termination_threshold, 4.5);
+    }
+
+    #[test]
+    fn test_zero_candidate_cap_still_returns_guess() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        set_solver_config(false, true, 0, false, 10.0, true).unwrap();
+        assert_eq!(SOLVER_CONFIG.lock().unwrap().candidate_cap, 1);
+
+        let guess_results = vec![
+            ("TARES".to_string(), vec!["X".to_string(), "Y".to_string(), "X".to_string(), "X".to_string(), "X".to_string()])
+        ];
+        let result = get_best_guess(guess_results);
+
+        let guess = result.expect("solver should still return a guess with candidate_cap = 0");
+        assert_eq!(guess.len(), 5);
+    }
+
+    #[test]
+    fn test_recompute_optimal_first_guess() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let original_words = WORD_MANAGER.lock().unwrap().guess_words.clone();
+
+        // TARES is the preferred opener; without it SLATE is next in line
+        WORD_MANAGER.lock().unwrap().guess_words = vec![
+            "SLATE".to_string(), "CRANE".to_string(), "PLATE".to_string(),
+        ];
+        let recomputed = recompute_optimal_first_guess();
+        let cached = WORD_MANAGER.lock().unwrap().get_optimal_first_guess();
+
+        WORD_MANAGER.lock().unwrap().guess_words = original_words;
+        let restored = recompute_optimal_first_guess();
+
+        assert_eq!(recomputed, Some("SLATE".to_string()));
+        assert_eq!(cached, recomputed);
+        assert_eq!(restored, Some("TARES".to_string()));
+    }
+
+    #[test]
+    fn test_new_game() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let opener = get_best_guess(vec![]).unwrap();
+        let guess_count = lock_recovering(&WORD_MANAGER).get_guess_words().len();
+
+        // Play a couple of guesses, then end on an invalid one that records an error
+        let mut state = vec![("TARES".to_string(), vec!["X".to_string(), "X".to_string(), "Y".to_string(), "X".to_string(), "X".to_string()])];
+        let second = get_best_guess(state.clone()).unwrap();
+        assert_ne!(second, opener);
+        state.push(("ZZ".to_string(), vec!["X".to_string(); 5]));
+        assert_eq!(get_best_guess(state), None);
+        assert!(get_last_error().is_some());
+
+        assert_eq!(new_game(), Some(opener.clone()));
+        assert_eq!(get_last_error(), None);
+        assert_eq!(lock_recovering(&WORD_MANAGER).get_guess_words().len(), guess_count);
+        assert_eq!(get_best_guess(vec![]), Some(opener));
+    }
+
+    #[test]
+    fn test_strict_patterns_reject_unknown_tokens() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let corrupted = vec![
+            ("TARES".to_string(), vec!["X".to_string(), "Z".to_string(), "X".to_string(), "X".to_string(), "X".to_string()])
+        ];
+
+        // Default behavior: "Z" is treated as Gray
+        let lenient_guess = get_best_guess(corrupted.clone());
+        assert!(lenient_guess.is_some());
+        assert!(!get_possible_words(corrupted.clone()).is_empty());
+
+        set_strict_patterns(true);
+        let strict_guess = get_best_guess(corrupted.clone());
+        let strict_words = get_possible_words(corrupted.clone());
+        let strict_intelligent = get_intelligent_guess(
+            vec!["CRANE".to_string(), "SLATE".to_string()],
+            vec!["CRANE".to_string(), "SLATE".to_string()],
+            corrupted.clone(),
+        );
+        let last_error = get_last_error();
+        set_strict_patterns(false);
+
+        assert_eq!(strict_guess, None);
+        assert!(strict_words.is_empty());
+        assert_eq!(strict_intelligent, None);
+        assert!(last_error.unwrap().contains("'Z'"));
+
+        // Turning strict mode off restores the defaulting behavior
+        assert_eq!(get_best_guess(corrupted), lenient_guess);
+    }
+
+    #[test]
+    fn test_wrong_length_patterns_are_rejected() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let short = vec![("CRANE".to_string(), vec!["G".to_string(), "X".to_string()])];
+        let long = vec![("CRANE".to_string(), vec!["X".to_string(); 6])];
+
+        // Rejected rather than panicking, also outside strict mode
+        for state in [short, long] {
+            assert_eq!(get_best_guess(state.clone()), None);
+            assert!(get_last_error().unwrap().contains("expected 5"));
+            assert!(get_possible_words(state.clone()).is_empty());
+            assert!(!is_word_still_possible("SLOTH".to_string(), state.clone()));
+            assert_eq!(get_intelligent_guess_reference(vec!["SLOTH".to_string(), "BLIMP".to_string()], state), None);
+        }
+    }
+
+    #[test]
+    fn test_lowercase_guesses_match_uppercase() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let state = |tares: &str, clomp: &str| vec![
+            (tares.to_string(), simulate_guess_pattern_results("TARES".to_string(), "PLUMB".to_string())),
+            (clomp.to_string(), simulate_guess_pattern_results("CLOMP".to_string(), "PLUMB".to_string())),
+        ];
+        let upper = state("TARES", "CLOMP");
+        let lower = state("tares", "cLoMp");
+
+        let possible_words = get_possible_words(lower.clone());
+        assert!(possible_words.contains(&"PLUMB".to_string()));
+        assert_eq!(possible_words, get_possible_words(upper.clone()));
+        assert_eq!(get_best_guess(lower), get_best_guess(upper));
+    }
+
+    #[test]
+    fn test_simulation_rejects_invalid_words() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        // Rejected rather than panicking on the short word
+        assert_eq!(simulate_guess_pattern("CAT".to_string(), "CRANE".to_string()), "");
+        assert!(get_last_error().unwrap().contains("'CAT'"));
+        assert!(simulate_guess_pattern_results("CRANE".to_string(), "CR4NE".to_string()).is_empty());
+        assert!(get_last_error().unwrap().contains("'CR4NE'"));
+
+        assert!(guess_outcome_distribution("CAT".to_string(), vec![]).is_empty());
+        assert_eq!(preview_guess("CRANES".to_string(), vec![]), 0.0);
+        assert!(guess_pattern_buckets("CAT".to_string(), vec![], 3).is_empty());
+        assert!(get_last_error().unwrap().contains("'CAT'"));
+    }
+
+    #[test]
+    fn test_interned_pipeline_matches_owned_words() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let all_words = WORD_MANAGER.lock().unwrap().get_guess_words().to_vec();
+
+        let pattern = |guess: &str, target: &str| -> Vec<String> {
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect()
+        };
+        let states = vec![
+            vec![("TARES".to_string(), pattern("TARES", "CRANE"))],
+            vec![("TARES".to_string(), pattern("TARES", "PLUMB")), ("CLOMP".to_string(), pattern("CLOMP", "PLUMB"))],
+        ];
 
+        for state in states {
+            // The previous owned-String pipeline, spelled out
+            let internal = to_internal_guess_results(&state).unwrap();
+            let eligible = filter_words_with_feedback(&all_words, &internal);
+            let expected = IntelligentSolver::new(all_words.clone()).get_best_guess(&eligible, &internal);
+
+            assert_eq!(get_best_guess(state), expected);
+        }
+    }
 
     #[test]
-    fn test_init_app() {
-        // This test ensures init_app doesn't panic
-        init_app();
-        // If we get here, the function executed successfully
-        assert!(true);
+    fn test_set_solver_difficulty_presets() {
+        let _globals = lock_global_state();
+
+        set_solver_difficulty("easy".to_string()).unwrap();
+        assert_eq!(get_solver_info().1, "ref=0,killer=0,cap=50,early=1,early_threshold=3,entropy_only=1,strict=0,exclude_prior=0,unique=0,source=remaining,suspect_bonus=0.1,full_max=2147483647");
+
+        set_solver_difficulty("Balanced".to_string()).unwrap();
+        assert_eq!(get_solver_info().1, "ref=0,killer=1,cap=100,early=0,early_threshold=10,entropy_only=1,strict=0,exclude_prior=0,unique=0,source=strategic,suspect_bonus=0.1,full_max=2147483647");
+
+        set_solver_difficulty("max".to_string()).unwrap();
+        let max_info = get_solver_info().1;
+        assert_eq!(max_info, format!("ref=0,killer=1,cap={},early=0,early_threshold=10,entropy_only=1,strict=0,exclude_prior=0,unique=0,source=strategic,suspect_bonus=0.1,full_max=2147483647", i32::MAX));
+
+        // An unknown level is rejected and leaves the config untouched
+        assert!(set_solver_difficulty("nightmare".to_string()).is_err());
+        assert_eq!(get_solver_info().1, max_info);
     }
 
+    #[test]
+    fn test_easy_difficulty_only_guesses_possible_words() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        set_solver_difficulty("easy".to_string()).unwrap();
 
-    // ============================================================================
-    // wrdlHelper Tests
-    // ============================================================================
+        for answer in ["MATCH", "JETTY", "ZEALS", "CUPPA"] {
+            let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), answer.to_string()))];
+            let guess = get_best_guess(state.clone()).unwrap();
+            assert!(get_possible_words(state).contains(&guess), "{}: {}", answer, guess);
+        }
+    }
 
     #[test]
-    fn test_get_intelligent_guess_basic() {
-        let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
-        let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
-        let guess_results = vec![];
-        
-        let result = get_intelligent_guess(all_words, remaining, guess_results);
-        assert!(result.is_some());
+    fn test_max_difficulty_scores_every_candidate() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "CUPPA".to_string()))];
+        assert!(get_possible_words(state.clone()).len() > 100);
+
+        set_solver_difficulty("balanced".to_string()).unwrap();
+        get_best_guess(state.clone()).unwrap();
+        assert!(last_guess_was_capped());
+
+        set_solver_difficulty("max".to_string()).unwrap();
+        get_best_guess(state).unwrap();
+        assert!(!last_guess_was_capped());
+    }
+
+    #[test]
+    fn test_reset_solver_config() {
+        let _globals = lock_global_state();
+
+        set_solver_difficulty("easy".to_string()).unwrap();
+        set_strict_patterns(true);
+        reset_solver_config();
+        assert_eq!(get_solver_info().1, crate::api::wrdl_helper::SolverConfig::default().to_string());
+        assert_eq!(get_solver_info().1, "ref=0,killer=1,cap=100,early=0,early_threshold=10,entropy_only=1,strict=0,exclude_prior=0,unique=0,source=strategic,suspect_bonus=0.1,full_max=2147483647");
+    }
+
+    #[test]
+    fn test_guesses_agree() {
+        let _globals = lock_global_state();
+        use crate::api::wrdl_helper::{CandidateSource, SolverConfig};
+        initialize_word_lists().unwrap();
+        set_strict_patterns(true);
+
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+        let remaining_only = SolverConfig { candidate_source: CandidateSource::RemainingOnly, ..SolverConfig::default() };
+
+        // A strategic word splits the remaining words better than any of them does
+        let (guess_a, guess_b, agree) = guesses_agree(state.clone(), remaining_only.clone(), SolverConfig::default());
+        assert!(!agree);
+        let possible_words = get_possible_words(state.clone());
+        assert!(possible_words.contains(&guess_a.unwrap()));
+        assert!(!possible_words.contains(&guess_b.unwrap()));
+
+        let (guess_a, guess_b, agree) = guesses_agree(state.clone(), remaining_only.clone(), remaining_only);
+        assert!(agree);
+        assert_eq!(guess_a, guess_b);
+
+        // The compared configs are used instead of the global one, which is left as it was
+        SOLVER_CONFIG.lock().unwrap().candidate_source = CandidateSource::RemainingOnly;
+        let before = get_solver_info().1;
+        let (guess_a, _, _) = guesses_agree(state, SolverConfig::default(), SolverConfig::default());
+        assert!(!possible_words.contains(&guess_a.unwrap()));
+        assert_eq!(get_solver_info().1, before);
+    }
+
+    #[test]
+    fn test_get_solver_info_tracks_config() {
+        let _globals = lock_global_state();
+        let config = SOLVER_CONFIG.lock().unwrap().clone();
+        let set_entropy_only = |entropy_only: bool| set_solver_config(
+            config.reference_mode,
+            config.include_killer_words,
+            config.candidate_cap,
+            config.early_termination_enabled,
+            config.early_termination_threshold,
+            entropy_only,
+        ).unwrap();
+
+        set_entropy_only(true);
+        let (algorithm, entropy_only_info) = get_solver_info();
+        assert!(algorithm.starts_with("wrdlhelper-entropy/"));
+        assert!(entropy_only_info.contains("entropy_only=1"));
+
+        set_entropy_only(false);
+        let (_, blended_info) = get_solver_info();
+        assert!(blended_info.contains("entropy_only=0"));
+        assert_ne!(entropy_only_info, blended_info);
+    }
+
+    #[test]
+    fn test_streamed_filter_matches_batch_filter() {
+        let _globals = lock_global_state();
+        let words: Vec<String> = ["CRANE", "SLATE", "CRATE", "PLATE", "CLOTH", "BLIMP"]
+            .iter().map(|w| w.to_string()).collect();
+        let state = vec![(
+            "TRACE".to_string(),
+            simulate_guess_pattern("TRACE".to_string(), "CRATE".to_string())
+                .chars().map(|c| c.to_string()).collect(),
+        )];
+
+        let mut streamed = Vec::new();
+        for_each_matching_word(&words, &state, |word| {
+            streamed.push(word);
+            true
+        });
+
+        let internal = to_internal_guess_results(&state).unwrap();
+        assert_eq!(streamed, filter_words_with_feedback(&words, &internal));
+        assert!(streamed.contains(&"CRATE".to_string()));
+
+        // A closed sink stops the stream after the first match
+        let mut first_only = Vec::new();
+        for_each_matching_word(&words, &[], |word| {
+            first_only.push(word);
+            false
+        });
+        assert_eq!(first_only, vec!["CRANE".to_string()]);
+    }
+
+    #[test]
+    fn test_get_possible_words_reads_from_memory() {
+        let _globals = lock_global_state();
+        // Copy the assets somewhere disposable and load from there
+        let temp_dir = std::env::temp_dir().join(format!("wrdlhelper_assets_{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        for file_name in ["official_wordle_words.json", "official_guess_words.txt"] {
+            std::fs::copy(word_list_path(file_name), temp_dir.join(file_name)).unwrap();
+        }
+        set_word_list_dir(temp_dir.to_string_lossy().to_string());
+        initialize_word_lists().unwrap();
+
+        // With the files gone, any further read would fail and return nothing
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+
+        let state = vec![(
+            "TARES".to_string(),
+            simulate_guess_pattern("TARES".to_string(), "CRANE".to_string())
+                .chars().map(|c| c.to_string()).collect(),
+        )];
+        let first = get_possible_words(state.clone());
+        let second = get_possible_words(state);
+        assert!(first.contains(&"CRANE".to_string()));
+        assert_eq!(first, second);
+        assert!(!get_possible_words(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_is_game_solved() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let pattern = |guess: &str, target: &str| -> Vec<String> {
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect()
+        };
+
+        let solved = vec![("CRANE".to_string(), pattern("CRANE", "CRANE"))];
+        assert_eq!(is_game_solved(solved), Some("CRANE".to_string()));
+
+        let open = vec![("TARES".to_string(), pattern("TARES", "CRANE"))];
+        assert!(get_possible_words(open.clone()).len() > 1);
+        assert_eq!(is_game_solved(open), None);
+        assert_eq!(is_game_solved(vec![]), None);
+    }
+
+    #[test]
+    fn test_get_letter_hint() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![(
+            "TARES".to_string(),
+            simulate_guess_pattern("TARES".to_string(), "CRANE".to_string())
+                .chars().map(|c| c.to_string()).collect(),
+        )];
+        let remaining = get_possible_words(state.clone());
+
+        let hint = get_letter_hint(state).unwrap();
+        let letter = hint.chars().next().unwrap();
+        assert_eq!(hint.len(), 1);
+        assert!(!"TARES".contains(letter));
+
+        // No untried letter appears in more remaining words
+        let words_with = |c: char| remaining.iter().filter(|word| word.contains(c)).count();
+        for other in ('A'..='Z').filter(|c| !"TARES".contains(*c)) {
+            assert!(words_with(letter) >= words_with(other), "{} beats hint {}", other, letter);
+        }
+    }
+
+    #[test]
+    fn test_get_possible_words_capped() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = |guess: &str, target: &str| vec![(
+            guess.to_string(),
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect(),
+        )];
+
+        // More possibilities than the cap
+        let wide_state = state("TARES", "CRANE");
+        let all_words = get_possible_words(wide_state.clone());
+        assert!(all_words.len() > 3);
+        let (words, truncated, total) = get_possible_words_capped(wide_state, 3);
+        assert_eq!(words.len(), 3);
+        assert!(truncated);
+        assert_eq!(total as usize, all_words.len());
+        assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Fewer possibilities than the cap
+        let narrow_state = state("CRANE", "CRANE");
+        let (words, truncated, total) = get_possible_words_capped(narrow_state, 10);
+        assert_eq!(words, vec!["CRANE".to_string()]);
+        assert!(!truncated);
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn test_word_lists_json_round_trip() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let stats = || {
+            let manager = WORD_MANAGER.lock().unwrap();
+            (manager.get_answer_words().len(), manager.get_guess_words().len(), manager.get_optimal_first_guess())
+        };
+        let before = stats();
+
+        let json = export_word_lists_json();
+        import_word_lists_json(json.clone()).unwrap();
+        assert_eq!(stats(), before);
+        assert_eq!(export_word_lists_json(), json);
+
+        // Invalid input is rejected without touching the lists
+        assert!(import_word_lists_json("not json".to_string()).is_err());
+        assert!(import_word_lists_json(r#"{"answer_words": ["CRANE"]}"#.to_string()).is_err());
+        assert!(import_word_lists_json(r#"{"answer_words": ["CRAN3"], "guess_words": []}"#.to_string()).is_err());
+        assert!(import_word_lists_json(r#"{"answer_words": [], "guess_words": ["CRANE"]}"#.to_string()).is_err());
+        assert_eq!(stats(), before);
+    }
+
+    #[test]
+    fn test_is_word_still_possible() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let pattern = |guess: &str, target: &str| simulate_guess_pattern_results(guess.to_string(), target.to_string());
+        let state = vec![
+            ("TARES".to_string(), pattern("TARES", "MATCH")),
+            ("CLINT".to_string(), pattern("CLINT", "MATCH")),
+        ];
+        let possible_words = get_possible_words(state.clone());
+        assert!(possible_words.len() > 1);
+
+        for word in ["MATCH", "match", "BATCH", "PATCH", "TARES", "CLINT", "FLINT", "QZXJV", "ZZ"] {
+            let expected = possible_words.contains(&word.to_uppercase());
+            assert_eq!(is_word_still_possible(word.to_string(), state.clone()), expected, "{}", word);
+        }
+        assert!(is_word_still_possible("match".to_string(), state));
+
+        // Without guesses, only answer words are possible
+        assert!(is_word_still_possible("JETTY".to_string(), vec![]));
+        assert!(!is_word_still_possible("TARES".to_string(), vec![]));
+    }
+
+    #[test]
+    fn test_word_matches_feedback() {
+        let _globals = lock_global_state();
+        let tokens = |pattern: &str| pattern.chars().map(|c| c.to_string()).collect::<Vec<_>>();
+
+        // C and E green, R, A and N gray; case doesn't matter
+        assert!(word_matches_feedback("clove".to_string(), "crane".to_string(), tokens("gxxxg")));
+        // Green position mismatch
+        assert!(!word_matches_feedback("GLOVE".to_string(), "CRANE".to_string(), tokens("GXXXG")));
+        // Yellow C must appear elsewhere
+        assert!(!word_matches_feedback("BLIMP".to_string(), "CRANE".to_string(), tokens("YXXXX")));
+        assert!(word_matches_feedback("TOPIC".to_string(), "CRANE".to_string(), tokens("YXXXX")));
+        // The gray Es cap E at the single green one
+        assert!(!word_matches_feedback("BEGET".to_string(), "LEVEE".to_string(), tokens("XGXXX")));
+        assert!(word_matches_feedback("BEGIN".to_string(), "LEVEE".to_string(), tokens("XGXXX")));
+
+        // Invalid input never matches
+        assert!(!word_matches_feedback("CLOVE".to_string(), "CRANE".to_string(), tokens("GXXG")));
+        assert!(get_last_error().unwrap().contains("expected 5"));
+        assert!(!word_matches_feedback("CL0VE".to_string(), "CRANE".to_string(), tokens("GXXXG")));
+    }
+
+    #[test]
+    fn test_warm_up_prepares_first_guess() {
+        let _globals = lock_global_state();
+        *WORD_MANAGER.lock().unwrap() = crate::api::wrdl_helper::WordManager::new();
+
+        let loads = LAZY_WORD_LIST_LOADS.load(Ordering::Relaxed);
+        warm_up().unwrap();
+        assert_eq!(LAZY_WORD_LIST_LOADS.load(Ordering::Relaxed), loads + 1);
+        assert!(WORD_MANAGER.lock().unwrap().get_optimal_first_guess().is_some());
+
+        // Nothing is left to load lazily
+        assert!(get_best_guess(vec![]).is_some());
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+        assert!(get_best_guess(state).is_some());
+        assert_eq!(LAZY_WORD_LIST_LOADS.load(Ordering::Relaxed), loads + 1);
+    }
+
+    #[test]
+    fn test_guess_pattern_buckets() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+
+        let buckets = guess_pattern_buckets("clint".to_string(), state.clone(), i32::MAX);
+        let mut bucketed: Vec<String> = buckets.iter().flat_map(|(_, words)| words.clone()).collect();
+        bucketed.sort();
+        let mut possible_words = get_possible_words(state.clone());
+        possible_words.sort();
+        assert_eq!(bucketed, possible_words);
+
+        // Each word sits under the pattern CLINT produces against it
+        for (pattern, words) in &buckets {
+            assert!(words.iter().all(|word| &simulate_guess_pattern("CLINT".to_string(), word.clone()) == pattern));
+        }
+        let counts: Vec<usize> = buckets.iter().map(|(_, words)| words.len()).collect();
+        let expected: Vec<usize> = guess_outcome_distribution("CLINT".to_string(), state.clone()).iter().map(|(_, count, _)| *count as usize).collect();
+        assert_eq!(counts, expected);
+
+        let capped = guess_pattern_buckets("CLINT".to_string(), state, 2);
+        assert_eq!(capped.len(), buckets.len());
+        assert!(capped.iter().all(|(_, words)| words.len() <= 2));
+    }
+
+    #[test]
+    fn test_get_best_guess_on_solved_board() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let all_green = || vec!["G".to_string(); 5];
+
+        assert_eq!(get_best_guess(vec![("CRANE".to_string(), all_green())]), Some("CRANE".to_string()));
+
+        let state = vec![
+            ("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string())),
+            ("match".to_string(), all_green()),
+        ];
+        assert_eq!(get_best_guess(state), Some("MATCH".to_string()));
+    }
+
+    #[test]
+    fn test_solve_with_explanations() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let opener = get_best_guess(vec![]).unwrap();
+
+        let steps = solve_with_explanations("match".to_string(), 6);
+        let (first_guess, first_rationale, first_entropy) = &steps[0];
+        assert_eq!(first_guess, &opener);
+        assert_eq!(first_rationale, "optimal opener");
+        assert!(*first_entropy > 0.0);
+
+        let (last_guess, _, last_entropy) = steps.last().unwrap();
+        assert_eq!(last_guess, "MATCH");
+        assert!(steps.len() <= 6);
+        // A guess made with one word left can't learn anything more
+        if steps.last().unwrap().1 == "only one word left" {
+            assert_eq!(*last_entropy, 0.0);
+        }
+
+        assert_eq!(solve_with_explanations("MATCH".to_string(), 1).len(), 1);
+        assert!(solve_with_explanations("MAT".to_string(), 6).is_empty());
+    }
+
+    #[test]
+    fn test_get_possible_words_in() {
+        let _globals = lock_global_state();
+        let universe: Vec<String> = ["tiger", "zebra", "Camel", "HORSE", "OTTER"].iter().map(|w| w.to_string()).collect();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "HORSE".to_string()))];
+
+        assert_eq!(get_possible_words_in(universe.clone(), vec![]), vec!["TIGER", "ZEBRA", "CAMEL", "HORSE", "OTTER"]);
+        assert_eq!(get_possible_words_in(universe.clone(), state.clone()), vec!["HORSE".to_string()]);
+
+        let mut malformed = universe;
+        malformed.push("EEL".to_string());
+        assert!(get_possible_words_in(malformed, state).is_empty());
+        assert!(get_last_error().unwrap().contains("EEL"));
+    }
+
+    #[test]
+    fn test_prime_suspect_bonus() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "SHORT".to_string()))];
+        let possible_words = get_possible_words(state.clone());
+
+        // The bonus lets a possible answer win over a slightly more informative word
+        let with_bonus = get_best_guess(state.clone()).unwrap();
+        set_prime_suspect_bonus(0.0).unwrap();
+        let without_bonus = get_best_guess(state.clone()).unwrap();
+        assert!(possible_words.contains(&with_bonus));
+        assert!(!possible_words.contains(&without_bonus));
+        assert!(calculate_entropy(without_bonus, possible_words.clone()) > calculate_entropy(with_bonus, possible_words));
+
+        assert!(set_prime_suspect_bonus(-0.1).is_err());
+        assert!(set_prime_suspect_bonus(f64::NAN).is_err());
+        assert_eq!(SOLVER_CONFIG.lock().unwrap().prime_suspect_bonus, 0.0);
+    }
+
+    #[test]
+    fn test_reconstruct_remaining_from_emoji() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let to_emoji = |pattern: String| -> String {
+            pattern.chars().map(|c| match c { 'G' => '🟩', 'Y' => '🟨', _ => '⬛' }).collect()
+        };
+        let guesses = vec!["TARES".to_string(), "CLINT".to_string()];
+        let rows: Vec<String> = guesses.iter()
+            .map(|guess| to_emoji(simulate_guess_pattern(guess.clone(), "MATCH".to_string())))
+            .collect();
+        assert_eq!(rows[0], "🟨🟩⬛⬛⬛");
+
+        let state: Vec<(String, Vec<String>)> = guesses.iter()
+            .map(|guess| (guess.clone(), simulate_guess_pattern_results(guess.clone(), "MATCH".to_string())))
+            .collect();
+        let remaining = reconstruct_remaining_from_emoji(guesses.clone(), rows.clone());
+        assert!(remaining.contains(&"MATCH".to_string()));
+        assert_eq!(remaining, get_possible_words(state));
+
+        // High-contrast colors and light-mode grays read the same
+        let high_contrast: Vec<String> = rows.iter().map(|row| row.replace('🟩', "🟧").replace('🟨', "🟦").replace('⬛', "⬜")).collect();
+        assert_eq!(reconstruct_remaining_from_emoji(guesses.clone(), high_contrast), remaining);
+
+        assert!(reconstruct_remaining_from_emoji(guesses.clone(), rows[..1].to_vec()).is_empty());
+        assert!(reconstruct_remaining_from_emoji(guesses[..1].to_vec(), vec!["🟩🟩🟩🟩".to_string()]).is_empty());
+        assert!(get_last_error().unwrap().contains("4 tiles"));
+    }
+
+    #[test]
+    fn test_duplicate_guess_results_are_ignored() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let pattern = |guess: &str| simulate_guess_pattern_results(guess.to_string(), "MATCH".to_string());
+        let tares = ("TARES".to_string(), pattern("TARES"));
+        let clint = ("CLINT".to_string(), pattern("CLINT"));
+
+        let deduped = vec![tares.clone()];
+        let duplicated = vec![tares.clone(), tares.clone()];
+        assert_eq!(get_best_guess(duplicated.clone()), get_best_guess(deduped.clone()));
+        assert_eq!(get_possible_words(duplicated), get_possible_words(deduped));
+
+        let deduped = vec![tares.clone(), clint.clone()];
+        let duplicated = vec![tares.clone(), clint.clone(), tares];
+        assert_eq!(get_best_guess(duplicated.clone()), get_best_guess(deduped.clone()));
+        assert_eq!(get_possible_words(duplicated), get_possible_words(deduped));
+    }
+
+    #[test]
+    fn test_information_gained() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let pattern = |guess: &str| simulate_guess_pattern_results(guess.to_string(), "MATCH".to_string());
+        let first = vec![("TARES".to_string(), pattern("TARES"))];
+        let both = vec![("TARES".to_string(), pattern("TARES")), ("CLINT".to_string(), pattern("CLINT"))];
+
+        let (before, after) = information_gained(both.clone());
+        assert!(before >= after);
+        assert_eq!(before, get_possible_word_count(first.clone()));
+        assert_eq!(after, get_possible_word_count(both));
+
+        let answer_count = get_possible_word_count(vec![]);
+        assert_eq!(information_gained(first.clone()), (answer_count, get_possible_word_count(first)));
+        assert_eq!(information_gained(vec![]), (answer_count, answer_count));
+    }
+
+    #[test]
+    fn test_get_best_guess_with_budget() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+        let possible_words = get_possible_words(state.clone());
+
+        // With guesses to spare, a strategic word that can't win is worth more
+        let informative = get_best_guess(state.clone()).unwrap();
+        assert!(!possible_words.contains(&informative));
+        assert_eq!(get_best_guess_with_budget(state.clone(), 2), Some(informative));
+
+        let last_chance = get_best_guess_with_budget(state.clone(), 1).unwrap();
+        assert!(possible_words.contains(&last_chance));
+        assert!(WORD_MANAGER.lock().unwrap().is_possible_answer(&last_chance));
+        assert_eq!(get_best_guess_with_budget(state, 0), Some(last_chance));
+
+        // After two solver turns the last guess is still always an answer word
+        for answer in ["MATCH", "JETTY", "ZEALS", "CUPPA", "WEDGY", "FUZZY"] {
+            let mut state = Vec::new();
+            for _ in 0..2 {
+                let guess = get_best_guess(state.clone()).unwrap();
+                let pattern = simulate_guess_pattern_results(guess.clone(), answer.to_string());
+                state.push((guess, pattern));
+            }
+            let last_chance = get_best_guess_with_budget(state, 1).unwrap();
+            assert!(WORD_MANAGER.lock().unwrap().is_possible_answer(&last_chance), "{}: {}", answer, last_chance);
+        }
+    }
+
+    #[test]
+    fn test_last_guess_was_capped() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+        let possible_count = get_possible_word_count(state.clone());
+        assert!(possible_count > 100);
+
+        SOLVER_CONFIG.lock().unwrap().candidate_cap = 5;
+        get_best_guess(state.clone());
+        let capped = last_guess_was_capped();
+        let dropped = last_guess_capped_count();
+
+        SOLVER_CONFIG.lock().unwrap().candidate_cap = i32::MAX;
+        get_best_guess(state.clone());
+        let ample_cap_capped = last_guess_was_capped();
+
+        // The default cap is counted like any other
+        reset_solver_config();
+        get_best_guess(state.clone());
+        let default_cap_dropped = last_guess_capped_count();
+
+        // A capped call followed by one that never selects candidates
+        SOLVER_CONFIG.lock().unwrap().candidate_cap = 5;
+        get_best_guess(state);
+        get_best_guess(vec![]);
+        let first_guess_capped = last_guess_was_capped();
+        assert!(capped);
+        assert!(dropped >= possible_count - 5);
+        assert!(!ample_cap_capped);
+        assert!(default_cap_dropped >= possible_count - 100);
+        assert_eq!(last_guess_capped_count(), 0);
+        assert!(!first_guess_capped);
+    }
+
+    #[test]
+    fn test_validate_words() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let words = vec!["TARES", "crane", "QZXJV", "CRAN3", "SLATES", ""]
+            .into_iter().map(String::from).collect();
+        assert_eq!(validate_words(words), vec![true, true, false, false, false, false]);
+        assert!(validate_words(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_get_best_guess_for_remaining_matches_get_best_guess() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let pattern = |guess: &str, target: &str| -> Vec<String> {
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect()
+        };
+        let states = vec![
+            vec![("TARES".to_string(), pattern("TARES", "CRANE"))],
+            vec![("TARES".to_string(), pattern("TARES", "PLUMB")), ("CLOMP".to_string(), pattern("CLOMP", "PLUMB"))],
+        ];
+
+        for state in states {
+            let remaining = get_possible_words(state.clone());
+            assert_eq!(
+                get_best_guess_for_remaining(remaining, state.clone()),
+                get_best_guess(state)
+            );
+        }
+
+        assert_eq!(get_best_guess_for_remaining(vec![], vec![("TARES".to_string(), pattern("TARES", "CRANE"))]), None);
+    }
+
+    #[test]
+    fn test_best_guess_consistent_with_possible_words() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let solver = IntelligentSolver::new(vec![]);
+
+        for target in ["JETTY", "WEDGY", "CUPPA"] {
+            let mut state: Vec<(String, Vec<String>)> = Vec::new();
+            for _ in 0..6 {
+                let best = get_best_guess(state.clone()).unwrap();
+                let possible = get_possible_words(state.clone());
+                assert!(possible.contains(&target.to_string()), "{} lost at {:?}", target, state);
+
+                // Both go through the same filter, so the best guess is always a
+                // possible word or one of the solver's strategic words, and a
+                // possible word once the game is down to its endgame
+                if !state.is_empty() {
+                    let internal = to_internal_guess_results(&state).unwrap();
+                    assert!(solver.get_candidate_words(&possible, &internal).contains(&best));
+                    assert_eq!(count_remaining_guess_words(&internal), possible.len());
+                }
+                if possible.len() <= 2 {
+                    assert!(possible.contains(&best), "{} not possible for {}", best, target);
+                }
+
+                if best == target {
+                    break;
+                }
+                let pattern = simulate_guess_pattern(best.clone(), target.to_string());
+                state.push((best, pattern.chars().map(|c| c.to_string()).collect()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_strategic_words_stay_in_dictionary() {
+        let _globals = lock_global_state();
+        let words: Vec<String> = ["WEDGY", "JETTY", "JUMPY", "FUZZY", "ROSSA", "CUPPA"]
+            .iter().map(|w| w.to_string()).collect();
+        WORD_MANAGER.lock().unwrap().set_words(words.clone(), words.clone());
+
+        let state = vec![("ROSSA".to_string(), vec!["X".to_string(); 5])];
+        let internal = to_internal_guess_results(&state).unwrap();
+        let possible = get_possible_words(state.clone());
+        assert_eq!(possible.len(), 4);
+
+        // None of the built-in strategic words are in this dictionary
+        let candidates = IntelligentSolver::new_from_manager().get_candidate_words(&possible, &internal);
+        assert!(candidates.iter().all(|word| words.contains(word)), "{:?}", candidates);
+        assert!(words.contains(&get_best_guess(state).unwrap()));
+    }
+
+    #[test]
+    fn test_non_ascii_words_are_rejected() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        // At FFI input
+        let state = vec![("CAFÉS".to_string(), vec!["X".to_string(); 5])];
+        assert_eq!(get_best_guess(state.clone()), None);
+        assert!(get_last_error().unwrap().contains("CAFÉS"));
+        assert!(get_possible_words(state).is_empty());
+
+        // At load
+        let temp_dir = std::env::temp_dir().join(format!("wrdlhelper_non_ascii_{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        std::fs::write(temp_dir.join("official_wordle_words.json"), r#"{"answer_words": ["CRANE", "CAFÉS"]}"#).unwrap();
+        std::fs::write(temp_dir.join("official_guess_words.txt"), "CRANE\n").unwrap();
+        set_word_list_dir(temp_dir.to_string_lossy().to_string());
+
+        let error = initialize_word_lists().unwrap_err();
+        assert!(error.contains("CAFÉS"), "{}", error);
+        assert!(import_word_lists_json(r#"{"answer_words": ["NAÏVE"], "guess_words": []}"#.to_string()).is_err());
+
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_guess_lines_are_skipped() {
+        let _globals = lock_global_state();
+        let temp_dir = std::env::temp_dir().join(format!("wrdlhelper_malformed_{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        std::fs::write(temp_dir.join("official_guess_words.txt"), "CRAN\nCRANES\nCR4NE\n slate \n").unwrap();
+        set_word_list_dir(temp_dir.to_string_lossy().to_string());
+
+        let loaded = load_guess_words_from_assets();
+
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+        assert_eq!(loaded, Ok(vec!["SLATE".to_string()]));
+    }
+
+    /// Logger that keeps every record so tests can inspect them
+    struct CapturingLogger {
+        records: Mutex<Vec<(log::Level, String)>>,
+    }
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.records.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURING_LOGGER: Lazy<CapturingLogger> = Lazy::new(|| CapturingLogger {
+        records: Mutex::new(Vec::new()),
+    });
+
+    #[test]
+    fn test_loading_words_logs_at_info() {
+        let _globals = lock_global_state();
+        // The logger can only be installed once per process
+        let _ = log::set_logger(&*CAPTURING_LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+        CAPTURING_LOGGER.records.lock().unwrap().clear();
+
+        initialize_word_lists().unwrap();
+
+        let records = CAPTURING_LOGGER.records.lock().unwrap();
+        assert!(records.iter().any(|(level, message)| *level == log::Level::Info && message.contains("answer words")));
+        assert!(records.iter().any(|(level, message)| *level == log::Level::Info && message.contains("guess words")));
+    }
+
+    #[test]
+    fn test_get_daily_answer_is_deterministic() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        assert_eq!(get_daily_answer(42), get_daily_answer(42));
+        assert!(get_daily_answer(42).is_some());
+
+        let week: std::collections::HashSet<_> = (0..7).filter_map(get_daily_answer).collect();
+        assert!(week.len() > 1, "Different days should usually give different words");
+
+        let answer_count = WORD_MANAGER.lock().unwrap().get_answer_words().len() as i32;
+        assert_eq!(get_daily_answer(-1), get_daily_answer(answer_count - 1));
+    }
+
+    #[test]
+    fn test_exclude_prior_guesses() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let pattern = |guess: &str, target: &str| -> Vec<String> {
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect()
+        };
+
+        // The all-green guess matches its own feedback, so it normally stays possible
+        let winning_state = vec![("CRANE".to_string(), pattern("CRANE", "CRANE"))];
+        assert_eq!(get_possible_words(winning_state.clone()), vec!["CRANE".to_string()]);
+
+        let losing_state = vec![
+            ("TARES".to_string(), pattern("TARES", "CRANE")),
+            ("CRATE".to_string(), pattern("CRATE", "CRANE")),
+        ];
+
+        set_exclude_prior_guesses(true);
+        assert!(get_possible_words(winning_state).is_empty());
+        let possible = get_possible_words(losing_state.clone());
+        assert!(possible.contains(&"CRANE".to_string()));
+        assert!(!possible.contains(&"TARES".to_string()));
+        assert!(!possible.contains(&"CRATE".to_string()));
+        let best = get_best_guess(losing_state).unwrap();
+        assert!(best != "TARES" && best != "CRATE");
+    }
+
+    #[test]
+    fn test_get_best_guess_multiboard() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let board_for = |guesses: &[&str], target: &str| -> Vec<(String, Vec<String>)> {
+            guesses.iter().map(|guess| {
+                let pattern = simulate_guess_pattern(guess.to_string(), target.to_string());
+                (guess.to_string(), pattern.chars().map(|c| c.to_string()).collect())
+            }).collect()
+        };
+
+        let board_a = board_for(&["TARES", "CLOMP"], "CRANE");
+        let board_b = board_for(&["TARES", "CLOMP"], "PLUMB");
+        let solved_board = board_for(&["TARES", "CLOMP", "CLOTH"], "CLOTH");
+
+        let shared_guess = get_best_guess_multiboard(vec![board_a.clone(), board_b.clone()])
+            .expect("two unsolved boards should produce a guess");
+        assert!(WORD_MANAGER.lock().unwrap().get_guess_words().contains(&shared_guess));
+
+        // A solved board no longer influences the choice
+        assert_eq!(
+            get_best_guess_multiboard(vec![board_a.clone(), board_b.clone(), solved_board.clone()]),
+            Some(shared_guess)
+        );
+        assert_eq!(
+            get_best_guess_multiboard(vec![board_a.clone(), solved_board.clone()]),
+            get_best_guess_multiboard(vec![board_a])
+        );
+
+        // Nothing left to play once every board is solved
+        assert_eq!(get_best_guess_multiboard(vec![solved_board]), None);
     }
 
     #[test]
     fn test_filter_words_basic() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let guess_results = vec![
             ("CRANE".to_string(), vec!["G".to_string(), "Y".to_string(), "X".to_string(), "X".to_string(), "G".to_string()])
@@ -848,6 +3141,7 @@ mod tests {
 
     #[test]
     fn test_calculate_entropy_basic() {
+        let _globals = lock_global_state();
         let candidate = "CRANE".to_string();
         let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
         
@@ -855,6 +3149,138 @@ mod tests {
         assert!(entropy >= 0.0);
     }
 
+    #[test]
+    fn test_calculate_entropy_for_state() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let pattern = |guess: &str, target: &str| -> Vec<String> {
+            simulate_guess_pattern(guess.to_string(), target.to_string())
+                .chars().map(|c| c.to_string()).collect()
+        };
+        let state = vec![("TARES".to_string(), pattern("TARES", "CRANE"))];
+        let remaining = get_possible_words(state.clone());
+
+        for candidate in ["CRANE", "CLOMP", "PLUMB"] {
+            assert_eq!(
+                calculate_entropy_for_state(candidate.to_string(), state.clone()),
+                calculate_entropy(candidate.to_string(), remaining.clone())
+            );
+        }
+        assert!(calculate_entropy_for_state("CLOMP".to_string(), state) > 0.0);
+    }
+
+    #[test]
+    fn test_guess_outcome_distribution() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let state = vec![(
+            "TARES".to_string(),
+            simulate_guess_pattern("TARES".to_string(), "CRANE".to_string())
+                .chars().map(|c| c.to_string()).collect(),
+        )];
+        let remaining_count = get_possible_words(state.clone()).len() as i32;
+        let distribution = guess_outcome_distribution("clomp".to_string(), state);
+
+        assert!(distribution.len() > 1);
+        assert_eq!(distribution.iter().map(|(_, count, _)| count).sum::<i32>(), remaining_count);
+        let total_probability: f64 = distribution.iter().map(|(_, _, probability)| probability).sum();
+        assert!((total_probability - 1.0).abs() < 1e-9);
+        assert!(distribution.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+    }
+
+    #[test]
+    fn test_preview_guess() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let state = vec![(
+            "TARES".to_string(),
+            simulate_guess_pattern_results("TARES".to_string(), "CUPPA".to_string()),
+        )];
+        let remaining_count = get_possible_words(state.clone()).len() as f64;
+
+        let strong = calculate_entropy_for_state("CLOMP".to_string(), state.clone());
+        let weak = calculate_entropy_for_state("FUZZY".to_string(), state.clone());
+        assert!(strong > weak);
+
+        // More bits means fewer words left on average
+        let strong_preview = preview_guess("clomp".to_string(), state.clone());
+        let weak_preview = preview_guess("FUZZY".to_string(), state);
+        assert!(strong_preview < weak_preview, "{} should be below {}", strong_preview, weak_preview);
+        assert!(strong_preview >= 1.0 && weak_preview <= remaining_count);
+    }
+
+    #[test]
+    fn test_evaluate_opener() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+
+        let recommended = evaluate_opener(get_optimal_first_guess().unwrap());
+        let weak = evaluate_opener("fuzzy".to_string());
+        assert!(weak > 0.0);
+        assert!(recommended > weak, "{} should beat {}", recommended, weak);
+        assert_eq!(evaluate_opener("tares".to_string()), evaluate_opener("TARES".to_string()));
+    }
+
+    #[test]
+    fn test_rank_openers() {
+        let _globals = lock_global_state();
+        initialize_word_lists().unwrap();
+        let opener = get_optimal_first_guess().unwrap();
+
+        let candidates: Vec<String> = ["fuzzy", "JUMPY", "WEDGY", &opener.to_lowercase(), "QAJAQ", "CRAN3"]
+            .iter().map(|w| w.to_string()).collect();
+        let ranked = rank_openers(candidates, 3);
+        println!("📊 Ranked openers: {:?}", ranked);
+
+        assert_eq!(ranked.len(), 3);
+        assert_eq!(ranked[0].0, opener);
+        assert_eq!(ranked[0].1, evaluate_opener(opener.clone()));
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        assert!(rank_openers(vec!["TARES".to_string()], 0).is_empty());
+
+        // No candidates ranks only the best letter splitters of a long guess list
+        let letters = ['A', 'B', 'C', 'D', 'E', 'F'];
+        let synthetic: Vec<String> = (0..letters.len().pow(5))
+            .map(|index| (0..5).map(|position| letters[index / letters.len().pow(position) % letters.len()]).collect())
+            .collect();
+        let answers: Vec<String> = ["CRANE", "SLOTH"].iter().map(|w| w.to_string()).collect();
+        WORD_MANAGER.lock().unwrap().set_words(answers, synthetic.clone());
+        let default_ranking = rank_openers(vec![], i32::MAX);
+        assert_eq!(default_ranking.len(), DEFAULT_OPENER_POOL);
+        // SLOTH is appended after the 7,776 synthetic words and splits the answers
+        assert!(default_ranking.iter().take_while(|(_, entropy)| *entropy == 1.0).any(|(word, _)| word == "SLOTH"));
+
+        let words: Vec<String> = ["WEDGY", "JETTY", "JUMPY", "FUZZY"].iter().map(|w| w.to_string()).collect();
+        WORD_MANAGER.lock().unwrap().set_words(words.clone(), words.clone());
+        let defaults = rank_openers(vec![], 10);
+        assert_eq!(defaults.len(), words.len());
+        assert!(defaults.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn test_get_best_winning_guess() {
+        let _globals = lock_global_state();
+        // CATCH can't tell MARCH from PARCH, while either of those splits the rest
+        let answers: Vec<String> = ["CATCH", "MARCH", "PARCH"].iter().map(|w| w.to_string()).collect();
+        let guesses = [answers.clone(), vec!["LUNCH".to_string(), "BARCH".to_string()]].concat();
+        WORD_MANAGER.lock().unwrap().set_words(answers.clone(), guesses);
+
+        let state = vec![("LUNCH".to_string(), simulate_guess_pattern_results("LUNCH".to_string(), "MARCH".to_string()))];
+        // BARCH survives the feedback and ties MARCH, but can never be the answer
+        assert!(get_possible_words(state.clone()).contains(&"BARCH".to_string()));
+
+        let best = get_best_winning_guess(state).unwrap();
+        assert!(answers.contains(&best));
+        assert_eq!(best, "MARCH");
+
+        // Before any guess this is the opener
+        assert_eq!(get_best_winning_guess(vec![]), get_optimal_first_guess());
+    }
+
     #[test]
     fn test_simulate_guess_pattern() {
         let pattern = simulate_guess_pattern("CRANE".to_string(), "CRATE".to_string());
@@ -864,8 +3290,28 @@ mod tests {
         assert_eq!(pattern2, "XXGXG"); // Only A and E match
     }
 
+    #[test]
+    fn test_simulate_guess_pattern_results() {
+        for (guess, target) in [("TARES", "CRANE"), ("speed", "erase"), ("CRANE", "CRANE")] {
+            let tokens = simulate_guess_pattern_results(guess.to_string(), target.to_string());
+            assert_eq!(tokens.len(), 5);
+            assert_eq!(tokens.concat(), simulate_guess_pattern(guess.to_string(), target.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_simulate_guess_pattern_mixed_case() {
+        let upper = simulate_guess_pattern("CRANE".to_string(), "CRATE".to_string());
+        let lower = simulate_guess_pattern("crane".to_string(), "CRATE".to_string());
+        let mixed = simulate_guess_pattern("CrAnE".to_string(), "crate".to_string());
+
+        assert_eq!(lower, upper);
+        assert_eq!(mixed, upper);
+    }
+
     #[test]
     fn test_wrdl_helper_integration() {
+        let _globals = lock_global_state();
         // Test complete wrdlHelper workflow
         let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
@@ -912,6 +3358,7 @@ mod tests {
 
     #[test]
     fn test_word_filtering_debug() {
+        let _globals = lock_global_state();
         use crate::api::wrdl_helper::{IntelligentSolver, GuessResult, LetterResult};
         
         // Use words that don't contain C,R,A,N,E for all-gray test
@@ -944,6 +3391,7 @@ mod tests {
 
     #[test]
     fn test_word_filtering_partial_gray_debug() {
+        let _globals = lock_global_state();
         use crate::api::wrdl_helper::{IntelligentSolver, GuessResult, LetterResult};
         
         // Test GXXXX pattern (C=Green, R,A,N,E=Gray)
@@ -972,7 +3420,7 @@ mod tests {
             // Debug each step for valid words
             if word.starts_with('C') {
                 println!("  Debugging {}:", word);
-                println!("  - {}[0] = '{}', should be 'C' (Green): {}", word, word.chars().nth(0).unwrap(), word.chars().nth(0).unwrap() == 'C');
+                println!("  - {}[0] = '{}', should be 'C' (Green): {}", word, word.chars().next().unwrap(), word.starts_with('C'));
                 println!("  - {} contains 'R': {}", word, word.contains('R'));
                 println!("  - {} contains 'A': {}", word, word.contains('A'));
                 println!("  - {} contains 'N': {}", word, word.contains('N'));
@@ -993,6 +3441,7 @@ mod tests {
 
     #[test]
     fn test_constraint_violation_tares_gyyxx() {
+        let _globals = lock_global_state();
         // Test case from handoff document: TARES GYYXX should NOT suggest CRAFT
         // TARES GYYXX means:
         // - T in position 1 = Green (must be T)
@@ -0,0 +1,61 @@
+//! Serializes tests that use the global solver state
+//!
+//! `WORD_MANAGER`, `SOLVER_CONFIG` and `WORD_LIST_DIR` are process-wide, and the
+//! test harness runs tests on several threads. A test that changes them, or whose
+//! results depend on them, holds `lock_global_state()` for its whole body. The
+//! guard puts the saved state back when dropped, including when an assert fails,
+//! and starts each test with an empty `ENTROPY_CACHE`.
+
+use crate::api::simple::WORD_LIST_DIR;
+use crate::api::wrdl_helper::{lock_recovering, SolverConfig, ENTROPY_CACHE, SOLVER_CONFIG, WORD_MANAGER};
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+/// Held by whichever test is currently using the global state
+static GLOBAL_STATE: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Exclusive use of the global state, restored when dropped
+pub(crate) struct GlobalStateGuard {
+    solver_config: SolverConfig,
+    answer_words: Vec<String>,
+    guess_words: Vec<String>,
+    optimal_first_guess: Option<String>,
+    word_list_dir: PathBuf,
+    // Dropped last, so the state is restored before the next test can start
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// Wait for exclusive use of the global state and save it
+pub(crate) fn lock_global_state() -> GlobalStateGuard {
+    // A failed test poisons the lock, but its guard has already restored the state
+    let lock = lock_recovering(&GLOBAL_STATE);
+    // Entropies cached by earlier tests would hide the evaluations a test counts
+    ENTROPY_CACHE.clear();
+    let manager = lock_recovering(&WORD_MANAGER);
+
+    GlobalStateGuard {
+        solver_config: lock_recovering(&SOLVER_CONFIG).clone(),
+        answer_words: manager.answer_words.clone(),
+        guess_words: manager.guess_words.clone(),
+        optimal_first_guess: manager.optimal_first_guess.clone(),
+        word_list_dir: lock_recovering(&WORD_LIST_DIR).clone(),
+        _lock: lock,
+    }
+}
+
+impl Drop for GlobalStateGuard {
+    fn drop(&mut self) {
+        *lock_recovering(&SOLVER_CONFIG) = self.solver_config.clone();
+        *lock_recovering(&WORD_LIST_DIR) = self.word_list_dir.clone();
+
+        // Reinstalling the lists recomputes the opener, so only do it when they changed
+        let mut manager = lock_recovering(&WORD_MANAGER);
+        if manager.answer_words != self.answer_words
+            || manager.guess_words != self.guess_words
+            || manager.optimal_first_guess != self.optimal_first_guess
+        {
+            manager.set_words(self.answer_words.clone(), self.guess_words.clone());
+        }
+    }
+}
@@ -6,22 +6,77 @@
 //! - Pattern Simulation
 //! - Intelligent Word Selection
 
-use std::collections::HashMap;
-use std::f64::consts::LN_2;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use once_cell::sync::Lazy;
 use flutter_rust_bridge::frb;
 
 /// FFI-compatible enum for letter results
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// 
+/// Variants are declared from least to most informative, so the derived
+/// ordering is `Gray < Yellow < Green` and e.g. `max()` picks the best tile.
+/// Keep this order when adding variants; `rank` exposes it as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum LetterResult {
     Gray,
     Yellow,
     Green,
 }
 
+impl LetterResult {
+    /// Ordinal rank of the result: Gray=0, Yellow=1, Green=2
+    pub fn rank(self) -> u8 {
+        match self {
+            LetterResult::Gray => 0,
+            LetterResult::Yellow => 1,
+            LetterResult::Green => 2,
+        }
+    }
+
+    /// Result for a rank from `solver_core::simulate_guess_ranks`
+    pub fn from_rank(rank: u8) -> Self {
+        match rank {
+            crate::solver_core::GREEN => LetterResult::Green,
+            crate::solver_core::YELLOW => LetterResult::Yellow,
+            _ => LetterResult::Gray,
+        }
+    }
+
+    /// Pattern token used across FFI: "G", "Y", or "X"
+    pub fn to_token(self) -> String {
+        match self {
+            LetterResult::Green => "G",
+            LetterResult::Yellow => "Y",
+            LetterResult::Gray => "X",
+        }.to_string()
+    }
+}
+
+/// Where the solver draws the guesses it scores from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CandidateSource {
+    /// Only words that could still be the answer, so every guess can win
+    RemainingOnly,
+    /// Remaining words plus the curated strategic words, if `include_killer_words` is set
+    #[default]
+    RemainingPlusStrategic,
+    /// Every word in the loaded guess list
+    FullDictionary,
+}
+
+impl CandidateSource {
+    /// Short name used in the `SolverConfig` summary
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CandidateSource::RemainingOnly => "remaining",
+            CandidateSource::RemainingPlusStrategic => "strategic",
+            CandidateSource::FullDictionary => "full",
+        }
+    }
+}
+
 /// FFI-compatible struct for guess results
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GuessResult {
     pub word: String,
     pub results: Vec<LetterResult>,
@@ -31,11 +86,46 @@ pub struct GuessResult {
 #[derive(Debug, Clone)]
 pub struct SolverConfig {
     pub reference_mode: bool,
+    /// Add the strategic "killer" words to the candidates of `RemainingPlusStrategic`
     pub include_killer_words: bool,
+    /// Maximum number of candidates scored per guess
     pub candidate_cap: i32,
+    /// Stop scoring once a new best guess reaches `early_termination_threshold` bits
     pub early_termination_enabled: bool,
     pub early_termination_threshold: f64,
     pub entropy_only_scoring: bool,
+    /// Reject unknown pattern tokens instead of treating them as Gray
+    pub strict_patterns: bool,
+    /// Remove previously guessed words from possible answers and candidates
+    pub exclude_prior_guesses: bool,
+    /// Favor words with five distinct letters for the first two guesses
+    pub prefer_unique_letters: bool,
+    /// Pool of words the solver scores as guesses
+    pub candidate_source: CandidateSource,
+    /// Score bonus for candidates that could still be the answer (0.0 to disable)
+    pub prime_suspect_bonus: f64,
+    /// Largest remaining-word count that gets full entropy scoring; above it the
+    /// strategic shortlist is ranked by letter frequency instead
+    pub full_scoring_max_remaining: i32,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            reference_mode: false,
+            include_killer_words: true,  // Enable killer words by default for better performance
+            candidate_cap: 100,          // Bounds scoring cost; raise for thorough analysis
+            early_termination_enabled: false,  // Disable early termination for thorough analysis
+            early_termination_threshold: 10.0,
+            entropy_only_scoring: true,  // Use pure entropy scoring for better decisions
+            strict_patterns: false,      // Unknown tokens default to Gray for compatibility
+            exclude_prior_guesses: false, // Standard Wordle allows the answer to repeat a guess
+            prefer_unique_letters: false, // Pure entropy already values letter coverage
+            candidate_source: CandidateSource::RemainingPlusStrategic,
+            prime_suspect_bonus: 0.1,    // Breaks near-ties in favor of guesses that can win
+            full_scoring_max_remaining: i32::MAX, // Always score fully; lower it to trade accuracy for speed
+        }
+    }
 }
 
 impl GuessResult {
@@ -45,6 +135,86 @@ impl GuessResult {
             results: results.to_vec(),
         }
     }
+
+    /// Build the feedback for guessing `guess` when the answer is `target`
+    /// 
+    /// Both words are uppercased. Uses the same pattern logic as
+    /// `simulate_guess_pattern`, so test fixtures don't need hand-written tiles.
+    pub fn from_guess_against(guess: &str, target: &str) -> Self {
+        let guess = guess.to_uppercase();
+        let results = IntelligentSolver::new_from_manager().pattern_results(&guess, &target.to_uppercase());
+
+        Self {
+            word: guess,
+            results,
+        }
+    }
+
+    /// Pattern as a compact string, e.g. "GYYXX" (G=Green, Y=Yellow, X=Gray)
+    pub(crate) fn pattern_string(&self) -> String {
+        self.results.iter().map(|r| match r {
+            LetterResult::Green => 'G',
+            LetterResult::Yellow => 'Y',
+            LetterResult::Gray => 'X',
+        }).collect()
+    }
+}
+
+/// Compact "WORD:PATTERN" form, e.g. "TARES:GYYXX" (G=Green, Y=Yellow, X=Gray)
+impl std::fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.word, self.pattern_string())
+    }
+}
+
+/// Parse the "WORD:PATTERN" form produced by `Display`
+impl std::str::FromStr for GuessResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (word, pattern) = s.split_once(':')
+            .ok_or_else(|| format!("Missing ':' separator in guess result '{}'", s))?;
+
+        if word.is_empty() || word.chars().count() != pattern.chars().count() {
+            return Err(format!(
+                "Word '{}' and pattern '{}' must be non-empty and the same length", word, pattern
+            ));
+        }
+
+        let results = pattern.chars().map(|c| match c.to_ascii_uppercase() {
+            'G' => Ok(LetterResult::Green),
+            'Y' => Ok(LetterResult::Yellow),
+            'X' => Ok(LetterResult::Gray),
+            other => Err(format!("Invalid pattern token '{}' in guess result '{}'", other, s)),
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            word: word.to_string(),
+            results,
+        })
+    }
+}
+
+/// Compact "key=value" form for logs, e.g. "ref=0,killer=1,cap=100,..."
+impl std::fmt::Display for SolverConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ref={},killer={},cap={},early={},early_threshold={},entropy_only={},strict={},exclude_prior={},unique={},source={},suspect_bonus={},full_max={}",
+            self.reference_mode as u8,
+            self.include_killer_words as u8,
+            self.candidate_cap,
+            self.early_termination_enabled as u8,
+            self.early_termination_threshold,
+            self.entropy_only_scoring as u8,
+            self.strict_patterns as u8,
+            self.exclude_prior_guesses as u8,
+            self.prefer_unique_letters as u8,
+            self.candidate_source.as_str(),
+            self.prime_suspect_bonus,
+            self.full_scoring_max_remaining,
+        )
+    }
 }
 
 /// Intelligent solver that combines multiple algorithms for optimal word selection
@@ -52,26 +222,205 @@ pub struct IntelligentSolver {
     pub words: Vec<String>,
 }
 
+/// Memoized entropies keyed by candidate and remaining-word list
+/// 
+/// Entropy is a pure function of its inputs, so an entry never goes stale. Lists
+/// are found by hash and then compared in full, so two lists that happen to share
+/// a hash never share entropies. Only the most recent `ENTROPY_CACHE_LISTS` lists
+/// are kept. The solver's scoring uses the shared `ENTROPY_CACHE`, so repeated
+/// calls on one game state (best guess, then top two) share the work; create a
+/// separate cache to keep an analysis session apart.
+#[frb(opaque)]
+pub struct EntropyCache {
+    /// Oldest first
+    lists: Mutex<Vec<CachedEntropies>>,
+    /// Entropies actually computed, i.e. cache misses
+    evaluations: std::sync::atomic::AtomicUsize,
+}
+
+/// Remaining-word lists an `EntropyCache` keeps entropies for
+const ENTROPY_CACHE_LISTS: usize = 16;
+
+/// Entropies of the candidates scored against one remaining-word list
+struct CachedEntropies {
+    hash: u64,
+    remaining_words: Vec<String>,
+    entropies: std::collections::HashMap<String, f64>,
+}
+
+impl CachedEntropies {
+    fn is_for(&self, hash: u64, remaining_words: &[String]) -> bool {
+        self.hash == hash && self.remaining_words == remaining_words
+    }
+}
+
+impl EntropyCache {
+    pub fn new() -> Self {
+        Self {
+            lists: Mutex::new(Vec::new()),
+            evaluations: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of entropies computed rather than served from the cache
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Forget every cached entropy
+    pub fn clear(&self) {
+        lock_recovering(&self.lists).clear();
+    }
+
+    /// Hash of a remaining-word list, order included
+    fn hash_words(words: &[String]) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        words.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn get(&self, hash: u64, candidate_word: &str, remaining_words: &[String]) -> Option<f64> {
+        lock_recovering(&self.lists).iter()
+            .find(|list| list.is_for(hash, remaining_words))
+            .and_then(|list| list.entropies.get(candidate_word).copied())
+    }
+
+    fn insert(&self, hash: u64, candidate_word: &str, remaining_words: &[String], entropy: f64) {
+        let mut lists = lock_recovering(&self.lists);
+        let index = match lists.iter().position(|list| list.is_for(hash, remaining_words)) {
+            Some(index) => index,
+            None => {
+                if lists.len() == ENTROPY_CACHE_LISTS {
+                    lists.remove(0);
+                }
+                lists.push(CachedEntropies {
+                    hash,
+                    remaining_words: remaining_words.to_vec(),
+                    entropies: std::collections::HashMap::new(),
+                });
+                lists.len() - 1
+            }
+        };
+        lists[index].entropies.insert(candidate_word.to_string(), entropy);
+    }
+}
+
+impl Default for EntropyCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Global word manager to avoid passing large word lists across FFI
 #[frb(opaque)]
 pub struct WordManager {
     pub answer_words: Vec<String>,
     pub guess_words: Vec<String>,
     pub optimal_first_guess: Option<String>,
+    /// Shared copy of `guess_words` handed to the solving pipeline without cloning
+    interned_guess_words: Arc<[Arc<str>]>,
+    /// Most recent `shuffled_answers` result and its seed
+    shuffled_answers_cache: Option<(u64, Vec<String>)>,
 }
 
-impl WordManager {
-    pub fn new() -> Self {
+impl Default for WordManager {
+    fn default() -> Self {
         Self {
             answer_words: Vec::new(),
             guess_words: Vec::new(),
             optimal_first_guess: None,
+            interned_guess_words: Arc::from(Vec::new()),
+            shuffled_answers_cache: None,
         }
     }
+}
 
+impl WordManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace both word lists, re-intern them, and recompute the opener
+    /// 
+    /// `answer_words` are the possible solutions and `guess_words` the allowed
+    /// guesses. Every answer is always a legal guess, so answers missing from
+    /// `guess_words` are appended to keep it a superset.
+    pub fn set_words(&mut self, answer_words: Vec<String>, guess_words: Vec<String>) {
+        let mut guess_words = guess_words;
+        let known_guesses: std::collections::HashSet<String> = guess_words.iter().cloned().collect();
+        guess_words.extend(answer_words.iter().filter(|word| !known_guesses.contains(*word)).cloned());
+
+        self.answer_words = answer_words;
+        self.guess_words = guess_words;
+        self.shuffled_answers_cache = None;
+        self.intern_guess_words();
+        self.compute_optimal_first_guess();
+    }
+
+    /// Get the answer words in a stable pseudo-random order
+    /// 
+    /// A seeded Fisher-Yates shuffle, so the same seed and answer list always give
+    /// the same order. The result for the latest seed is cached until `set_words`
+    /// replaces the lists.
+    pub fn shuffled_answers(&mut self, seed: u64) -> Vec<String> {
+        self.shuffled_answer_slice(seed).to_vec()
+    }
+
+    /// Borrowing form of `shuffled_answers`, for callers that only need one word
+    pub(crate) fn shuffled_answer_slice(&mut self, seed: u64) -> &[String] {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let cached = matches!(&self.shuffled_answers_cache, Some((cached_seed, _)) if *cached_seed == seed);
+        if !cached {
+            let mut shuffled = self.answer_words.clone();
+            shuffled.shuffle(&mut StdRng::seed_from_u64(seed));
+            self.shuffled_answers_cache = Some((seed, shuffled));
+        }
+        self.shuffled_answers_cache.as_ref().map(|(_, words)| words.as_slice()).unwrap_or_default()
+    }
+
+    /// Rebuild the interned guess list from `guess_words`
+    fn intern_guess_words(&mut self) {
+        self.interned_guess_words = self.guess_words.iter()
+            .map(|word| Arc::from(word.as_str()))
+            .collect();
+    }
+
+    /// Get the guess words as shared, interned strings
+    /// 
+    /// Cloning the result only bumps a reference count, so callers can filter and
+    /// score without copying the 14k-word list on every call. If `guess_words` was
+    /// assigned directly instead of through `set_words`, a fresh list is interned.
+    pub fn get_interned_guess_words(&self) -> Arc<[Arc<str>]> {
+        if self.interned_guess_words.len() != self.guess_words.len() {
+            return self.guess_words.iter().map(|word| Arc::from(word.as_str())).collect();
+        }
+        Arc::clone(&self.interned_guess_words)
+    }
+
+    /// Load the built-in word lists
+    /// 
+    /// With the `embedded-words` feature these are the full official lists,
+    /// compiled into the binary. Otherwise a small hardcoded list is used.
     pub fn load_words(&mut self) -> Result<(), String> {
-        // For now, use hardcoded words - in production, load from files
-        self.answer_words = vec![
+        #[cfg(feature = "embedded-words")]
+        let (answer_words, guess_words) = EMBEDDED_WORDS.clone()?;
+        #[cfg(not(feature = "embedded-words"))]
+        let (answer_words, guess_words) = Self::hardcoded_words();
+
+        // Interns the guesses and computes the optimal first guess once at startup
+        self.set_words(answer_words, guess_words);
+        
+        Ok(())
+    }
+
+    /// Small fallback lists for builds without the embedded official lists
+    #[cfg(not(feature = "embedded-words"))]
+    fn hardcoded_words() -> (Vec<String>, Vec<String>) {
+        let answer_words = vec![
             "CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string(), 
             "PLATE".to_string(), "GRATE".to_string(), "TRACE".to_string(),
             "CHASE".to_string(), "CLOTH".to_string(), "CLOUD".to_string(),
@@ -79,13 +428,16 @@ impl WordManager {
             "HELLO".to_string(), "FLUTE".to_string(), "PRIDE".to_string(),
             "SHINE".to_string(), "BRAVE".to_string(), "QUICK".to_string(),
         ];
+
+        // Guess-only words: allowed as guesses, never an answer
+        let guess_only_words = vec![
+            "ADIEU".to_string(), "AUDIO".to_string(), "ROATE".to_string(),
+            "SOARE".to_string(),
+        ];
         
-        self.guess_words = self.answer_words.clone();
-        
-        // Compute optimal first guess once at startup
-        self.compute_optimal_first_guess();
-        
-        Ok(())
+        // Keep the lists separate - guesses are a superset of answers
+        let guess_words = [answer_words.clone(), guess_only_words].concat();
+        (answer_words, guess_words)
     }
     
     /// Compute the optimal first guess once at startup
@@ -93,12 +445,15 @@ impl WordManager {
     /// Uses proven optimal first guesses from statistical analysis.
     /// No computation needed - these are already known to be optimal!
     pub fn compute_optimal_first_guess(&mut self) {
+        // Never keep an opener computed for a previously loaded word list
+        self.optimal_first_guess = None;
+
         if self.guess_words.is_empty() {
-            println!("⚠️ No guess words available for optimal first guess computation");
+            log::warn!("⚠️ No guess words available for optimal first guess computation");
             return;
         }
         
-        println!("🔍 Computing optimal first guess from {} guess words", self.guess_words.len());
+        log::debug!("🔍 Computing optimal first guess from {} guess words", self.guess_words.len());
         
         // REVERTED: Use proven optimal first guesses (no computation needed!)
         // The dynamic approach made things worse - simpler is better
@@ -107,7 +462,7 @@ impl WordManager {
         // Pick the first one that's in our word list (case-insensitive)
         for &word in &optimal_first_guesses {
             if self.guess_words.iter().any(|w| w.to_uppercase() == word) {
-                println!("✅ Found optimal first guess: {}", word);
+                log::debug!("✅ Found optimal first guess: {}", word);
                 self.optimal_first_guess = Some(word.to_string());
                 return;
             }
@@ -118,6 +473,39 @@ impl WordManager {
         &self.answer_words
     }
 
+    /// Check if a word may be guessed (case-insensitive)
+    /// 
+    /// Stored words are ASCII, so an ASCII case-insensitive compare needs no
+    /// allocation per entry.
+    pub fn is_allowed_guess(&self, word: &str) -> bool {
+        self.guess_words.iter().any(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    /// Keep only the words that are in the guess list
+    /// 
+    /// Scans the guess list once, so filtering a short list such as the strategic
+    /// words costs one pass instead of one pass per word.
+    pub fn retain_allowed_guesses(&self, words: &mut Vec<String>) {
+        let wanted: std::collections::HashSet<&str> = words.iter().map(String::as_str).collect();
+        let allowed: std::collections::HashSet<String> = self.guess_words.iter()
+            .filter(|word| wanted.contains(word.as_str()))
+            .cloned()
+            .collect();
+        words.retain(|word| allowed.contains(word));
+    }
+
+    /// Check if a word can be the answer (case-insensitive)
+    pub fn is_possible_answer(&self, word: &str) -> bool {
+        self.answer_words.iter().any(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    /// Get the answer words consistent with every guess result
+    /// 
+    /// Only `answer_words` are considered, so guess-only words are never returned.
+    pub fn get_possible_answers(&self, guess_results: &[GuessResult]) -> Vec<String> {
+        crate::constraints::filter_words_merged(&self.answer_words, guess_results)
+    }
+
     pub fn get_guess_words(&self) -> &[String] {
         &self.guess_words
     }
@@ -128,133 +516,346 @@ impl WordManager {
     
 }
 
+/// Remove duplicate words while preserving first-seen order
+///
+/// Duplicate targets inflate pattern buckets in entropy analysis and
+/// double-count prime suspects, so caller-supplied lists are deduplicated
+/// before scoring.
+pub fn dedup_preserving_order(words: &[String]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    words.iter()
+        .filter(|word| seen.insert(word.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Remove repeated guess results (same word and feedback) while preserving order
+///
+/// A UI that submits the same row twice adds no information, so callers
+/// deduplicate before filtering instead of doing the work twice.
+pub fn dedup_guess_results(guess_results: &[GuessResult]) -> Vec<GuessResult> {
+    let mut seen = std::collections::HashSet::new();
+    guess_results.iter()
+        .filter(|guess_result| seen.insert(*guess_result))
+        .cloned()
+        .collect()
+}
+
+/// Check whether every letter of a word is different
+fn has_unique_letters(word: &str) -> bool {
+    let mut seen = std::collections::HashSet::new();
+    word.chars().all(|c| seen.insert(c))
+}
+
+/// Lock a global, recovering it if a panicking thread poisoned the mutex
+/// 
+/// The guarded data is plain word lists and settings, which stay valid even if
+/// a panic interrupted an update, so the poison flag is cleared and the guard
+/// returned. Without this, one panic would fail every later FFI call for the
+/// lifetime of the isolate.
+pub(crate) fn lock_recovering<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+/// Official word lists compiled into the binary, parsed on first use
+#[cfg(feature = "embedded-words")]
+static EMBEDDED_WORDS: Lazy<Result<(Vec<String>, Vec<String>), String>> = Lazy::new(|| {
+    let answer_json = include_str!("../../../assets/word_lists/official_wordle_words.json");
+    let guess_text = include_str!("../../../assets/word_lists/official_guess_words.txt");
+
+    let word_data: serde_json::Value = serde_json::from_str(answer_json)
+        .map_err(|e| format!("Failed to parse embedded answer words: {}", e))?;
+    let answer_words: Vec<String> = word_data.get("answer_words")
+        .and_then(|v| v.as_array())
+        .ok_or("Embedded answer words have no answer_words array")?
+        .iter()
+        .filter_map(|v| v.as_str())
+        .map(|word| word.to_uppercase())
+        .collect();
+    let guess_words: Vec<String> = guess_text.lines()
+        .map(|line| line.trim())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_uppercase())
+        .collect();
+
+    Ok((answer_words, guess_words))
+});
+
 /// Global word manager instance
 pub static WORD_MANAGER: Lazy<Mutex<WordManager>> = Lazy::new(|| {
     Mutex::new(WordManager::new())
 });
 
+/// Entropies shared by the solver's scoring across calls
+pub static ENTROPY_CACHE: Lazy<EntropyCache> = Lazy::new(EntropyCache::new);
+
 /// Global configuration instance
-pub static SOLVER_CONFIG: Lazy<Mutex<SolverConfig>> = Lazy::new(|| {
-    Mutex::new(SolverConfig {
-        reference_mode: false,
-        include_killer_words: true,  // Enable killer words by default for better performance
-        candidate_cap: 1000,         // Large candidate cap for thorough analysis
-        early_termination_enabled: false,  // Disable early termination for thorough analysis
-        early_termination_threshold: 10.0,
-        entropy_only_scoring: true,  // Use pure entropy scoring for better decisions
-    })
+pub static SOLVER_CONFIG: Lazy<Mutex<SolverConfig>> = Lazy::new(|| Mutex::new(SolverConfig::default()));
+
+/// Most recent error message from an FFI call, for callers that only receive None/empty
+pub static LAST_ERROR: Lazy<Mutex<Option<String>>> = Lazy::new(|| {
+    Mutex::new(None)
 });
 
+/// Tolerance when comparing a score against the best score possible
+const MAX_SCORE_EPSILON: f64 = 1e-9;
+
+/// Number of `IntelligentSolver::calculate_entropy` calls, for tests and profiling
+pub(crate) static ENTROPY_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Candidates `candidate_cap` dropped in the most recent `get_candidate_words` call
+pub(crate) static CANDIDATES_DROPPED_BY_CAP: AtomicUsize = AtomicUsize::new(0);
+
 impl IntelligentSolver {
     /// Create a new intelligent solver
     pub fn new(words: Vec<String>) -> Self {
         Self { words }
     }
 
+    /// Create a solver for the global word manager's lists without copying them
+    /// 
+    /// Scoring only reads the remaining words and guess results passed to each
+    /// call, which the FFI hot paths filter straight from the manager's interned
+    /// list. `words` is left empty rather than cloning ~14k strings per call; use
+    /// `new` when a test needs a solver that owns its words.
+    pub fn new_from_manager() -> Self {
+        Self { words: Vec::new() }
+    }
+
     /// Get the best guess using intelligent algorithms
     /// 
     /// IMPROVED VERSION: Implements three advanced strategies:
     /// 1. Two-List "Hard Mode" Strategy - Smart choice between info gathering vs winning
     /// 2. Dynamic Candidate Selection - Focus on high-potential words only
     /// 3. Minimax Look-Ahead Score - Advanced endgame optimization
-    pub fn get_best_guess(&self, remaining_words: &[String], _guess_results: &[GuessResult]) -> Option<String> {
-        if remaining_words.is_empty() {
-            return None;
+    pub fn get_best_guess(&self, remaining_words: &[String], guess_results: &[GuessResult]) -> Option<String> {
+        self.get_top_guesses(remaining_words, guess_results, 1).into_iter().next()
+    }
+
+    /// Get the `count` highest-scoring distinct guesses, best first
+    /// 
+    /// Uses exactly the same candidate scoring and early termination as
+    /// `get_best_guess`, so the first entry always equals its result.
+    /// 
+    /// Words already in `guess_results` are never suggested again, since
+    /// repeating a guess wastes a turn, unless one is the only remaining answer.
+    pub fn get_top_guesses(&self, remaining_words: &[String], guess_results: &[GuessResult], count: usize) -> Vec<String> {
+        let config = lock_recovering(&SOLVER_CONFIG).clone();
+        self.get_top_guesses_with_config(&config, remaining_words, guess_results, count)
+    }
+
+    /// Get the top guesses like `get_top_guesses`, under `config` instead of `SOLVER_CONFIG`
+    /// 
+    /// Lets callers compare configs without touching the global one.
+    pub fn get_top_guesses_with_config(&self, config: &SolverConfig, remaining_words: &[String], guess_results: &[GuessResult], count: usize) -> Vec<String> {
+        if remaining_words.is_empty() || count == 0 {
+            return Vec::new();
         }
 
+        // Deduplicate so repeated targets don't skew pattern buckets
+        let remaining_words = &dedup_preserving_order(remaining_words);
+        let is_prior_guess = |word: &String| guess_results.iter().any(|gr| &gr.word == word);
+
         // For endgame scenarios (few remaining words), use direct strategy
+        // Without word priors both are equally likely, so guess them alphabetically
+        // to keep the choice independent of the caller's word order
         if remaining_words.len() <= 2 {
-            return remaining_words.first().cloned();
+            let fresh_words: Vec<String> = remaining_words.iter()
+                .filter(|word| !is_prior_guess(word))
+                .cloned()
+                .collect();
+            let mut endgame_words = if fresh_words.is_empty() { remaining_words.clone() } else { fresh_words };
+            endgame_words.sort();
+            return endgame_words.into_iter().take(count).collect();
+        }
+
+        // Too many words for full scoring: rank the strategic shortlist by letter
+        // frequency, which needs no pattern simulation
+        if remaining_words.len() > config.full_scoring_max_remaining.max(0) as usize {
+            let mut shortlist = self.get_top_strategic_words();
+            lock_recovering(&WORD_MANAGER).retain_allowed_guesses(&mut shortlist);
+            shortlist.retain(|word| !is_prior_guess(word));
+            if !shortlist.is_empty() {
+                let mut scored: Vec<(String, f64)> = shortlist.into_iter()
+                    .map(|word| {
+                        let score = self.calculate_statistical_score(&word, remaining_words);
+                        (word, score)
+                    })
+                    .collect();
+                // Stable, so ties keep the shortlist's ranking
+                scored.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+                return scored.into_iter().take(count).map(|(word, _)| word).collect();
+            }
         }
 
         // Get candidate words (for now, use remaining words; in future could use full word list)
-        let candidate_words = self.get_candidate_words(remaining_words, _guess_results);
+        let mut candidate_words = self.get_candidate_words_with_config(config, remaining_words, guess_results);
+        candidate_words.retain(|word| !is_prior_guess(word));
         
         // Analyze each candidate using entropy with early termination
-        let mut best_word = None;
-        let mut best_score = f64::NEG_INFINITY;
-        
-        // BALANCED OPTIMIZATION: Early termination threshold
-        // If we find a word with very high entropy, we can stop early
-        // Adjusted to be less aggressive for better accuracy
-        let early_termination_threshold = 5.0; // Higher threshold for better accuracy
-        let mut candidates_processed = 0;
+        // Top words kept sorted by score, best first
+        let mut top_words: Vec<(String, f64)> = Vec::with_capacity(count + 1);
+
+        // Opening heuristic: repeated letters waste coverage in the first two guesses
+        let favor_unique_letters = guess_results.len() < 2 && config.prefer_unique_letters;
+
+        // No guess can beat one that puts every remaining word in its own bucket
+        // and collects every bonus, so once all kept words score that, later
+        // candidates can only tie, and ties go to the earlier word
+        let max_entropy = (remaining_words.len() as f64).log2();
+        let unique_letter_bonus_max = if favor_unique_letters { 0.1 } else { 0.0 };
+        let max_score = max_entropy + config.prime_suspect_bonus + unique_letter_bonus_max;
 
         for candidate in candidate_words.iter() {
-            let entropy_score = self.calculate_entropy(candidate, remaining_words);
+            let entropy_score = self.calculate_entropy_cached(&ENTROPY_CACHE, candidate, remaining_words);
             let statistical_score = self.calculate_statistical_score(candidate, remaining_words);
             
             // Prime suspect bonus: prioritize words that could actually win the game
             let is_prime_suspect = remaining_words.contains(candidate);
-            let prime_suspect_bonus = if is_prime_suspect { 0.1 } else { 0.0 };
+            let prime_suspect_bonus = if is_prime_suspect { config.prime_suspect_bonus } else { 0.0 };
+            let unique_letter_bonus = if favor_unique_letters && has_unique_letters(candidate) { 0.1 } else { 0.0 };
             
             // Use production settings - full algorithm power (pure entropy)
             let entropy_weight = 1.0;
             let statistical_weight = 0.0;
                     
             // Combine scores with prime suspect bonus
-            let combined_score = (entropy_score * entropy_weight) + (statistical_score * statistical_weight) + prime_suspect_bonus;                                                                                               
+            let combined_score = (entropy_score * entropy_weight) + (statistical_score * statistical_weight) + prime_suspect_bonus + unique_letter_bonus;                                                                                               
+            debug_assert!(combined_score.is_finite(), "Non-finite score {} for {}", combined_score, candidate);
             
-            if combined_score > best_score {
-                best_score = combined_score;
-                best_word = Some(candidate.clone());
+            // Earlier candidates win ties, matching a strict `>` best-score comparison
+            let position = top_words.iter()
+                .position(|(_, score)| combined_score > *score)
+                .unwrap_or(top_words.len());
+            if position < count {
+                top_words.insert(position, (candidate.clone(), combined_score));
+                top_words.truncate(count);
                 
-                // CRITICAL OPTIMIZATION: Early termination
-                // If we found a word with very high entropy, stop processing
-                if entropy_score >= early_termination_threshold {
+                // Early termination: a new best word with enough entropy ends the search
+                if config.early_termination_enabled && position == 0 && entropy_score >= config.early_termination_threshold {
                     break;
                 }
             }
-            
-            candidates_processed += 1;
-            
-            // QUALITY-FOCUSED OPTIMIZATION: Limit processing for performance
-            // Don't process more than 100 candidates (reverted from 150)
-            if candidates_processed >= 100 {
+            if top_words.len() == count && top_words.iter().all(|(_, score)| *score >= max_score - MAX_SCORE_EPSILON) {
                 break;
             }
         }
 
-        best_word
+        top_words.into_iter().map(|(word, _)| word).collect()
     }
 
-    /// Calculate entropy (information gain) for a candidate word
+    /// Get the best single guess across several boards (Quordle/Dordle)
     /// 
-    /// BALANCED: Uses Shannon entropy - simple and effective
-    /// Based on the working algorithm that achieved 96% success rate
-    pub fn calculate_entropy(&self, candidate_word: &str, remaining_words: &[String]) -> f64 {
-        if remaining_words.is_empty() || remaining_words.len() == 1 {
-            return 0.0;
+    /// Each entry of `boards_remaining` holds the possible answers of one unsolved
+    /// board. A candidate's score is its entropy summed over all boards, plus the
+    /// prime suspect bonus for every board it could solve. A board that is down to a
+    /// single word is finished first, since that guess is guaranteed to solve it.
+    pub fn get_best_guess_multiboard(&self, boards_remaining: &[Vec<String>]) -> Option<String> {
+        let boards: Vec<Vec<String>> = boards_remaining.iter()
+            .map(|remaining| dedup_preserving_order(remaining))
+            .filter(|remaining| !remaining.is_empty())
+            .collect();
+
+        if boards.is_empty() {
+            return None;
         }
 
-        // Group words by the pattern they would produce
-        let mut pattern_groups: HashMap<String, usize> = HashMap::new();
-        
-        for target_word in remaining_words {
-            let pattern = self.simulate_guess_pattern(candidate_word, target_word);
-            *pattern_groups.entry(pattern).or_insert(0) += 1;
+        // A board with exactly one answer left is solved by guessing it
+        if let Some(solved) = boards.iter().find(|remaining| remaining.len() == 1) {
+            return solved.first().cloned();
         }
 
-        // Calculate Shannon entropy
-        let total_words = remaining_words.len() as f64;
-        let mut entropy = 0.0;
+        // Candidates come from the union of every board's remaining words
+        let all_remaining = dedup_preserving_order(&boards.concat());
+        let candidate_words = self.get_candidate_words(&all_remaining, &[]);
 
-        for &count in pattern_groups.values() {
-            let probability = count as f64 / total_words;
-            if probability > 0.0 {
-                entropy -= probability * (probability.ln() / LN_2);
+        let bonus = lock_recovering(&SOLVER_CONFIG).prime_suspect_bonus;
+        let mut best_word = None;
+        let mut best_score = f64::NEG_INFINITY;
+
+        for candidate in candidate_words.iter() {
+            let combined_score: f64 = boards.iter()
+                .map(|remaining| {
+                    let prime_suspect_bonus = if remaining.contains(candidate) { bonus } else { 0.0 };
+                    self.calculate_entropy_cached(&ENTROPY_CACHE, candidate, remaining) + prime_suspect_bonus
+                })
+                .sum();
+            debug_assert!(combined_score.is_finite(), "Non-finite score {} for {}", combined_score, candidate);
+
+            if combined_score > best_score {
+                best_score = combined_score;
+                best_word = Some(candidate.clone());
             }
         }
 
+        best_word
+    }
+
+    /// Score every candidate by entropy and return the `top_n` best, best first
+    /// 
+    /// With `parallel` set, entropies are computed across threads with rayon. Ties
+    /// are broken alphabetically, so both modes produce exactly the same ranking.
+    pub fn score_all_candidates(&self, candidates: &[String], remaining_words: &[String], top_n: usize, parallel: bool) -> Vec<(String, f64)> {
+        use rayon::prelude::*;
+
+        let score = |candidate: &String| (candidate.clone(), self.calculate_entropy_cached(&ENTROPY_CACHE, candidate, remaining_words));
+        let mut scored: Vec<(String, f64)> = if parallel {
+            candidates.par_iter().map(score).collect()
+        } else {
+            candidates.iter().map(score).collect()
+        };
+
+        // Entropy descending, then word ascending, for a total and stable order
+        scored.sort_by(|(word_a, entropy_a), (word_b, entropy_b)| {
+            entropy_b.total_cmp(entropy_a).then_with(|| word_a.cmp(word_b))
+        });
+        scored.truncate(top_n);
+        scored
+    }
+
+    /// Calculate entropy like `calculate_entropy`, reusing results from `cache`
+    /// 
+    /// Lets analysis functions that score the same candidate against the same
+    /// remaining words (e.g. best guess, then top guesses) share the work.
+    pub fn calculate_entropy_cached(&self, cache: &EntropyCache, candidate_word: &str, remaining_words: &[String]) -> f64 {
+        let hash = EntropyCache::hash_words(remaining_words);
+        if let Some(entropy) = cache.get(hash, candidate_word, remaining_words) {
+            return entropy;
+        }
+
+        // Computed outside the lock so parallel scorers don't serialize on it
+        let entropy = self.calculate_entropy(candidate_word, remaining_words);
+        cache.evaluations.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        cache.insert(hash, candidate_word, remaining_words, entropy);
         entropy
     }
 
+    /// Calculate entropy (information gain) for a candidate word
+    /// 
+    /// BALANCED: Uses Shannon entropy - simple and effective
+    /// Based on the working algorithm that achieved 96% success rate
+    /// 
+    /// Returns 0.0 for an empty candidate or fewer than two remaining words.
+    pub fn calculate_entropy(&self, candidate_word: &str, remaining_words: &[String]) -> f64 {
+        if candidate_word.is_empty() {
+            return 0.0;
+        }
+        ENTROPY_EVALUATIONS.fetch_add(1, Ordering::Relaxed);
+        crate::solver_core::calculate_entropy(candidate_word, remaining_words)
+    }
+
     /// Calculate statistical score based on letter frequency and position probability
     /// 
     /// BALANCED: Simple and effective statistical analysis
     /// Based on the working algorithm that achieved 96% success rate
+    /// 
+    /// Returns 0.0 for an empty candidate or an empty remaining list, rather than
+    /// dividing by zero and letting NaN break score comparisons.
     pub fn calculate_statistical_score(&self, candidate_word: &str, remaining_words: &[String]) -> f64 {
-        if remaining_words.is_empty() {
+        if candidate_word.is_empty() || remaining_words.is_empty() {
             return 0.0;
         }
 
@@ -271,55 +872,137 @@ impl IntelligentSolver {
     }
 
 
-    /// Simulate the guess pattern that would result from guessing against a target word
-    pub fn simulate_guess_pattern(&self, guess: &str, target: &str) -> String {
-        let mut result = vec!['X'; 5];
-        let mut target_chars: Vec<char> = target.chars().collect();
-        let guess_chars: Vec<char> = guess.chars().collect();
-
-        // First pass: mark green letters (correct position)
-        for i in 0..5 {
-            if guess_chars[i] == target_chars[i] {
-                result[i] = 'G';
-                target_chars[i] = ' '; // Mark as used
-            }
+    /// Cheap stand-in for entropy, used to rank candidates before the cap
+    /// 
+    /// Each distinct letter of a candidate scores p * (1 - p), where p is the
+    /// share of remaining words containing it. Letters in about half of the words
+    /// split them best, while letters every word has (or none) tell nothing. Letter
+    /// counts are gathered once, so this is cheap even for the full dictionary.
+    fn letter_split_scores(candidates: &[String], remaining_words: &[String]) -> Vec<f64> {
+        if remaining_words.is_empty() {
+            return vec![0.0; candidates.len()];
         }
 
-        // Second pass: mark yellow letters (wrong position)
-        for i in 0..5 {
-            if result[i] == 'X' { // Not already green
-                if let Some(pos) = target_chars.iter().position(|&c| c == guess_chars[i]) {
-                    result[i] = 'Y';
-                    target_chars[pos] = ' '; // Mark as used
-                }
+        let distinct_letters = |word: &str| {
+            let mut letters: Vec<char> = word.chars().collect();
+            letters.sort_unstable();
+            letters.dedup();
+            letters
+        };
+        let mut words_containing: std::collections::HashMap<char, usize> = std::collections::HashMap::new();
+        for word in remaining_words {
+            for letter in distinct_letters(word) {
+                *words_containing.entry(letter).or_insert(0) += 1;
             }
         }
 
-        result.iter().collect()
+        let total = remaining_words.len() as f64;
+        candidates.iter()
+            .map(|candidate| distinct_letters(candidate).into_iter()
+                .map(|letter| {
+                    let share = words_containing.get(&letter).copied().unwrap_or(0) as f64 / total;
+                    share * (1.0 - share)
+                })
+                .sum())
+            .collect()
+    }
+
+    /// Keep the `count` candidates with the best `letter_split_scores`, best first
+    /// 
+    /// Ties go to the alphabetically earlier word.
+    pub fn best_letter_splitters(candidates: Vec<String>, remaining_words: &[String], count: usize) -> Vec<String> {
+        let scores = Self::letter_split_scores(&candidates, remaining_words);
+        let mut ranked: Vec<(String, f64)> = candidates.into_iter().zip(scores).collect();
+        ranked.sort_by(|(word_a, score_a), (word_b, score_b)| {
+            score_b.total_cmp(score_a).then_with(|| word_a.cmp(word_b))
+        });
+        ranked.into_iter().take(count).map(|(word, _)| word).collect()
+    }
+
+    /// Simulate the guess pattern that would result from guessing against a target word
+    pub fn simulate_guess_pattern(&self, guess: &str, target: &str) -> String {
+        self.pattern_results(guess, target).into_iter().map(LetterResult::to_token).collect()
+    }
+
+    /// Simulate the guess pattern along with the target letter each guess letter used
+    /// 
+    /// Returns the pattern (as `simulate_guess_pattern`) and, per guess position,
+    /// the target index that absorbed the letter: its own index for a green, the
+    /// consumed index for a yellow, or `None` for a gray.
+    pub fn simulate_guess_pattern_detailed(&self, guess: &str, target: &str) -> (String, Vec<Option<usize>>) {
+        let matches = crate::solver_core::simulate_guess_matches(guess, target);
+        let pattern = matches.iter().enumerate().map(|(i, matched)| match matched {
+            Some(pos) if *pos == i => 'G',
+            Some(_) => 'Y',
+            None => 'X',
+        }).collect();
+        (pattern, matches.to_vec())
+    }
+
+    /// Simulate the feedback for guessing against a target word as `LetterResult`s
+    /// 
+    /// Same feedback as `simulate_guess_pattern`, without a string to parse back.
+    pub fn pattern_results(&self, guess: &str, target: &str) -> Vec<LetterResult> {
+        crate::solver_core::simulate_guess_ranks(guess, target).into_iter().map(LetterResult::from_rank).collect()
     }
 
     /// Get candidate words for analysis - OPTIMIZED for performance
     /// 
     /// CRITICAL OPTIMIZATION: Limit to 50-100 strategic words instead of thousands
     /// This is the key fix for the 1293ms -> <200ms performance improvement
-    pub fn get_candidate_words(&self, remaining_words: &[String], _guess_results: &[GuessResult]) -> Vec<String> {
+    /// 
+    /// The pool depends on `SolverConfig::candidate_source`. When it holds more
+    /// than `candidate_cap` words, the ones whose letters best split the remaining
+    /// words are kept (see `letter_split_scores`), so a large pool like
+    /// `FullDictionary` isn't cut off alphabetically. The kept candidates are
+    /// returned in alphabetical order.
+    pub fn get_candidate_words(&self, remaining_words: &[String], guess_results: &[GuessResult]) -> Vec<String> {
+        let config = lock_recovering(&SOLVER_CONFIG).clone();
+        self.get_candidate_words_with_config(&config, remaining_words, guess_results)
+    }
+
+    /// Get candidate words like `get_candidate_words`, under `config` instead of `SOLVER_CONFIG`
+    fn get_candidate_words_with_config(&self, config: &SolverConfig, remaining_words: &[String], guess_results: &[GuessResult]) -> Vec<String> {
         let mut candidates = Vec::new();
         
         // Always include all remaining words (prime suspects)
         candidates.extend(remaining_words.iter().cloned());
         
-        // Add "killer" words - the top statistical words for information gathering
-        // This gives us access to optimal words regardless of alphabetical position
-        candidates.extend(self.get_top_strategic_words());
+        match config.candidate_source {
+            CandidateSource::RemainingOnly => {}
+            CandidateSource::RemainingPlusStrategic if config.include_killer_words => {
+                // Add "killer" words - the top statistical words for information gathering
+                // This gives us access to optimal words regardless of alphabetical position
+                // The list is static, so words missing from a custom dictionary are dropped
+                let mut strategic_words = self.get_top_strategic_words();
+                lock_recovering(&WORD_MANAGER).retain_allowed_guesses(&mut strategic_words);
+                candidates.extend(strategic_words);
+            }
+            CandidateSource::RemainingPlusStrategic => {}
+            CandidateSource::FullDictionary => {
+                candidates.extend(lock_recovering(&WORD_MANAGER).get_guess_words().iter().cloned());
+            }
+        }
         
         // Remove duplicates
         candidates.sort();
         candidates.dedup();
+
+        // Variants that never repeat an answer gain nothing from replaying a guess
+        if config.exclude_prior_guesses {
+            candidates.retain(|word| !guess_results.iter().any(|gr| &gr.word == word));
+        }
         
         // PERFORMANCE FIX: Cap candidates to prevent O(n²) slowdown with large word lists
         // The reference algorithm was designed for smaller word lists
-        if candidates.len() > 200 {
-            candidates.truncate(200);
+        // A cap below 1 would leave nothing to score, so at least one candidate is kept
+        let candidate_cap = config.candidate_cap.max(1) as usize;
+        CANDIDATES_DROPPED_BY_CAP.store(candidates.len().saturating_sub(candidate_cap), Ordering::Relaxed);
+        if candidates.len() > candidate_cap {
+            // Keep the best splitters, then restore alphabetical order so scoring
+            // ties still go to the earlier word
+            candidates = Self::best_letter_splitters(candidates, remaining_words, candidate_cap);
+            candidates.sort();
         }
         
         // Return the strategic candidate list (typically <100 words)
@@ -392,9 +1075,7 @@ impl IntelligentSolver {
         let mut filtered = words.to_vec();
         
         for guess_result in guess_results {
-            filtered = filtered.into_iter()
-                .filter(|word| self.word_matches_pattern(word, guess_result))
-                .collect();
+            filtered.retain(|word| self.word_matches_pattern(word, guess_result));
         }
         
         filtered
@@ -402,103 +1083,18 @@ impl IntelligentSolver {
 
     /// Check if a word matches the given guess pattern
     pub fn word_matches_pattern(&self, word: &str, guess_result: &GuessResult) -> bool {
-        let word_chars: Vec<char> = word.chars().collect();
-        let guess_chars: Vec<char> = guess_result.word.chars().collect();
-        
-        // Build letter count constraints from guess
-        use std::collections::HashMap;
-        let mut min_required: HashMap<char, usize> = HashMap::new();
-        let mut banned_positions: HashMap<usize, char> = HashMap::new();
-        let mut fixed_positions: HashMap<usize, char> = HashMap::new();
-        let mut total_occurrence_cap: HashMap<char, usize> = HashMap::new();
-        
-        // First pass: fixed greens and count greens/yellows per letter
-        for i in 0..5 {
-            match guess_result.results[i] {
-                LetterResult::Green => {
-                    fixed_positions.insert(i, guess_chars[i]);
-                    *min_required.entry(guess_chars[i]).or_insert(0) += 1;
-                }
-                LetterResult::Yellow => {
-                    banned_positions.insert(i, guess_chars[i]);
-                    *min_required.entry(guess_chars[i]).or_insert(0) += 1;
-                }
-                LetterResult::Gray => {}
-            }
-        }
-        
-        // Second pass: for grays, if the letter also appears as green/yellow elsewhere,
-        // cap the total occurrences to that minimum (i.e., no extra occurrences)
-        for i in 0..5 {
-            if let LetterResult::Gray = guess_result.results[i] {
-                let ch = guess_chars[i];
-                if let Some(&required) = min_required.get(&ch) {
-                    // gray means no more than required occurrences across the word
-                    total_occurrence_cap.insert(ch, required);
-                } else {
-                    // letter is completely absent
-                    total_occurrence_cap.insert(ch, 0);
-                }
-            }
-        }
-        
-        // First, check green letters (must be in exact position)
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Green {
-                if word_chars[i] != guess_chars[i] {
-                    return false;
-                }
-            }
-        }
-        
-        // Then check yellow letters (must be in word but not in this position)
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Yellow {
-                if word_chars[i] == guess_chars[i] {
-                    return false; // Can't be in same position
-                }
-                if !word_chars.contains(&guess_chars[i]) {
-                    return false; // Must contain the letter
-                }
-            }
-        }
-        
-        // Gray letters and occurrence caps
-        // Enforce position bans and total occurrence limits
-        let mut counts: HashMap<char, usize> = HashMap::new();
-        for (idx, ch) in word_chars.iter().enumerate() {
-            // Position bans (from yellows)
-            if let Some(&banned_ch) = banned_positions.get(&idx) {
-                if *ch == banned_ch {
-                    return false;
-                }
-            }
-            // Count occurrences
-            *counts.entry(*ch).or_insert(0) += 1;
-        }
-        
-        // Enforce minimums (greens+yellows) and caps (grays)
-        for (ch, &min_cnt) in min_required.iter() {
-            if counts.get(ch).cloned().unwrap_or(0) < min_cnt {
-                return false;
-            }
-        }
-        for (ch, &cap_cnt) in total_occurrence_cap.iter() {
-            if counts.get(ch).cloned().unwrap_or(0) > cap_cnt {
-                return false;
-            }
-        }
-        
-        true
+        crate::constraints::word_matches_single_feedback(word, guess_result)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::test_support::lock_global_state;
 
     #[test]
     fn test_entropy_calculation_basic() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let solver = IntelligentSolver::new(words);
         
@@ -514,8 +1110,120 @@ mod tests {
         assert_eq!(entropy_self, 0.0);
     }
 
+    #[test]
+    fn test_entropy_cache_reuses_results() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let cache = EntropyCache::new();
+        let remaining: Vec<String> = ["CRANE", "SLATE", "CRATE", "PLATE"].iter().map(|w| w.to_string()).collect();
+
+        let first = solver.calculate_entropy_cached(&cache, "TRACE", &remaining);
+        let second = solver.calculate_entropy_cached(&cache, "TRACE", &remaining);
+        assert_eq!(first, second);
+        assert_eq!(first, solver.calculate_entropy("TRACE", &remaining));
+        assert_eq!(cache.evaluations(), 1);
+
+        // A different remaining set is a different entry
+        solver.calculate_entropy_cached(&cache, "TRACE", &remaining[..3]);
+        assert_eq!(cache.evaluations(), 2);
+    }
+
+    #[test]
+    fn test_scoring_shares_the_entropy_cache() {
+        let _globals = lock_global_state();
+        SOLVER_CONFIG.lock().unwrap().candidate_source = CandidateSource::RemainingOnly;
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining: Vec<String> = ["BLIMP", "CRANE", "CRATE", "GRATE", "TRACE"].iter().map(|w| w.to_string()).collect();
+
+        let ranked = solver.score_all_candidates(&remaining, &remaining, 5, true);
+        let evaluations = ENTROPY_EVALUATIONS.load(Ordering::Relaxed);
+        let top_two = solver.get_top_guesses(&remaining, &[], 2);
+        assert_eq!(solver.get_best_guess(&remaining, &[]), top_two.first().cloned());
+        assert_eq!(solver.score_all_candidates(&remaining, &remaining, 5, false), ranked);
+        // Every candidate was already scored against this list
+        assert_eq!(ENTROPY_EVALUATIONS.load(Ordering::Relaxed), evaluations);
+
+        // A different list is scored afresh
+        solver.get_best_guess(&remaining[1..], &[]);
+        assert!(ENTROPY_EVALUATIONS.load(Ordering::Relaxed) > evaluations);
+    }
+
+    #[test]
+    fn test_scores_are_zero_for_empty_inputs() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining = vec!["CRANE".to_string(), "SLATE".to_string()];
+
+        assert_eq!(solver.calculate_statistical_score("CRANE", &[]), 0.0);
+        assert_eq!(solver.calculate_statistical_score("", &remaining), 0.0);
+        assert_eq!(solver.calculate_entropy("CRANE", &[]), 0.0);
+        assert_eq!(solver.calculate_entropy("", &remaining), 0.0);
+    }
+
+    #[test]
+    fn test_score_all_candidates_parallel_matches_serial() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining: Vec<String> = ["CRANE", "CRATE", "GRATE", "PLATE", "SLATE", "STATE", "SKATE", "SPATE"]
+            .iter().map(|w| w.to_string()).collect();
+        let candidates: Vec<String> = ["TARES", "SLATE", "PRICK", "CLOMP", "CRANE", "SPATE", "GHOST"]
+            .iter().map(|w| w.to_string()).collect();
+
+        let serial = solver.score_all_candidates(&candidates, &remaining, 5, false);
+        let parallel = solver.score_all_candidates(&candidates, &remaining, 5, true);
+        assert_eq!(serial, parallel);
+        assert_eq!(serial.len(), 5);
+        assert!(serial.windows(2).all(|pair| pair[0].1 > pair[1].1 || (pair[0].1 == pair[1].1 && pair[0].0 < pair[1].0)));
+    }
+
+    #[test]
+    fn test_prefer_unique_letters_breaks_ties() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        // Each word tells the other two apart, so all three score the same
+        let remaining: Vec<String> = ["ABBEY", "CRANE", "DOING"].iter().map(|w| w.to_string()).collect();
+        assert_eq!(solver.calculate_entropy("ABBEY", &remaining), solver.calculate_entropy("CRANE", &remaining));
+
+        SOLVER_CONFIG.lock().unwrap().prefer_unique_letters = false;
+        assert_eq!(solver.get_best_guess(&remaining, &[]), Some("ABBEY".to_string()));
+
+        SOLVER_CONFIG.lock().unwrap().prefer_unique_letters = true;
+        assert_eq!(solver.get_best_guess(&remaining, &[]), Some("CRANE".to_string()));
+    }
+
+    #[test]
+    fn test_two_word_endgame_is_alphabetical() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let forward = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let backward = vec!["SLATE".to_string(), "CRANE".to_string()];
+
+        assert_eq!(solver.get_best_guess(&forward, &[]), Some("CRANE".to_string()));
+        assert_eq!(solver.get_best_guess(&backward, &[]), Some("CRANE".to_string()));
+        assert_eq!(solver.get_top_guesses(&backward, &[], 2), forward);
+    }
+
+    #[test]
+    fn test_pattern_results_match_pattern_string() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let pairs = [("CRANE", "CRANE"), ("SPEED", "ERASE"), ("ALLEY", "LLAMA"), ("TARES", "BLIMP")];
+
+        for (guess, target) in pairs {
+            let results = solver.pattern_results(guess, target);
+            let pattern = solver.simulate_guess_pattern(guess, target);
+            let tokens: String = results.iter().map(|r| r.to_token()).collect();
+            assert_eq!(tokens, pattern);
+            assert_eq!(results.iter().map(|r| LetterResult::from_rank(r.rank())).collect::<Vec<_>>(), results);
+        }
+        assert_eq!(solver.pattern_results("SPEED", "ERASE"), vec![
+            LetterResult::Yellow, LetterResult::Gray, LetterResult::Yellow, LetterResult::Yellow, LetterResult::Gray,
+        ]);
+    }
+
     #[test]
     fn test_pattern_simulation() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string()];
         let solver = IntelligentSolver::new(words);
         
@@ -529,6 +1237,7 @@ mod tests {
 
     #[test]
     fn test_intelligent_solver_basic() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let solver = IntelligentSolver::new(words);
         
@@ -540,8 +1249,50 @@ mod tests {
         assert!(remaining.contains(&best_guess.unwrap()));
     }
 
+    #[test]
+    fn test_simulate_guess_pattern_detailed() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+
+        // The first E of EERIE is yellow and takes THEME's free E at index 2; the
+        // second E would need another free E, so it is gray. The last E is green.
+        let (pattern, matches) = solver.simulate_guess_pattern_detailed("EERIE", "THEME");
+        assert_eq!(pattern, "YXXXG");
+        assert_eq!(matches, vec![Some(2), None, None, None, Some(4)]);
+
+        for (guess, target) in [("EERIE", "THEME"), ("SPEED", "ABIDE"), ("LLAMA", "ALLOT"), ("CRANE", "CRANE")] {
+            let (pattern, _) = solver.simulate_guess_pattern_detailed(guess, target);
+            assert_eq!(pattern, solver.simulate_guess_pattern(guess, target));
+        }
+    }
+
+    #[test]
+    fn test_dedup_preserving_order() {
+        let words = vec![
+            "SLATE".to_string(), "CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string(), "CRANE".to_string(),
+        ];
+        let deduped = dedup_preserving_order(&words);
+        assert_eq!(deduped, vec!["SLATE".to_string(), "CRANE".to_string(), "CRATE".to_string()]);
+    }
+
+    #[test]
+    fn test_top_guesses_match_best_guess() {
+        let _globals = lock_global_state();
+        let words = vec![
+            "CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string(),
+            "PLATE".to_string(), "GRATE".to_string(), "TRACE".to_string(),
+        ];
+        let solver = IntelligentSolver::new(words.clone());
+
+        let top = solver.get_top_guesses(&words, &[], 2);
+        assert_eq!(top.len(), 2);
+        assert_ne!(top[0], top[1]);
+        assert_eq!(top.first().cloned(), solver.get_best_guess(&words, &[]));
+    }
+
     #[test]
     fn test_word_filtering() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let solver = IntelligentSolver::new(words.clone());
         
@@ -562,6 +1313,7 @@ mod tests {
 
     #[test]
     fn test_word_filtering_all_green_matches() {
+        let _globals = lock_global_state();
         let words = vec!["CRANE".to_string()];
         let solver = IntelligentSolver::new(words.clone());
         let guess = GuessResult::new(
@@ -577,4 +1329,326 @@ mod tests {
         let filtered = solver.filter_words(&words, &[guess]);
         assert_eq!(filtered, vec!["CRANE".to_string()]);
     }
+
+    #[test]
+    fn test_guess_result_display_round_trip() {
+        let cases = [
+            ("TARES", [LetterResult::Green, LetterResult::Yellow, LetterResult::Yellow, LetterResult::Gray, LetterResult::Gray], "TARES:GYYXX"),
+            ("CRANE", [LetterResult::Green; 5], "CRANE:GGGGG"),
+            ("EERIE", [LetterResult::Gray, LetterResult::Gray, LetterResult::Yellow, LetterResult::Gray, LetterResult::Green], "EERIE:XXYXG"),
+        ];
+
+        for (word, results, text) in cases {
+            let guess = GuessResult::new(word.to_string(), results);
+            assert_eq!(guess.to_string(), text);
+
+            let parsed: GuessResult = text.parse().unwrap();
+            assert_eq!(parsed.word, guess.word);
+            assert_eq!(parsed.results, guess.results);
+        }
+    }
+
+    #[test]
+    fn test_from_guess_against_matches_simulated_pattern() {
+        let pairs = [("CRANE", "CRANE"), ("CRATE", "CRANE"), ("SPEED", "ERASE"), ("ALLEY", "LLAMA"), ("tares", "crane")];
+
+        for (guess, target) in pairs {
+            let guess_result = GuessResult::from_guess_against(guess, target);
+            let expected = crate::api::simple::simulate_guess_pattern(guess.to_string(), target.to_string());
+            assert_eq!(guess_result.word, guess.to_uppercase());
+            assert_eq!(guess_result.pattern_string(), expected, "{} vs {}", guess, target);
+        }
+    }
+
+    #[test]
+    fn test_guess_result_parse_rejects_malformed() {
+        assert!("TARES:GYY".parse::<GuessResult>().is_err());
+        assert!("TARESGYYXX".parse::<GuessResult>().is_err());
+        assert!("TARES:GYYXZ".parse::<GuessResult>().is_err());
+        assert!(":".parse::<GuessResult>().is_err());
+    }
+
+    #[test]
+    fn test_interned_guess_words_are_shared() {
+        let _globals = lock_global_state();
+        let mut manager = WordManager::new();
+        manager.set_words(
+            vec!["CRANE".to_string()],
+            vec!["CRANE".to_string(), "SLATE".to_string(), "TARES".to_string()],
+        );
+
+        let interned = manager.get_interned_guess_words();
+        let as_strs: Vec<&str> = interned.iter().map(|word| word.as_ref()).collect();
+        assert_eq!(as_strs, vec!["CRANE", "SLATE", "TARES"]);
+        assert_eq!(manager.get_optimal_first_guess(), Some("TARES".to_string()));
+
+        // Repeated calls share one allocation instead of copying the list
+        assert!(Arc::ptr_eq(&interned, &manager.get_interned_guess_words()));
+
+        // Direct field writes still produce an up-to-date list
+        manager.guess_words = vec!["PLATE".to_string()];
+        assert_eq!(&*manager.get_interned_guess_words()[0], "PLATE");
+    }
+
+    #[test]
+    fn test_guess_only_words_are_not_answers() {
+        let _globals = lock_global_state();
+        let mut manager = WordManager::new();
+        manager.set_words(
+            vec!["CRANE".to_string(), "SLATE".to_string()],
+            vec!["CRANE".to_string(), "SOARE".to_string()],
+        );
+
+        // Answers are always allowed guesses, guess-only words never answers
+        assert!(manager.is_allowed_guess("SOARE"));
+        assert!(manager.is_allowed_guess("slate"));
+        assert!(!manager.is_possible_answer("SOARE"));
+        assert!(manager.is_possible_answer("crane"));
+        assert!(!manager.is_allowed_guess("PLUMB"));
+
+        // SOARE matches its own all-green feedback but is still not an answer
+        let all_green = GuessResult::new("SOARE".to_string(), [LetterResult::Green; 5]);
+        assert!(manager.get_possible_answers(&[all_green]).is_empty());
+
+        let crane_hint = GuessResult::new("CRANE".to_string(), [LetterResult::Green; 5]);
+        assert_eq!(manager.get_possible_answers(&[crane_hint]), vec!["CRANE".to_string()]);
+
+        // The built-in lists keep their lists separate too
+        let mut fallback = WordManager::new();
+        fallback.load_words().unwrap();
+        assert!(fallback.guess_words.len() > fallback.answer_words.len());
+        #[cfg(not(feature = "embedded-words"))]
+        {
+            assert!(fallback.is_allowed_guess("ADIEU"));
+            assert!(!fallback.is_possible_answer("ADIEU"));
+        }
+    }
+
+    #[cfg(feature = "embedded-words")]
+    #[test]
+    fn test_load_words_uses_embedded_lists() {
+        let _globals = lock_global_state();
+        // The lists are compiled in, so no asset directory is consulted
+        let mut manager = WordManager::new();
+        manager.load_words().unwrap();
+
+        assert_eq!(manager.answer_words.len(), 2300);
+        assert!(manager.guess_words.len() >= 14855);
+        assert!(manager.is_possible_answer("JETTY"));
+        assert!(manager.is_allowed_guess("TARES"));
+        assert_eq!(manager.get_optimal_first_guess(), Some("TARES".to_string()));
+    }
+
+    #[test]
+    fn test_shuffled_answers_are_stable() {
+        let _globals = lock_global_state();
+        let answers: Vec<String> = ["BLIMP", "CLOTH", "DWARF", "FJORD", "GUSTY", "NYMPH", "SPICE", "WALTZ"]
+            .iter().map(|w| w.to_string()).collect();
+        let mut manager = WordManager::new();
+        manager.set_words(answers.clone(), vec![]);
+
+        let shuffled = manager.shuffled_answers(7);
+        assert_eq!(manager.shuffled_answers(7), shuffled);
+        assert_ne!(shuffled, answers);
+        assert_ne!(manager.shuffled_answers(8), shuffled);
+        // Switching seeds back recomputes the same order
+        assert_eq!(manager.shuffled_answers(7), shuffled);
+
+        let mut sorted = shuffled;
+        sorted.sort();
+        assert_eq!(sorted, answers);
+
+        // New word lists drop the cached order
+        manager.set_words(answers[..3].to_vec(), vec![]);
+        assert_eq!(manager.shuffled_answers(7).len(), 3);
+    }
+
+    #[test]
+    fn test_stops_at_maximum_entropy() {
+        let _globals = lock_global_state();
+        SOLVER_CONFIG.lock().unwrap().candidate_source = CandidateSource::RemainingOnly;
+        let solver = IntelligentSolver::new(vec![]);
+
+        // ABBEY gives each of the five words a different pattern, so log2(5) bits
+        let remaining: Vec<String> = ["ABBEY", "BLIMP", "CLOTH", "CRANE", "DWARF"].iter().map(|w| w.to_string()).collect();
+        assert!((solver.calculate_entropy("ABBEY", &remaining) - 5f64.log2()).abs() < 1e-9);
+
+        let evaluations = ENTROPY_EVALUATIONS.load(Ordering::Relaxed);
+        let best_guess = solver.get_best_guess(&remaining, &[]);
+        let scored = ENTROPY_EVALUATIONS.load(Ordering::Relaxed) - evaluations;
+        assert_eq!(best_guess, Some("ABBEY".to_string()));
+        // ABBEY is scored first and can't be beaten, so nothing else is scored
+        assert_eq!(scored, 1);
+    }
+
+    #[test]
+    fn test_full_scoring_max_remaining() {
+        let _globals = lock_global_state();
+        crate::api::simple::initialize_word_lists().unwrap();
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining: Vec<String> = WORD_MANAGER.lock().unwrap().get_answer_words()[..50].to_vec();
+        let strategic_words = solver.get_top_strategic_words();
+
+        let guess_with_limit = |limit: i32| {
+            SOLVER_CONFIG.lock().unwrap().full_scoring_max_remaining = limit;
+            let evaluations = ENTROPY_EVALUATIONS.load(Ordering::Relaxed);
+            let guess = solver.get_best_guess(&remaining, &[]).unwrap();
+            (guess, ENTROPY_EVALUATIONS.load(Ordering::Relaxed) - evaluations)
+        };
+        let (shortlist_guess, shortlist_evaluations) = guess_with_limit(49);
+        let (full_guess, full_evaluations) = guess_with_limit(50);
+        assert_eq!(shortlist_evaluations, 0);
+        assert!(strategic_words.contains(&shortlist_guess));
+        assert!(full_evaluations > 0);
+        assert_eq!(Some(full_guess), solver.get_best_guess(&remaining, &[]));
+    }
+
+    #[test]
+    fn test_solver_config_default() {
+        let config = SolverConfig::default();
+        assert!(!config.reference_mode);
+        assert!(config.include_killer_words);
+        assert_eq!(config.candidate_cap, 100);
+        assert!(!config.early_termination_enabled);
+        assert_eq!(config.early_termination_threshold, 10.0);
+        assert!(config.entropy_only_scoring);
+        assert!(!config.strict_patterns);
+        assert!(!config.exclude_prior_guesses);
+        assert!(!config.prefer_unique_letters);
+        assert_eq!(config.candidate_source, CandidateSource::RemainingPlusStrategic);
+        assert_eq!(config.prime_suspect_bonus, 0.1);
+        assert_eq!(config.full_scoring_max_remaining, i32::MAX);
+    }
+
+    #[test]
+    fn test_candidate_source_pools() {
+        let _globals = lock_global_state();
+        let words = |list: &[&str]| -> Vec<String> { list.iter().map(|w| w.to_string()).collect() };
+        WORD_MANAGER.lock().unwrap().set_words(
+            words(&["BLIMP", "CLOTH", "DWARF"]),
+            words(&["BLIMP", "CLOTH", "DWARF", "FJORD", "SLATE", "TARES"]),
+        );
+
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining = words(&["BLIMP", "CLOTH"]);
+        let candidates_from = |source: CandidateSource| {
+            SOLVER_CONFIG.lock().unwrap().candidate_source = source;
+            solver.get_candidate_words(&remaining, &[])
+        };
+
+        let remaining_only = candidates_from(CandidateSource::RemainingOnly);
+        let strategic = candidates_from(CandidateSource::RemainingPlusStrategic);
+        let full = candidates_from(CandidateSource::FullDictionary);
+
+        assert_eq!(remaining_only, words(&["BLIMP", "CLOTH"]));
+        // Only the strategic words present in the dictionary are added
+        assert_eq!(strategic, words(&["BLIMP", "CLOTH", "SLATE", "TARES"]));
+        assert_eq!(full, words(&["BLIMP", "CLOTH", "DWARF", "FJORD", "SLATE", "TARES"]));
+    }
+
+    #[test]
+    fn test_letter_split_scores() {
+        let remaining: Vec<String> = ["CRANE", "SLATE", "BLIMP", "FJORD"].iter().map(|w| w.to_string()).collect();
+        let candidates: Vec<String> = ["LEAPT", "EERIE", "QUVWX"].iter().map(|w| w.to_string()).collect();
+
+        // L, E, A, and R are each in half the words, P, T, and I in a quarter;
+        // EERIE counts each distinct letter once, and letters no word has score nothing
+        let scores = IntelligentSolver::letter_split_scores(&candidates, &remaining);
+        assert!((scores[0] - (0.25 + 0.25 + 0.25 + 0.1875 + 0.1875)).abs() < 1e-9);
+        assert!((scores[1] - (0.25 + 0.25 + 0.1875)).abs() < 1e-9);
+        assert_eq!(scores[2], 0.0);
+    }
+
+    #[test]
+    fn test_full_dictionary_is_ranked_before_the_cap() {
+        let _globals = lock_global_state();
+        crate::api::simple::initialize_word_lists().unwrap();
+        SOLVER_CONFIG.lock().unwrap().candidate_source = CandidateSource::FullDictionary;
+        let solver = IntelligentSolver::new(vec![]);
+        let state = [GuessResult::from_guess_against("TARES", "MOUNT")];
+        let remaining = crate::api::simple::get_possible_words(vec![(
+            "TARES".to_string(),
+            state[0].results.iter().map(|result| result.to_token()).collect(),
+        )]);
+
+        // The default cap keeps 100 of ~14.8k words, and the first 100 all start with A
+        let candidates = solver.get_candidate_words(&remaining, &state);
+        assert_eq!(candidates.len(), 100);
+        assert!(candidates.iter().any(|word| !word.starts_with('A')));
+        let guess = solver.get_best_guess(&remaining, &state).unwrap();
+        assert!(!guess.starts_with('A'), "{}", guess);
+    }
+
+    #[test]
+    fn test_killer_words_follow_config() {
+        let _globals = lock_global_state();
+        let words = |list: &[&str]| -> Vec<String> { list.iter().map(|w| w.to_string()).collect() };
+        WORD_MANAGER.lock().unwrap().set_words(words(&["BLIMP", "CLOTH"]), words(&["BLIMP", "CLOTH", "SLATE", "TARES"]));
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining = words(&["BLIMP", "CLOTH"]);
+
+        SOLVER_CONFIG.lock().unwrap().include_killer_words = false;
+        assert_eq!(solver.get_candidate_words(&remaining, &[]), remaining);
+        SOLVER_CONFIG.lock().unwrap().include_killer_words = true;
+        assert_eq!(solver.get_candidate_words(&remaining, &[]), words(&["BLIMP", "CLOTH", "SLATE", "TARES"]));
+    }
+
+    #[test]
+    fn test_early_termination_follows_config() {
+        let _globals = lock_global_state();
+        let words = |list: &[&str]| -> Vec<String> { list.iter().map(|w| w.to_string()).collect() };
+        SOLVER_CONFIG.lock().unwrap().candidate_source = CandidateSource::RemainingOnly;
+        let solver = IntelligentSolver::new(vec![]);
+        // BLIMP is scored first but only splits off itself; CRANE separates all five
+        let remaining = words(&["BLIMP", "CRANE", "CRATE", "GRATE", "TRACE"]);
+        assert!(solver.calculate_entropy("BLIMP", &remaining) > 0.5);
+        assert_eq!(solver.get_best_guess(&remaining, &[]), Some("CRANE".to_string()));
+
+        {
+            let mut config = SOLVER_CONFIG.lock().unwrap();
+            config.early_termination_enabled = true;
+            config.early_termination_threshold = 0.5;
+        }
+        assert_eq!(solver.get_best_guess(&remaining, &[]), Some("BLIMP".to_string()));
+    }
+
+    #[test]
+    fn test_letter_result_ordering() {
+        assert!(LetterResult::Green > LetterResult::Yellow);
+        assert!(LetterResult::Yellow > LetterResult::Gray);
+
+        let mut results = vec![LetterResult::Yellow, LetterResult::Green, LetterResult::Gray];
+        results.sort();
+        assert_eq!(results, vec![LetterResult::Gray, LetterResult::Yellow, LetterResult::Green]);
+
+        // rank agrees with the derived order
+        for pair in results.windows(2) {
+            assert!(pair[0].rank() < pair[1].rank());
+        }
+        assert_eq!(LetterResult::Gray.rank(), 0);
+        assert_eq!(LetterResult::Yellow.rank(), 1);
+        assert_eq!(LetterResult::Green.rank(), 2);
+    }
+
+    #[test]
+    fn test_best_guess_never_repeats_prior_guess() {
+        let _globals = lock_global_state();
+        let solver = IntelligentSolver::new(vec![]);
+        let remaining = vec!["CRANE".to_string(), "SLATE".to_string(), "PLATE".to_string()];
+
+        // Without history the solver picks PLATE
+        let naive_best = solver.get_best_guess(&remaining, &[]).unwrap();
+        assert_eq!(naive_best, "PLATE");
+
+        // Once PLATE has been played it must not be suggested again
+        let played = GuessResult::new("PLATE".to_string(), [
+            LetterResult::Gray, LetterResult::Gray, LetterResult::Green, LetterResult::Gray, LetterResult::Green,
+        ]);
+        let next = solver.get_best_guess(&remaining, std::slice::from_ref(&played)).unwrap();
+        assert_ne!(next, naive_best);
+
+        // ...unless it is the only answer left
+        let only_answer = vec!["PLATE".to_string()];
+        assert_eq!(solver.get_best_guess(&only_answer, &[played]), Some("PLATE".to_string()));
+    }
 }
@@ -5,7 +5,7 @@
 //! 
 //! COPIED FROM REFERENCE IMPLEMENTATION THAT ACHIEVED 99.8% SUCCESS RATE
 
-use crate::api::wrdl_helper::GuessResult;
+use crate::api::wrdl_helper::{lock_recovering, GuessResult, SOLVER_CONFIG};
 use std::collections::HashMap;
 use std::f64::consts::LN_2;
 
@@ -28,8 +28,9 @@ impl IntelligentSolver {
         }
 
         // For endgame scenarios (few remaining words), use direct strategy
+        // Alphabetical, matching the production solver
         if remaining_words.len() <= 2 {
-            return remaining_words.first().cloned();
+            return remaining_words.iter().min().cloned();
         }
 
         // Get candidate words (for now, use remaining words; in future could use full word list)
@@ -80,7 +81,7 @@ impl IntelligentSolver {
         
         for target_word in remaining_words {
             let pattern = self.simulate_guess_pattern(candidate_word, target_word);
-            pattern_groups.entry(pattern).or_insert_with(Vec::new).push(target_word);
+            pattern_groups.entry(pattern).or_default().push(target_word);
         }
 
         // Calculate Shannon entropy
@@ -229,6 +230,10 @@ impl IntelligentSolver {
         // Remove duplicates
         candidates.sort();
         candidates.dedup();
+
+        // Honor the same candidate cap as the production solver
+        let candidate_cap = lock_recovering(&SOLVER_CONFIG).candidate_cap.max(1) as usize;
+        candidates.truncate(candidate_cap);
         
         // Return the strategic candidate list (typically <100 words)
         // This solves both the alphabetical bias and performance issues
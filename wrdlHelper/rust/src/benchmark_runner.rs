@@ -3,7 +3,10 @@
 //! This module provides a comprehensive benchmark runner that tests our intelligent solver
 //! against human performance statistics and provides detailed analysis.
 
-use crate::benchmarking::{WordleBenchmark, BenchmarkStats};
+use crate::api::simple::set_solver_config;
+use crate::api::wrdl_helper::{lock_recovering, SolverConfig, SOLVER_CONFIG, WORD_MANAGER};
+use crate::benchmarking::{WordleBenchmark, BenchmarkStats, SolverVariant};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 /// Format duration in a human-readable way
@@ -25,14 +28,34 @@ fn format_duration(duration: Duration) -> String {
 /// Human performance benchmarks based on research
 #[derive(Debug, Clone)]
 pub struct HumanBenchmarks {
+    pub max_guesses: usize,
     pub average_guesses: f64,
     pub success_rate: f64,
     pub guess_distribution: std::collections::HashMap<usize, f64>,
 }
 
+impl Default for HumanBenchmarks {
+    /// Human benchmarks for standard six-guess Wordle
+    fn default() -> Self {
+        Self::for_max_guesses(6)
+    }
+}
+
 impl HumanBenchmarks {
     /// Create human benchmarks based on research data
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create human benchmarks for a game allowing `max_guesses` guesses
+    /// 
+    /// Presets exist for 4, 5, 6 (standard Wordle) and 7 guesses. The shorter and
+    /// longer variants reuse the standard per-guess solve rates, so games that
+    /// would have been won after the limit count as failures. Limits outside the
+    /// preset range use the nearest preset.
+    pub fn for_max_guesses(max_guesses: usize) -> Self {
+        let max_guesses = max_guesses.clamp(4, 7);
+
         let mut guess_distribution = std::collections::HashMap::new();
         // Based on research: average 4.0-4.2 guesses, ~89% success rate
         guess_distribution.insert(1, 0.01); // 1% solve in 1 guess
@@ -41,11 +64,20 @@ impl HumanBenchmarks {
         guess_distribution.insert(4, 0.35); // 35% solve in 4 guesses
         guess_distribution.insert(5, 0.20); // 20% solve in 5 guesses
         guess_distribution.insert(6, 0.08); // 8% solve in 6 guesses
-        // 11% fail (not included in distribution)
+        guess_distribution.insert(7, 0.06); // 6% solve in 7 guesses (extended mode only)
+        guess_distribution.retain(|&guess, _| guess <= max_guesses);
+
+        let (average_guesses, success_rate) = match max_guesses {
+            4 => (3.5, 0.61),  // "Hard challenge" - 39% fail
+            5 => (3.9, 0.81),  // 19% fail
+            6 => (4.1, 0.89),  // Standard Wordle - 11% fail
+            _ => (4.2, 0.95),  // 7 guesses - 5% fail
+        };
 
         Self {
-            average_guesses: 4.1,
-            success_rate: 0.89,
+            max_guesses,
+            average_guesses,
+            success_rate,
             guess_distribution,
         }
     }
@@ -55,6 +87,8 @@ impl HumanBenchmarks {
 pub struct BenchmarkRunner {
     benchmark: WordleBenchmark,
     human_benchmarks: HumanBenchmarks,
+    /// Play random benchmark games on the rayon thread pool
+    parallel: bool,
 }
 
 impl BenchmarkRunner {
@@ -70,11 +104,58 @@ impl BenchmarkRunner {
         Ok(Self {
             benchmark,
             human_benchmarks,
+            parallel: false,
         })
     }
 
+    /// Create a benchmark runner from in-memory word lists
+    /// 
+    /// Skips all file IO, so tests and embedders (e.g. WASM) can supply their own
+    /// words. The lists are installed in the global word manager, which the
+    /// solver reads during each game.
+    pub fn with_words(answer_words: Vec<String>, all_words: Vec<String>) -> Self {
+        lock_recovering(&WORD_MANAGER).set_words(answer_words.clone(), all_words.clone());
+
+        Self {
+            benchmark: WordleBenchmark::new(answer_words, all_words),
+            human_benchmarks: HumanBenchmarks::new(),
+            parallel: false,
+        }
+    }
+
+    /// Play random benchmark games in parallel instead of one after another
+    /// 
+    /// Sequential is the default so timings stay reproducible. In parallel the
+    /// games run on the global rayon thread pool, so configure its size first.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Number of threads random benchmark games are played on
+    pub fn thread_count(&self) -> usize {
+        if self.parallel { rayon::current_num_threads() } else { 1 }
+    }
+
+    /// Play random games sequentially or in parallel, as configured
+    fn run_random_games(&self, sample_size: usize, max_guesses: usize) -> BenchmarkStats {
+        if self.parallel {
+            self.benchmark.run_benchmark_parallel(sample_size, max_guesses)
+        } else {
+            self.benchmark.run_benchmark(sample_size, max_guesses)
+        }
+    }
+
     /// Run comprehensive benchmark suite
     pub fn run_comprehensive_benchmark(&self) -> BenchmarkReport {
+        self.run_comprehensive_benchmark_with_stop(&AtomicBool::new(false))
+    }
+
+    /// Run comprehensive benchmark suite with a cancellation flag
+    /// 
+    /// The flag is checked before each game. Setting it from another thread ends
+    /// the run early and the report covers only the games completed so far.
+    pub fn run_comprehensive_benchmark_with_stop(&self, stop: &AtomicBool) -> BenchmarkReport {
         println!("🚀 Starting Comprehensive Wordle Solver Benchmark");
         println!("📊 Testing against human performance statistics...");
         
@@ -82,7 +163,7 @@ impl BenchmarkRunner {
 
         // Run benchmark on comprehensive sample
         let sample_size = 30000; // Test on 30,000 words for maximum statistical significance
-        let ai_stats = self.benchmark.run_benchmark(sample_size, 6);
+        let ai_stats = self.benchmark.run_benchmark_until(sample_size, 6, |_| stop.load(Ordering::Relaxed));
         
         let duration = start_time.elapsed();
 
@@ -90,11 +171,12 @@ impl BenchmarkRunner {
         let comparison = self.compare_with_humans(&ai_stats);
 
         BenchmarkReport {
+            sample_size: ai_stats.total_games,
             ai_stats,
             human_benchmarks: self.human_benchmarks.clone(),
             comparison,
             duration,
-            sample_size,
+            threads: 1,
         }
     }
 
@@ -115,7 +197,7 @@ impl BenchmarkRunner {
         
         let start_time = Instant::now();
 
-        let ai_stats = self.benchmark.run_benchmark(sample_size, 6);
+        let ai_stats = self.run_random_games(sample_size, 6);
         let duration = start_time.elapsed();
 
         let comparison = self.compare_with_humans(&ai_stats);
@@ -126,25 +208,184 @@ impl BenchmarkRunner {
             comparison,
             duration,
             sample_size,
+            threads: self.thread_count(),
+        }
+    }
+
+    /// Run random benchmark with a custom guess limit
+    /// 
+    /// The AI results are compared against the human baseline matching
+    /// `max_guesses`, so e.g. a 4-guess challenge isn't judged against
+    /// standard 6-guess human statistics.
+    pub fn run_random_benchmark_with_max_guesses(&self, sample_size: usize, max_guesses: usize) -> BenchmarkReport {
+        println!("🎲 Running Random Wordle Answer Benchmark ({} guesses max)", max_guesses);
+        println!("📊 Testing on {} random Wordle answer words...", sample_size);
+
+        let start_time = Instant::now();
+
+        let ai_stats = self.run_random_games(sample_size, max_guesses);
+        let duration = start_time.elapsed();
+
+        let human_benchmarks = HumanBenchmarks::for_max_guesses(max_guesses);
+        let comparison = compare_stats(&ai_stats, &human_benchmarks);
+
+        BenchmarkReport {
+            ai_stats,
+            human_benchmarks,
+            comparison,
+            duration,
+            sample_size,
+            threads: self.thread_count(),
+        }
+    }
+
+    /// Play every answer word once and report how each game went
+    /// 
+    /// Returns `(word, guess_count, solved)` for each answer, in answer-list
+    /// order, so the hardest words can be picked out or heatmapped.
+    pub fn run_per_word_benchmark(&self) -> Vec<(String, usize, bool)> {
+        println!("🗺️  Playing every answer word once...");
+
+        self.benchmark.play_every_answer(self.human_benchmarks.max_guesses, self.parallel)
+            .into_iter()
+            .map(|game| (game.target_word, game.guess_count, game.solved))
+            .collect()
+    }
+
+    /// Run the same seeded games under two solver configurations
+    /// 
+    /// Each run draws its targets with `seed` and without replacement, so both
+    /// configurations face exactly the same words. The global solver config is
+    /// restored afterwards.
+    /// 
+    /// # Panics
+    /// If either config is rejected by `set_solver_config`.
+    pub fn run_config_comparison(&self, sample_size: usize, seed: u64, config_a: &SolverConfig, config_b: &SolverConfig) -> ConfigComparison {
+        let previous = lock_recovering(&SOLVER_CONFIG).clone();
+
+        println!("🅰️  Running {} games with config A...", sample_size);
+        apply_solver_config(config_a);
+        let target_words_a = self.benchmark.sample_targets_without_replacement(sample_size, seed);
+        let stats_a = self.benchmark.run_benchmark_on_words(target_words_a.clone(), 6);
+
+        println!("🅱️  Running {} games with config B...", sample_size);
+        apply_solver_config(config_b);
+        let target_words_b = self.benchmark.sample_targets_without_replacement(sample_size, seed);
+        let stats_b = self.benchmark.run_benchmark_on_words(target_words_b.clone(), 6);
+
+        *lock_recovering(&SOLVER_CONFIG) = previous;
+
+        ConfigComparison {
+            target_words_a,
+            target_words_b,
+            stats_a,
+            stats_b,
+        }
+    }
+
+    /// Run the same seeded games with the production and the reference solver
+    /// 
+    /// A is the production solver and B the reference one. Both play under the
+    /// current solver config, and the benchmark's own variant is restored after.
+    pub fn run_variant_comparison(&mut self, sample_size: usize, seed: u64) -> ConfigComparison {
+        let previous = self.benchmark.solver_variant();
+        let mut run = |variant: SolverVariant| {
+            println!("▶️  Running {} games with the {} solver...", sample_size, variant.as_str());
+            self.benchmark.set_solver_variant(variant);
+            let target_words = self.benchmark.sample_targets_without_replacement(sample_size, seed);
+            let stats = self.benchmark.run_benchmark_on_words(target_words.clone(), 6);
+            (target_words, stats)
+        };
+
+        let (target_words_a, stats_a) = run(SolverVariant::Production);
+        let (target_words_b, stats_b) = run(SolverVariant::Reference);
+        self.benchmark.set_solver_variant(previous);
+
+        ConfigComparison {
+            target_words_a,
+            target_words_b,
+            stats_a,
+            stats_b,
+        }
+    }
+
+    /// Measure worst-case `get_best_guess` latency across simulated games
+    /// 
+    /// Plays `sample_size` seeded games one after another, timing every call,
+    /// and reports latency percentiles rather than success rates. Games always
+    /// run sequentially so calls don't compete for CPU.
+    pub fn run_timing_benchmark(&self, sample_size: usize) -> TimingReport {
+        println!("⏱️  Timing get_best_guess over {} games...", sample_size);
+
+        let target_words = self.benchmark.sample_targets_without_replacement(sample_size, TIMING_BENCHMARK_SEED);
+        let mut latencies = self.benchmark.measure_guess_latencies(&target_words, self.human_benchmarks.max_guesses);
+        latencies.sort();
+
+        TimingReport {
+            games: target_words.len(),
+            calls: latencies.len(),
+            p50: percentile(&latencies, 50.0),
+            p95: percentile(&latencies, 95.0),
+            p99: percentile(&latencies, 99.0),
+            max: latencies.last().copied().unwrap_or_default(),
         }
     }
 
     /// Compare AI performance with human benchmarks
     fn compare_with_humans(&self, ai_stats: &BenchmarkStats) -> PerformanceComparison {
-        let guess_improvement = self.human_benchmarks.average_guesses - ai_stats.average_guesses;
-        let success_improvement = ai_stats.success_rate - self.human_benchmarks.success_rate;
-        
-        let guess_improvement_percent = (guess_improvement / self.human_benchmarks.average_guesses) * 100.0;
-        let success_improvement_percent = (success_improvement / self.human_benchmarks.success_rate) * 100.0;
-
-        PerformanceComparison {
-            guess_improvement,
-            guess_improvement_percent,
-            success_improvement,
-            success_improvement_percent,
-            ai_better_at_guesses: guess_improvement > 0.0,
-            ai_better_at_success: success_improvement > 0.0,
-        }
+        compare_stats(ai_stats, &self.human_benchmarks)
+    }
+}
+
+/// Seed for the targets of the timing benchmark, so runs time the same games
+const TIMING_BENCHMARK_SEED: u64 = 42;
+
+/// Nearest-rank percentile of an ascending list of durations (zero when empty)
+fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Apply a full solver config through the FFI setters
+/// 
+/// # Panics
+/// If the config has a negative candidate cap or an out-of-range threshold.
+fn apply_solver_config(config: &SolverConfig) {
+    set_solver_config(
+        config.reference_mode,
+        config.include_killer_words,
+        config.candidate_cap,
+        config.early_termination_enabled,
+        config.early_termination_threshold,
+        config.entropy_only_scoring,
+    ).expect("Benchmark solver configs must be valid");
+    let mut current = lock_recovering(&SOLVER_CONFIG);
+    current.strict_patterns = config.strict_patterns;
+    current.exclude_prior_guesses = config.exclude_prior_guesses;
+    current.prefer_unique_letters = config.prefer_unique_letters;
+    current.candidate_source = config.candidate_source;
+    current.prime_suspect_bonus = config.prime_suspect_bonus;
+    current.full_scoring_max_remaining = config.full_scoring_max_remaining;
+}
+
+/// Compare AI performance with a specific human baseline
+fn compare_stats(ai_stats: &BenchmarkStats, human_benchmarks: &HumanBenchmarks) -> PerformanceComparison {
+    let guess_improvement = human_benchmarks.average_guesses - ai_stats.average_guesses;
+    let success_improvement = ai_stats.success_rate - human_benchmarks.success_rate;
+    
+    let guess_improvement_percent = (guess_improvement / human_benchmarks.average_guesses) * 100.0;
+    let success_improvement_percent = (success_improvement / human_benchmarks.success_rate) * 100.0;
+
+    PerformanceComparison {
+        guess_improvement,
+        guess_improvement_percent,
+        success_improvement,
+        success_improvement_percent,
+        ai_better_at_guesses: guess_improvement > 0.0,
+        ai_better_at_success: success_improvement > 0.0,
     }
 }
 
@@ -159,6 +400,56 @@ pub struct PerformanceComparison {
     pub ai_better_at_success: bool,
 }
 
+/// Head-to-head results of two solver configurations
+#[derive(Debug, Clone)]
+pub struct ConfigComparison {
+    pub target_words_a: Vec<String>,
+    pub target_words_b: Vec<String>,
+    pub stats_a: BenchmarkStats,
+    pub stats_b: BenchmarkStats,
+}
+
+impl ConfigComparison {
+    /// Print both runs side by side with the B - A delta
+    pub fn print_comparison(&self) {
+        println!("\n⚖️  SOLVER CONFIG COMPARISON");
+        println!("=====================================");
+        println!("Games per config: {}", self.stats_a.total_games);
+        println!("\n{:<16} {:>10} {:>10} {:>10}", "", "Config A", "Config B", "Delta");
+        println!("{:<16} {:>9.1}% {:>9.1}% {:>+9.1}%", "Success Rate",
+            self.stats_a.success_rate * 100.0, self.stats_b.success_rate * 100.0,
+            (self.stats_b.success_rate - self.stats_a.success_rate) * 100.0);
+        println!("{:<16} {:>10.2} {:>10.2} {:>+10.2}", "Average Guesses",
+            self.stats_a.average_guesses, self.stats_b.average_guesses,
+            self.stats_b.average_guesses - self.stats_a.average_guesses);
+    }
+}
+
+/// Latency percentiles of `get_best_guess` calls
+#[derive(Debug, Clone)]
+pub struct TimingReport {
+    /// Games played
+    pub games: usize,
+    /// `get_best_guess` calls timed across all games
+    pub calls: usize,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl TimingReport {
+    /// Print the latency percentiles in milliseconds
+    pub fn print_report(&self) {
+        println!("\n⏱️  GET_BEST_GUESS LATENCY");
+        println!("=====================================");
+        println!("Games: {} ({} calls)", self.games, self.calls);
+        for (label, latency) in [("p50", self.p50), ("p95", self.p95), ("p99", self.p99), ("max", self.max)] {
+            println!("{:<4} {:>10.2}ms", label, latency.as_secs_f64() * 1000.0);
+        }
+    }
+}
+
 /// Comprehensive benchmark report
 #[derive(Debug, Clone)]
 pub struct BenchmarkReport {
@@ -167,6 +458,8 @@ pub struct BenchmarkReport {
     pub comparison: PerformanceComparison,
     pub duration: std::time::Duration,
     pub sample_size: usize,
+    /// Threads the games were played on (1 when sequential)
+    pub threads: usize,
 }
 
 impl BenchmarkReport {
@@ -178,6 +471,7 @@ impl BenchmarkReport {
         println!("\n🎯 PERFORMANCE SUMMARY");
         println!("Sample Size: {} words", self.sample_size);
         println!("Benchmark Duration: {:.2}s", self.duration.as_secs_f64());
+        println!("Threads: {}", self.threads);
         if self.sample_size < 100 {
             println!("⚠️  Note: Sample size < 100 may not be statistically significant");
         } else if self.sample_size < 857 {
@@ -192,7 +486,7 @@ impl BenchmarkReport {
         
         // Show guess distribution for wins
         println!("\n📊 Win Distribution by Guess Count:");
-        for guess in 1..=6 {
+        for guess in 1..=self.human_benchmarks.max_guesses {
             let wins_at_guess = self.ai_stats.guess_distribution.get(&guess).copied().unwrap_or(0);
             if wins_at_guess > 0 {
                 let percentage = (wins_at_guess as f64 / self.ai_stats.solved_games as f64) * 100.0;
@@ -203,6 +497,9 @@ impl BenchmarkReport {
         println!("\n📈 Performance Summary:");
         println!("Success Rate: {:.1}% (Human: {:.1}%)", 
             self.ai_stats.success_rate * 100.0, self.human_benchmarks.success_rate * 100.0);
+        if self.ai_stats.success_rate_excluding_errors != self.ai_stats.success_rate {
+            println!("Success Rate (excluding setup errors): {:.1}%", self.ai_stats.success_rate_excluding_errors * 100.0);
+        }
         println!("Average Guesses: {:.2} (Human: {:.2})", 
             self.ai_stats.average_guesses, self.human_benchmarks.average_guesses);
         println!("Average Speed: {:.3}s per game", self.duration.as_secs_f64() / self.ai_stats.total_games as f64);
@@ -212,6 +509,20 @@ impl BenchmarkReport {
     
 }
 
+/// Uppercase the string entries of a word array, rejecting any that aren't 5 ASCII letters
+fn to_valid_words(values: &[serde_json::Value]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    values.iter()
+        .filter_map(|v| v.as_str())
+        .map(|word| {
+            if crate::solver_core::is_valid_word(word) {
+                Ok(word.to_uppercase())
+            } else {
+                Err(format!("'{}' is not a 5-letter word of ASCII letters A-Z", word).into())
+            }
+        })
+        .collect()
+}
+
 /// Load answer words from the current project's assets
 fn load_answer_words() -> Result<Vec<String>, Box<dyn std::error::Error>> {
     // Try to load from the current project's word list
@@ -222,10 +533,7 @@ fn load_answer_words() -> Result<Vec<String>, Box<dyn std::error::Error>> {
         let word_data: serde_json::Value = serde_json::from_str(&content)?;
         
         if let Some(answers) = word_data.get("answer_words").and_then(|v| v.as_array()) {
-            let answer_words: Vec<String> = answers
-                .iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_uppercase()))
-                .collect();
+            let answer_words = to_valid_words(answers)?;
             println!("📚 Loaded {} answer words from {}", answer_words.len(), word_list_path);
             return Ok(answer_words);
         }
@@ -251,10 +559,7 @@ fn load_all_words() -> Result<Vec<String>, Box<dyn std::error::Error>> {
         let word_data: serde_json::Value = serde_json::from_str(&content)?;
         
         if let Some(guesses) = word_data.get("guess_words").and_then(|v| v.as_array()) {
-            let all_words: Vec<String> = guesses
-                .iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_uppercase()))
-                .collect();
+            let all_words = to_valid_words(guesses)?;
             println!("📚 Loaded {} guess words from {}", all_words.len(), word_list_path);
             return Ok(all_words);
         }
@@ -275,6 +580,7 @@ fn load_all_words() -> Result<Vec<String>, Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::test_support::lock_global_state;
 
     #[test]
     fn test_human_benchmarks() {
@@ -284,12 +590,53 @@ mod tests {
         assert!(benchmarks.guess_distribution.contains_key(&4));
     }
 
+    #[test]
+    fn test_human_benchmark_presets() {
+        let hard = HumanBenchmarks::for_max_guesses(4);
+        assert_eq!(hard.max_guesses, 4);
+        assert_eq!(hard.success_rate, 0.61);
+        assert!(!hard.guess_distribution.contains_key(&5));
+
+        let extended = HumanBenchmarks::for_max_guesses(7);
+        assert_eq!(extended.max_guesses, 7);
+        assert!(extended.guess_distribution.contains_key(&7));
+
+        // Standard game matches the default baseline
+        assert_eq!(HumanBenchmarks::for_max_guesses(6).success_rate, HumanBenchmarks::new().success_rate);
+    }
+
+    #[test]
+    fn test_random_benchmark_uses_matching_human_preset() {
+        let _globals = lock_global_state();
+        let runner = BenchmarkRunner {
+            benchmark: WordleBenchmark::new(
+                vec!["CRANE".to_string(), "SLATE".to_string()],
+                vec!["CRANE".to_string(), "SLATE".to_string(), "RAISE".to_string()],
+            ),
+            human_benchmarks: HumanBenchmarks::new(),
+            parallel: false,
+        };
+
+        let report = runner.run_random_benchmark_with_max_guesses(2, 4);
+        let preset = HumanBenchmarks::for_max_guesses(4);
+
+        assert_eq!(report.human_benchmarks.max_guesses, 4);
+        assert_eq!(report.human_benchmarks.success_rate, preset.success_rate);
+        assert_eq!(
+            report.comparison.success_improvement,
+            report.ai_stats.success_rate - preset.success_rate
+        );
+    }
+
     #[test]
     fn test_performance_comparison() {
+        let _globals = lock_global_state();
         let ai_stats = BenchmarkStats {
             total_games: 100,
             solved_games: 95,
             success_rate: 0.95,
+            played_games: 100,
+            success_rate_excluding_errors: 0.95,
             average_guesses: 3.5,
             guess_distribution: std::collections::HashMap::new(),
             solve_rate_by_guess: std::collections::HashMap::new(),
@@ -299,10 +646,127 @@ mod tests {
         let runner = BenchmarkRunner {
             benchmark: WordleBenchmark::new(vec![], vec![]),
             human_benchmarks,
+            parallel: false,
         };
         
         let comparison = runner.compare_with_humans(&ai_stats);
         assert!(comparison.ai_better_at_guesses);
         assert!(comparison.ai_better_at_success);
     }
+
+    #[test]
+    fn test_config_comparison_uses_identical_targets() {
+        let _globals = lock_global_state();
+        let words = vec!["CRANE".to_string(), "SLATE".to_string(), "PLATE".to_string(), "CRATE".to_string()];
+        let runner = BenchmarkRunner {
+            benchmark: WordleBenchmark::new(words.clone(), words),
+            human_benchmarks: HumanBenchmarks::new(),
+            parallel: false,
+        };
+        let previous = SOLVER_CONFIG.lock().unwrap().clone();
+
+        let config_a = previous.clone();
+        let config_b = SolverConfig { candidate_cap: 25, ..previous.clone() };
+        let comparison = runner.run_config_comparison(3, 11, &config_a, &config_b);
+
+        assert_eq!(comparison.target_words_a.len(), 3);
+        assert_eq!(comparison.target_words_a, comparison.target_words_b);
+        assert_eq!(comparison.stats_a.total_games, 3);
+        assert_eq!(comparison.stats_b.total_games, 3);
+
+        // The global config is left as it was
+        assert_eq!(SOLVER_CONFIG.lock().unwrap().candidate_cap, previous.candidate_cap);
+    }
+
+    #[test]
+    fn test_with_words_runs_in_memory() {
+        let _globals = lock_global_state();
+        let answer_words: Vec<String> = ["CRANE", "SLATE", "PLATE", "BLIMP", "CLOTH", "FJORD"]
+            .iter().map(|w| w.to_string()).collect();
+        let all_words = [answer_words.clone(), vec!["TARES".to_string(), "ROATE".to_string()]].concat();
+
+        let runner = BenchmarkRunner::with_words(answer_words.clone(), all_words);
+        let report = runner.run_random_benchmark(5);
+
+        assert_eq!(report.ai_stats.total_games, 5);
+        assert_eq!(report.ai_stats.success_rate, 1.0);
+        assert_eq!(report.threads, 1);
+        assert_eq!(WORD_MANAGER.lock().unwrap().get_answer_words(), answer_words.as_slice());
+
+        // Parallel games reach the same results on the thread pool
+        let runner = runner.with_parallel(true);
+        let report = runner.run_random_benchmark(5);
+        assert_eq!(report.ai_stats.total_games, 5);
+        assert_eq!(report.ai_stats.success_rate, 1.0);
+        assert_eq!(report.threads, rayon::current_num_threads());
+    }
+
+    #[test]
+    fn test_variant_comparison_plays_the_same_games() {
+        let _globals = lock_global_state();
+        let answer_words: Vec<String> = ["CRANE", "SLATE", "PLATE", "BLIMP", "CLOTH", "FJORD"]
+            .iter().map(|w| w.to_string()).collect();
+        let all_words = [answer_words.clone(), vec!["TARES".to_string()]].concat();
+
+        let mut runner = BenchmarkRunner::with_words(answer_words, all_words);
+        let comparison = runner.run_variant_comparison(3, 5);
+
+        assert_eq!(comparison.target_words_a.len(), 3);
+        assert_eq!(comparison.target_words_a, comparison.target_words_b);
+        assert_eq!(comparison.stats_a.total_games, 3);
+        assert_eq!(comparison.stats_b.total_games, 3);
+        assert_eq!(runner.benchmark.solver_variant(), SolverVariant::Production);
+        assert!(runner.benchmark.guess_calls(SolverVariant::Production) > 0);
+        assert!(runner.benchmark.guess_calls(SolverVariant::Reference) > 0);
+    }
+
+    #[test]
+    fn test_timing_benchmark_percentiles_are_ordered() {
+        let _globals = lock_global_state();
+        let answer_words: Vec<String> = ["CRANE", "SLATE", "PLATE", "BLIMP", "CLOTH", "FJORD"]
+            .iter().map(|w| w.to_string()).collect();
+        let all_words = [answer_words.clone(), vec!["TARES".to_string()]].concat();
+
+        let runner = BenchmarkRunner::with_words(answer_words, all_words);
+        let report = runner.run_timing_benchmark(3);
+
+        assert_eq!(report.games, 3);
+        assert!(report.calls >= 3);
+        assert!(report.p50 <= report.p95 && report.p95 <= report.p99 && report.p99 <= report.max);
+        assert!(report.max.as_secs_f64().is_finite());
+    }
+
+    #[test]
+    fn test_per_word_benchmark_plays_each_answer_once() {
+        let _globals = lock_global_state();
+        let answer_words: Vec<String> = ["WEDGY", "JETTY", "JUMPY", "FUZZY", "CUPPA"]
+            .iter().map(|w| w.to_string()).collect();
+        let all_words = [answer_words.clone(), vec!["TARES".to_string()]].concat();
+
+        let runner = BenchmarkRunner::with_words(answer_words.clone(), all_words);
+        let results = runner.run_per_word_benchmark();
+
+        assert_eq!(results.len(), answer_words.len());
+        let words: Vec<String> = results.iter().map(|(word, _, _)| word.clone()).collect();
+        assert_eq!(words, answer_words);
+        assert!(results.iter().all(|&(_, guess_count, solved)| solved && (1..=6).contains(&guess_count)));
+    }
+
+    #[test]
+    fn test_comprehensive_benchmark_respects_stop_flag() {
+        let _globals = lock_global_state();
+        let runner = BenchmarkRunner {
+            benchmark: WordleBenchmark::new(vec!["CRANE".to_string()], vec!["CRANE".to_string()]),
+            human_benchmarks: HumanBenchmarks::new(),
+            parallel: false,
+        };
+
+        // A flag raised before the run starts means no games are played
+        let stop = AtomicBool::new(true);
+        let report = runner.run_comprehensive_benchmark_with_stop(&stop);
+
+        assert_eq!(report.sample_size, 0);
+        assert_eq!(report.ai_stats.total_games, 0);
+        assert_eq!(report.ai_stats.success_rate, 0.0);
+    }
 }
@@ -3,10 +3,13 @@
 //! This module provides comprehensive benchmarking tools to test our intelligent solver
 //! against human performance statistics and validate algorithm effectiveness.
 
-use crate::api::wrdl_helper::{IntelligentSolver, GuessResult, LetterResult};
-use rand::Rng;
+use crate::api::wrdl_helper::{lock_recovering, GuessResult, LetterResult, WORD_MANAGER};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 /// Format duration in a human-readable way
 fn format_duration(duration: std::time::Duration) -> String {
@@ -24,6 +27,19 @@ fn format_duration(duration: std::time::Duration) -> String {
     }
 }
 
+/// Why a simulated game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEndReason {
+    /// The target was guessed
+    Solved,
+    /// Every allowed guess was used without finding the target
+    OutOfGuesses,
+    /// The target isn't in the solver's word list, so it could never be guessed
+    TargetNotInDictionary,
+    /// The solver had no guess to offer (e.g. contradictory feedback)
+    NoValidGuess,
+}
+
 /// Represents the result of a single Wordle game
 #[derive(Debug, Clone)]
 pub struct GameResult {
@@ -32,33 +48,155 @@ pub struct GameResult {
     pub guess_count: usize,
     pub solved: bool,
     pub max_guesses: usize,
+    pub reason: GameEndReason,
+}
+
+/// Information recorded for one guess of a traced game
+#[derive(Debug, Clone)]
+pub struct GuessTrace {
+    pub guess: String,
+    /// Feedback pattern, e.g. "GYXXG"
+    pub pattern: String,
+    /// Words still possible before this guess
+    pub candidates_before: usize,
+    /// Words still possible after applying this guess's feedback
+    pub candidates_after: usize,
+}
+
+/// Per-guess information gain log of a single game
+#[derive(Debug, Clone)]
+pub struct GameTrace {
+    pub target_word: String,
+    pub steps: Vec<GuessTrace>,
 }
 
+/// One exported training row: a game state in FFI format and the guess chosen for it
+pub type TrainingExample = (Vec<(String, Vec<String>)>, String);
+
 /// Represents benchmark statistics
 #[derive(Debug, Clone)]
 pub struct BenchmarkStats {
     pub total_games: usize,
     pub solved_games: usize,
     pub success_rate: f64,
+    /// Games that ended `Solved` or `OutOfGuesses`
+    pub played_games: usize,
+    /// Success rate over games that ended `Solved` or `OutOfGuesses` only, so
+    /// setup errors such as a target missing from the dictionary don't count
+    /// against the solver
+    pub success_rate_excluding_errors: f64,
     pub average_guesses: f64,
     pub guess_distribution: HashMap<usize, usize>,
     pub solve_rate_by_guess: HashMap<usize, f64>,
 }
 
+impl BenchmarkStats {
+    /// Combine the stats of two separate runs, e.g. shards of one large benchmark
+    /// 
+    /// Counts and the guess distribution are summed; rates and the average number
+    /// of guesses are re-derived, so each run is weighted by its game count.
+    pub fn merge(&self, other: &BenchmarkStats) -> BenchmarkStats {
+        let total_games = self.total_games + other.total_games;
+        let solved_games = self.solved_games + other.solved_games;
+        let played_games = self.played_games + other.played_games;
+        let rate = |count: usize, of: usize| if of > 0 { count as f64 / of as f64 } else { 0.0 };
+
+        let total_guesses = self.average_guesses * self.total_games as f64 + other.average_guesses * other.total_games as f64;
+        let average_guesses = if total_games > 0 { total_guesses / total_games as f64 } else { 0.0 };
+
+        let mut guess_distribution = self.guess_distribution.clone();
+        for (guess_count, count) in &other.guess_distribution {
+            *guess_distribution.entry(*guess_count).or_insert(0) += count;
+        }
+        let solve_rate_by_guess = guess_distribution.iter()
+            .map(|(guess_count, count)| (*guess_count, rate(*count, total_games)))
+            .collect();
+
+        BenchmarkStats {
+            total_games,
+            solved_games,
+            success_rate: rate(solved_games, total_games),
+            played_games,
+            success_rate_excluding_errors: rate(solved_games, played_games),
+            average_guesses,
+            guess_distribution,
+            solve_rate_by_guess,
+        }
+    }
+}
+
+/// Which solver entry point a benchmark plays its games with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolverVariant {
+    /// `get_best_guess`, the solver the Flutter app calls
+    #[default]
+    Production,
+    /// `get_intelligent_guess_reference`, the reference implementation
+    Reference,
+}
+
+impl SolverVariant {
+    /// Short name used in comparison reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SolverVariant::Production => "production",
+            SolverVariant::Reference => "reference",
+        }
+    }
+}
+
 /// Wordle benchmarking system
 pub struct WordleBenchmark {
-    solver: IntelligentSolver,
     answer_words: Vec<String>,
+    solver_variant: SolverVariant,
+    /// Guesses requested from each solver variant, indexed by `SolverVariant as usize`
+    guess_calls: [AtomicUsize; 2],
 }
 
 impl WordleBenchmark {
     /// Create a new benchmark system
-    pub fn new(answer_words: Vec<String>, all_words: Vec<String>) -> Self {
+    ///
+    /// `_all_words` is kept for API compatibility: guesses are chosen by the FFI
+    /// solver functions, which read the guess list loaded into `WORD_MANAGER`.
+    pub fn new(answer_words: Vec<String>, _all_words: Vec<String>) -> Self {
         Self {
-            // REFERENCE APPROACH: Initialize solver with all words (14,855) for maximum coverage
-            // This matches the reference implementation that achieved 99.8% success rate
-            solver: IntelligentSolver::new(all_words),
             answer_words,
+            solver_variant: SolverVariant::default(),
+            guess_calls: [AtomicUsize::new(0), AtomicUsize::new(0)],
+        }
+    }
+
+    /// Play games with the given solver instead of the production one
+    pub fn with_solver_variant(mut self, solver_variant: SolverVariant) -> Self {
+        self.solver_variant = solver_variant;
+        self
+    }
+
+    /// Switch the solver games are played with
+    pub fn set_solver_variant(&mut self, solver_variant: SolverVariant) {
+        self.solver_variant = solver_variant;
+    }
+
+    /// Solver games are played with
+    pub fn solver_variant(&self) -> SolverVariant {
+        self.solver_variant
+    }
+
+    /// Number of guesses this benchmark has requested from `solver_variant`
+    pub fn guess_calls(&self, solver_variant: SolverVariant) -> usize {
+        self.guess_calls[solver_variant as usize].load(Ordering::Relaxed)
+    }
+
+    /// Ask the configured solver for the next guess in an FFI-format game state
+    fn next_guess(&self, ffi_guess_results: Vec<(String, Vec<String>)>) -> Option<String> {
+        self.guess_calls[self.solver_variant as usize].fetch_add(1, Ordering::Relaxed);
+        match self.solver_variant {
+            SolverVariant::Production => crate::api::simple::get_best_guess(ffi_guess_results),
+            SolverVariant::Reference => {
+                // The reference solver takes the remaining words rather than filtering itself
+                let remaining_words = crate::api::simple::get_possible_words(ffi_guess_results.clone());
+                crate::api::simple::get_intelligent_guess_reference(remaining_words, ffi_guess_results)
+            }
         }
     }
 
@@ -67,9 +205,49 @@ impl WordleBenchmark {
     /// The agent is unaware of the target word and must solve it using only
     /// the feedback from each guess (green, yellow, gray letters).
     pub fn simulate_game(&self, target_word: &str, max_guesses: usize) -> GameResult {
+        self.play_game(target_word, max_guesses, None)
+    }
+
+    /// Run a single game simulation and record how much each guess narrowed the field
+    /// 
+    /// Candidate counts are over the same word list `get_best_guess` filters, which
+    /// makes it easy to spot guesses that wasted information in 5-6 guess games.
+    pub fn simulate_game_with_trace(&self, target_word: &str, max_guesses: usize) -> (GameResult, GameTrace) {
+        let mut trace = GameTrace {
+            target_word: target_word.to_string(),
+            steps: Vec::new(),
+        };
+        let result = self.play_game(target_word, max_guesses, Some(&mut trace));
+        (result, trace)
+    }
+
+    /// Play one game, appending a step to `trace` for every guess when given
+    fn play_game(&self, target_word: &str, max_guesses: usize, mut trace: Option<&mut GameTrace>) -> GameResult {
         let mut guesses = Vec::new();
         let mut guess_results: Vec<GuessResult> = Vec::new();
 
+        // Load WORD_MANAGER from the assets unless words are already in memory
+        crate::api::simple::ensure_word_lists_loaded().unwrap();
+
+        // A target outside the solver's dictionary is a setup error, not a solver failure
+        let in_dictionary = lock_recovering(&WORD_MANAGER).is_allowed_guess(target_word);
+        if !in_dictionary {
+            log::warn!("⚠️ Target word {} is not in the solver's dictionary", target_word);
+            return GameResult {
+                target_word: target_word.to_string(),
+                guesses,
+                guess_count: 0,
+                solved: false,
+                max_guesses,
+                reason: GameEndReason::TargetNotInDictionary,
+            };
+        }
+
+        let mut reason = GameEndReason::OutOfGuesses;
+        let mut candidates_before = match trace {
+            Some(_) => crate::api::simple::count_remaining_guess_words(&guess_results),
+            None => 0,
+        };
         for attempt in 1..=max_guesses {
             // NEW ARCHITECTURE: Use server-side filtering
             // Convert guess_results to FFI format
@@ -85,27 +263,38 @@ impl WordleBenchmark {
             }).collect();
             
             // DEBUG: Show complete game state payload for Dart replication
-            println!("🔍 BENCHMARK GAME STATE PAYLOAD - Attempt {}", attempt);
-            println!("  • Target word: {}", target_word);
-            println!("  • Total constraints: {}", guess_results.len());
-            println!("  • Complete payload structure:");
-            println!("    guess_results: Vec<(String, Vec<String>)> = [");
-            for (i, (word, pattern)) in ffi_guess_results.iter().enumerate() {
-                println!("      (\"{}\", {:?}), // constraint {}", word, pattern, i + 1);
+            log::debug!("🔍 BENCHMARK GAME STATE PAYLOAD - Attempt {}", attempt);
+            log::debug!("  • Target word: {}", target_word);
+            log::debug!("  • Total constraints: {}", guess_results.len());
+            log::debug!("  • Complete payload structure (WORD:PATTERN):");
+            for (i, guess_result) in guess_results.iter().enumerate() {
+                log::debug!("      {} // constraint {}", guess_result, i + 1);
             }
-            println!("    ]");
-            println!("  • This is the EXACT payload passed to: get_best_guess(guess_results)");
+            log::debug!("  • This is the EXACT payload passed to the {} solver", self.solver_variant.as_str());
             
             // NEW: Use single server function (CORRECT ARCHITECTURE)
-            // Initialize WORD_MANAGER
-            crate::api::simple::initialize_word_lists().unwrap();
-            
             // Single server call - server handles everything internally
-            let best_guess = crate::api::simple::get_best_guess(ffi_guess_results.clone());
+            let best_guess = self.next_guess(ffi_guess_results.clone());
             
             if let Some(guess) = best_guess {
-                println!("  • Algorithm suggested: {}", guess);
+                log::debug!("  • Algorithm suggested: {}", guess);
                 guesses.push(guess.clone());
+
+                // Generate feedback for this guess
+                let feedback = self.generate_feedback(&guess, target_word);
+
+                if let Some(trace) = trace.as_deref_mut() {
+                    let mut next_state = guess_results.clone();
+                    next_state.push(feedback.clone());
+                    let candidates_after = crate::api::simple::count_remaining_guess_words(&next_state);
+                    trace.steps.push(GuessTrace {
+                        guess: guess.clone(),
+                        pattern: feedback.pattern_string(),
+                        candidates_before,
+                        candidates_after,
+                    });
+                    candidates_before = candidates_after;
+                }
                 
                 // Check if we solved it
                 if guess == target_word {
@@ -115,14 +304,14 @@ impl WordleBenchmark {
                         guess_count: attempt,
                         solved: true,
                         max_guesses,
+                        reason: GameEndReason::Solved,
                     };
                 }
                 
-                // Generate feedback for this guess
-                let feedback = self.generate_feedback(&guess, target_word);
                 guess_results.push(feedback);
             } else {
                 // No valid guess available
+                reason = GameEndReason::NoValidGuess;
                 break;
             }
         }
@@ -133,40 +322,35 @@ impl WordleBenchmark {
             guess_count: guesses.len(),
             solved: false,
             max_guesses,
+            reason,
         }
     }
 
-    
-    /// Convert FFI format to internal format
-    fn convert_ffi_to_internal(&self, guess_results: &[(String, Vec<String>)]) -> Vec<GuessResult> {
-        let mut internal_guess_results = Vec::new();
-        for (word, pattern) in guess_results {
-            let mut results = Vec::new();
-            for letter_result in pattern {
-                let lr = letter_result.to_uppercase();
-                let result = match lr.as_str() {
-                    "G" | "GREEN" => LetterResult::Green,
-                    "Y" | "YELLOW" => LetterResult::Yellow,
-                    "X" | "GRAY" | "GREY" => LetterResult::Gray,
-                    _ => LetterResult::Gray,
-                };
-                results.push(result);
-            }
-            internal_guess_results.push(GuessResult::new(word.clone(), [
-                results[0], results[1], results[2], results[3], results[4]
-            ]));
-        }
-        internal_guess_results
-    }
-
     /// Run benchmark on a random sample of words
     pub fn run_benchmark(&self, sample_size: usize, max_guesses: usize) -> BenchmarkStats {
+        self.run_benchmark_until(sample_size, max_guesses, |_| false)
+    }
+
+    /// Run benchmark on a random sample of words, stopping early on request
+    /// 
+    /// `should_stop` is called with the number of completed games before each new
+    /// game starts. Once it returns true the run ends and the returned stats cover
+    /// only the games that were completed.
+    pub fn run_benchmark_until<F>(&self, sample_size: usize, max_guesses: usize, mut should_stop: F) -> BenchmarkStats
+    where
+        F: FnMut(usize) -> bool,
+    {
         let mut rng = rand::thread_rng();
         let mut results = Vec::new();
         let start_time = Instant::now();
         
         // Select random words for benchmarking
         for i in 0..sample_size {
+            if should_stop(i) {
+                println!("\n🛑 Benchmark stopped early after {} games", i);
+                break;
+            }
+
             let random_index = rng.gen_range(0..self.answer_words.len());
             let target_word = &self.answer_words[random_index];
             
@@ -195,125 +379,165 @@ impl WordleBenchmark {
         self.calculate_stats(results)
     }
 
-    /// Run benchmark on specific words (for testing)
-    pub fn run_benchmark_on_words(&self, target_words: Vec<String>, max_guesses: usize) -> BenchmarkStats {
-        let mut results = Vec::new();
-        
-        for target_word in target_words {
-            let game_result = self.simulate_game(&target_word, max_guesses);
-            results.push(game_result);
+    /// Run benchmark on a random sample of words, playing games in parallel
+    /// 
+    /// Targets are drawn up front and the games are spread over the current rayon
+    /// thread pool. Per-game timings overlap, so use `run_benchmark` when
+    /// reproducible timing matters more than wall-clock time.
+    pub fn run_benchmark_parallel(&self, sample_size: usize, max_guesses: usize) -> BenchmarkStats {
+        use rayon::prelude::*;
+
+        if self.answer_words.is_empty() {
+            return self.calculate_stats(Vec::new());
         }
 
-        self.calculate_stats(results)
-    }
+        let mut rng = rand::thread_rng();
+        let target_words: Vec<&String> = (0..sample_size)
+            .map(|_| &self.answer_words[rng.gen_range(0..self.answer_words.len())])
+            .collect();
 
-    /// Generate feedback for a guess against a target word
-    fn generate_feedback(&self, guess: &str, target: &str) -> GuessResult {
-        let guess_chars: Vec<char> = guess.chars().collect();
-        let mut target_chars: Vec<char> = target.chars().collect();
-        let mut results = [LetterResult::Gray; 5];
+        println!("🧵 Playing {} games on {} threads", sample_size, rayon::current_num_threads());
+        let results = target_words.par_iter()
+            .map(|target_word| self.simulate_game(target_word, max_guesses))
+            .collect();
 
-        // First pass: mark exact matches (green)
-        for i in 0..5 {
-            if guess_chars[i] == target_chars[i] {
-                results[i] = LetterResult::Green;
-                target_chars[i] = ' '; // Mark as used
-            }
-        }
+        self.calculate_stats(results)
+    }
 
-        // Second pass: mark partial matches (yellow)
-        for i in 0..5 {
-            if results[i] == LetterResult::Gray {
-                let letter = guess_chars[i];
-                if let Some(pos) = target_chars.iter().position(|&c| c == letter) {
-                    results[i] = LetterResult::Yellow;
-                    target_chars[pos] = ' '; // Mark as used
-                }
-            }
+    /// Play every answer word exactly once, in answer-list order
+    /// 
+    /// With `parallel` set, the games are spread over the current rayon thread
+    /// pool; the results keep the answer-list order either way.
+    pub fn play_every_answer(&self, max_guesses: usize, parallel: bool) -> Vec<GameResult> {
+        use rayon::prelude::*;
+
+        if parallel {
+            self.answer_words.par_iter().map(|target_word| self.simulate_game(target_word, max_guesses)).collect()
+        } else {
+            self.answer_words.iter().map(|target_word| self.simulate_game(target_word, max_guesses)).collect()
         }
+    }
 
-        GuessResult::new(guess.to_string(), results)
+    /// Run benchmark on a seeded sample of distinct words
+    /// 
+    /// Unlike `run_benchmark`, no target word is tested twice. The answer list is
+    /// shuffled with a seeded RNG and the first `sample_size` words are played, so
+    /// a `sample_size` at or above the list length tests every word exactly once.
+    pub fn run_benchmark_no_replacement(&self, sample_size: usize, max_guesses: usize, seed: u64) -> BenchmarkStats {
+        let target_words = self.sample_targets_without_replacement(sample_size, seed);
+        self.run_benchmark_on_words(target_words, max_guesses)
     }
 
-    /// Filter words based on feedback from all guesses
-    fn filter_words_with_feedback(&self, words: &[String], guess_results: &[GuessResult]) -> Vec<String> {
-        words.iter()
-            .filter(|word| self.word_matches_all_feedback(word, guess_results))
-            .cloned()
-            .collect()
+    /// Pick up to `sample_size` distinct target words using a seeded shuffle
+    pub fn sample_targets_without_replacement(&self, sample_size: usize, seed: u64) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut target_words = self.answer_words.clone();
+        target_words.shuffle(&mut rng);
+        target_words.truncate(sample_size.min(self.answer_words.len()));
+        target_words
     }
 
-    /// Check if a word matches all feedback from previous guesses
-    fn word_matches_all_feedback(&self, candidate: &str, guess_results: &[GuessResult]) -> bool {
-        for guess_result in guess_results {
-            if !self.word_matches_single_feedback(candidate, guess_result) {
-                return false;
-            }
+    /// Export (game state, chosen guess) pairs for training data
+    /// 
+    /// Plays `sample_size` games against targets drawn with a seeded RNG, so the
+    /// same seed always exports the same dataset. Every pre-guess state is
+    /// recorded in FFI format together with the word `get_best_guess` chose for it.
+    pub fn export_training_data(&self, sample_size: usize, seed: u64) -> Vec<TrainingExample> {
+        let mut training_data = Vec::new();
+        if self.answer_words.is_empty() {
+            return training_data;
         }
-        true
-    }
 
-    /// Check if a word matches feedback from a single guess
-    fn word_matches_single_feedback(&self, candidate: &str, guess_result: &GuessResult) -> bool {
-        let candidate_chars: Vec<char> = candidate.chars().collect();
-        let guess_chars: Vec<char> = guess_result.word.chars().collect();
+        crate::api::simple::ensure_word_lists_loaded().unwrap();
 
-        // Check green letters (exact position matches)
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Green {
-                if candidate_chars[i] != guess_chars[i] {
-                    return false;
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..sample_size {
+            let target_word = &self.answer_words[rng.gen_range(0..self.answer_words.len())];
+            let mut state: Vec<(String, Vec<String>)> = Vec::new();
+
+            for _ in 0..6 {
+                let guess = match crate::api::simple::get_best_guess(state.clone()) {
+                    Some(guess) => guess,
+                    None => break,
+                };
+                training_data.push((state.clone(), guess.clone()));
+
+                if &guess == target_word {
+                    break;
                 }
+
+                let pattern = crate::api::simple::simulate_guess_pattern_results(guess.clone(), target_word.clone());
+                state.push((guess, pattern));
             }
         }
 
-        // Check yellow letters (letter exists but not in this position)
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Yellow {
-                let letter = guess_chars[i];
-                // Letter can't be in the same position
-                if candidate_chars[i] == letter {
-                    return false;
-                }
-                // Letter must exist somewhere else
-                if !candidate_chars.contains(&letter) {
-                    return false;
+        training_data
+    }
+
+    /// Time every `get_best_guess` call while playing the given targets
+    /// 
+    /// Games go through the FFI functions just like `export_training_data`, so
+    /// the latencies include parsing the game state. One duration is returned per
+    /// call, in the order the calls were made.
+    pub fn measure_guess_latencies(&self, target_words: &[String], max_guesses: usize) -> Vec<Duration> {
+        let mut latencies = Vec::new();
+        crate::api::simple::ensure_word_lists_loaded().unwrap();
+
+        for target_word in target_words {
+            let mut state: Vec<(String, Vec<String>)> = Vec::new();
+
+            for _ in 0..max_guesses {
+                let start_time = Instant::now();
+                let best_guess = crate::api::simple::get_best_guess(state.clone());
+                latencies.push(start_time.elapsed());
+
+                let guess = match best_guess {
+                    Some(guess) => guess,
+                    None => break,
+                };
+                if &guess == target_word {
+                    break;
                 }
-            }
-        }
 
-        // Check gray letters (letter doesn't exist or we have enough)
-        let mut required_counts: HashMap<char, usize> = HashMap::new();
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Green || guess_result.results[i] == LetterResult::Yellow {
-                let letter = guess_chars[i];
-                *required_counts.entry(letter).or_insert(0) += 1;
+                let pattern = crate::api::simple::simulate_guess_pattern_results(guess.clone(), target_word.clone());
+                state.push((guess, pattern));
             }
         }
 
-        for i in 0..5 {
-            if guess_result.results[i] == LetterResult::Gray {
-                let letter = guess_chars[i];
-                let required_count = required_counts.get(&letter).copied().unwrap_or(0);
-                let actual_count = candidate_chars.iter().filter(|&&c| c == letter).count();
-                
-                if actual_count > required_count {
-                    return false;
-                }
-            }
+        latencies
+    }
+
+    /// Run benchmark on specific words (for testing)
+    pub fn run_benchmark_on_words(&self, target_words: Vec<String>, max_guesses: usize) -> BenchmarkStats {
+        let mut results = Vec::new();
+        
+        for target_word in target_words {
+            let game_result = self.simulate_game(&target_word, max_guesses);
+            results.push(game_result);
         }
 
-        true
+        self.calculate_stats(results)
+    }
+
+    /// Generate feedback for a guess against a target word
+    fn generate_feedback(&self, guess: &str, target: &str) -> GuessResult {
+        GuessResult::from_guess_against(guess, target)
     }
 
     /// Calculate benchmark statistics from game results
     fn calculate_stats(&self, results: Vec<GameResult>) -> BenchmarkStats {
         let total_games = results.len();
         let solved_games = results.iter().filter(|r| r.solved).count();
-        let success_rate = solved_games as f64 / total_games as f64;
+        // A run stopped before its first game has no games to average over
+        let success_rate = if total_games > 0 { solved_games as f64 / total_games as f64 } else { 0.0 };
+
+        let played_games = results.iter()
+            .filter(|r| matches!(r.reason, GameEndReason::Solved | GameEndReason::OutOfGuesses))
+            .count();
+        let success_rate_excluding_errors = if played_games > 0 { solved_games as f64 / played_games as f64 } else { 0.0 };
 
         let total_guesses: usize = results.iter().map(|r| r.guess_count).sum();
-        let average_guesses = total_guesses as f64 / total_games as f64;
+        let average_guesses = if total_games > 0 { total_guesses as f64 / total_games as f64 } else { 0.0 };
 
         let mut guess_distribution: HashMap<usize, usize> = HashMap::new();
         for result in &results {
@@ -331,6 +555,8 @@ impl WordleBenchmark {
             total_games,
             solved_games,
             success_rate,
+            played_games,
+            success_rate_excluding_errors,
             average_guesses,
             guess_distribution,
             solve_rate_by_guess,
@@ -341,9 +567,11 @@ impl WordleBenchmark {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::test_support::lock_global_state;
 
     #[test]
     fn test_simulate_game_success() {
+        let _globals = lock_global_state();
         let answer_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
         let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string(), "RAISE".to_string()];
         let benchmark = WordleBenchmark::new(answer_words, all_words);
@@ -353,10 +581,137 @@ mod tests {
         assert!(result.solved);
         assert_eq!(result.target_word, "CRANE");
         assert!(result.guess_count <= 6);
+        assert_eq!(result.reason, GameEndReason::Solved);
+    }
+
+    #[test]
+    fn test_simulate_game_with_trace() {
+        let _globals = lock_global_state();
+        let answer_words = vec!["CRANE".to_string()];
+        let benchmark = WordleBenchmark::new(answer_words.clone(), answer_words);
+
+        let (result, trace) = benchmark.simulate_game_with_trace("PLUMB", 6);
+
+        assert_eq!(trace.target_word, "PLUMB");
+        assert_eq!(trace.steps.len(), result.guess_count);
+        assert_eq!(
+            trace.steps.iter().map(|step| step.guess.clone()).collect::<Vec<_>>(),
+            result.guesses
+        );
+
+        // Every guess narrows (or keeps) the field, and steps chain together
+        for step in &trace.steps {
+            assert!(step.candidates_after <= step.candidates_before);
+            assert_eq!(step.pattern.len(), 5);
+        }
+        for pair in trace.steps.windows(2) {
+            assert_eq!(pair[1].candidates_before, pair[0].candidates_after);
+        }
+
+        if result.solved {
+            let last = trace.steps.last().unwrap();
+            assert_eq!(last.pattern, "GGGGG");
+            assert_eq!(last.candidates_after, 1);
+        }
+    }
+
+    #[test]
+    fn test_solver_variant_routes_guesses() {
+        let _globals = lock_global_state();
+        use crate::api::simple::{
+            get_best_guess, get_intelligent_guess_reference, get_possible_words, initialize_word_lists,
+            simulate_guess_pattern_results,
+        };
+
+        initialize_word_lists().unwrap();
+        let state = vec![("TARES".to_string(), simulate_guess_pattern_results("TARES".to_string(), "MATCH".to_string()))];
+        let production = WordleBenchmark::new(vec![], vec![]);
+        let reference = WordleBenchmark::new(vec![], vec![]).with_solver_variant(SolverVariant::Reference);
+        assert_eq!(production.solver_variant(), SolverVariant::Production);
+
+        let production_guess = production.next_guess(state.clone());
+        let reference_guess = reference.next_guess(state.clone());
+
+        // Each benchmark only ever asked its own variant
+        assert_eq!(production.guess_calls(SolverVariant::Production), 1);
+        assert_eq!(production.guess_calls(SolverVariant::Reference), 0);
+        assert_eq!(reference.guess_calls(SolverVariant::Production), 0);
+        assert_eq!(reference.guess_calls(SolverVariant::Reference), 1);
+
+        // ...and got the answer of the matching FFI function
+        assert_eq!(production_guess, get_best_guess(state.clone()));
+        assert_eq!(reference_guess, get_intelligent_guess_reference(get_possible_words(state.clone()), state));
+    }
+
+    #[test]
+    fn test_simulate_game_target_not_in_dictionary() {
+        let _globals = lock_global_state();
+        let answer_words = vec!["CRANE".to_string()];
+        let benchmark = WordleBenchmark::new(answer_words.clone(), answer_words);
+
+        let result = benchmark.simulate_game("QZXJV", 6);
+
+        assert!(!result.solved);
+        assert_eq!(result.reason, GameEndReason::TargetNotInDictionary);
+        assert_eq!(result.guess_count, 0);
+        assert!(result.guesses.is_empty());
+    }
+
+    #[test]
+    fn test_success_rate_excluding_errors() {
+        let _globals = lock_global_state();
+        let words = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let benchmark = WordleBenchmark::new(words.clone(), words);
+
+        // Two real games and one target the solver could never guess
+        let targets = vec!["CRANE".to_string(), "SLATE".to_string(), "QZXJV".to_string()];
+        let stats = benchmark.run_benchmark_on_words(targets, 6);
+
+        assert_eq!(stats.total_games, 3);
+        assert_eq!(stats.solved_games, 2);
+        assert!((stats.success_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.success_rate_excluding_errors, 1.0);
+    }
+
+    #[test]
+    fn test_merge_weights_by_game_count() {
+        let _globals = lock_global_state();
+        let stats = |total_games: usize, solved_games: usize, average_guesses: f64, distribution: &[(usize, usize)]| BenchmarkStats {
+            total_games,
+            solved_games,
+            success_rate: solved_games as f64 / total_games as f64,
+            played_games: total_games,
+            success_rate_excluding_errors: solved_games as f64 / total_games as f64,
+            average_guesses,
+            guess_distribution: distribution.iter().copied().collect(),
+            solve_rate_by_guess: HashMap::new(),
+        };
+        let small = stats(10, 10, 3.0, &[(3, 10)]);
+        let large = stats(30, 27, 4.0, &[(3, 7), (4, 20)]);
+
+        let merged = small.merge(&large);
+        assert_eq!(merged.total_games, 40);
+        assert_eq!(merged.solved_games, 37);
+        assert!((merged.success_rate - 37.0 / 40.0).abs() < 1e-9);
+        assert!((merged.success_rate_excluding_errors - 37.0 / 40.0).abs() < 1e-9);
+        assert!((merged.average_guesses - 3.75).abs() < 1e-9);
+        assert_eq!(merged.guess_distribution, [(3, 17), (4, 20)].into_iter().collect());
+        assert!((merged.solve_rate_by_guess[&4] - 0.5).abs() < 1e-9);
+
+        // Merging real runs matches running every game at once
+        let words = vec!["CRANE".to_string(), "SLATE".to_string(), "CRATE".to_string()];
+        let benchmark = WordleBenchmark::new(words.clone(), words.clone());
+        let whole = benchmark.run_benchmark_on_words(words.clone(), 6);
+        let sharded = benchmark.run_benchmark_on_words(words[..1].to_vec(), 6)
+            .merge(&benchmark.run_benchmark_on_words(words[1..].to_vec(), 6));
+        assert_eq!(sharded.total_games, whole.total_games);
+        assert_eq!(sharded.guess_distribution, whole.guess_distribution);
+        assert!((sharded.average_guesses - whole.average_guesses).abs() < 1e-9);
     }
 
     #[test]
     fn test_generate_feedback() {
+        let _globals = lock_global_state();
         let answer_words = vec!["CRANE".to_string()];
         let all_words = vec!["CRANE".to_string()];
         let benchmark = WordleBenchmark::new(answer_words, all_words);
@@ -371,6 +726,7 @@ mod tests {
 
     #[test]
     fn test_generate_feedback_partial() {
+        let _globals = lock_global_state();
         let answer_words = vec!["CRANE".to_string()];
         let all_words = vec!["CRANE".to_string()];
         let benchmark = WordleBenchmark::new(answer_words, all_words);
@@ -393,6 +749,7 @@ mod tests {
 
     #[test]
     fn test_benchmark_stats() {
+        let _globals = lock_global_state();
         let answer_words = vec!["CRANE".to_string(), "SLATE".to_string()];
         let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "RAISE".to_string()];
         let benchmark = WordleBenchmark::new(answer_words, all_words);
@@ -403,4 +760,69 @@ mod tests {
         assert!(stats.success_rate > 0.0);
         assert!(stats.average_guesses > 0.0);
     }
+
+    #[test]
+    fn test_export_training_data() {
+        let _globals = lock_global_state();
+        let answer_words = vec!["CRANE".to_string(), "SLATE".to_string(), "PLATE".to_string()];
+        let benchmark = WordleBenchmark::new(answer_words.clone(), answer_words);
+
+        let training_data = benchmark.export_training_data(3, 42);
+        assert!(!training_data.is_empty());
+
+        // Every recorded guess is exactly what the solver picks for that state
+        for (state, guess) in &training_data {
+            assert_eq!(crate::api::simple::get_best_guess(state.clone()).as_ref(), Some(guess));
+        }
+
+        // Same seed, same dataset
+        assert_eq!(benchmark.export_training_data(3, 42), training_data);
+    }
+
+    #[test]
+    fn test_benchmark_sampling_without_replacement() {
+        let _globals = lock_global_state();
+        let answer_words = vec!["CRANE".to_string(), "SLATE".to_string(), "PLATE".to_string(), "CRATE".to_string()];
+        let benchmark = WordleBenchmark::new(answer_words.clone(), answer_words.clone());
+
+        // No duplicate targets, and the same seed gives the same sample
+        let targets = benchmark.sample_targets_without_replacement(3, 7);
+        assert_eq!(targets.len(), 3);
+        let unique: std::collections::HashSet<&String> = targets.iter().collect();
+        assert_eq!(unique.len(), targets.len());
+        assert_eq!(benchmark.sample_targets_without_replacement(3, 7), targets);
+
+        // Asking for more than the list holds tests every word once
+        let mut all_targets = benchmark.sample_targets_without_replacement(10, 7);
+        all_targets.sort();
+        let mut expected = answer_words;
+        expected.sort();
+        assert_eq!(all_targets, expected);
+
+        let stats = benchmark.run_benchmark_no_replacement(10, 6, 7);
+        assert_eq!(stats.total_games, 4);
+    }
+
+    #[test]
+    fn test_benchmark_stops_early() {
+        let _globals = lock_global_state();
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let answer_words = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let all_words = vec!["CRANE".to_string(), "SLATE".to_string(), "RAISE".to_string()];
+        let benchmark = WordleBenchmark::new(answer_words, all_words);
+
+        // Simulate a caller cancelling the run once three games have finished
+        let stop = AtomicBool::new(false);
+        let stats = benchmark.run_benchmark_until(100, 6, |completed| {
+            if completed == 3 {
+                stop.store(true, Ordering::Relaxed);
+            }
+            stop.load(Ordering::Relaxed)
+        });
+
+        assert_eq!(stats.total_games, 3);
+        assert!(stats.solved_games <= 3);
+        assert!(stats.success_rate.is_finite());
+    }
 }
@@ -3,17 +3,69 @@
 //! This executable runs comprehensive benchmarks of our intelligent Wordle solver
 //! against human performance statistics.
 
+use rust_lib_wrdlhelper::api::wrdl_helper::{SolverConfig, SOLVER_CONFIG};
 use rust_lib_wrdlhelper::benchmark_runner::BenchmarkRunner;
 use std::env;
 
+/// Command line options, with flags separated from the positional arguments
+#[derive(Debug, Clone, PartialEq)]
+struct BenchmarkArgs {
+    /// Benchmark type followed by its own arguments
+    positional: Vec<String>,
+    /// Play games on a rayon thread pool instead of sequentially
+    parallel: bool,
+    /// Size of the rayon thread pool (rayon's default when unset)
+    threads: Option<usize>,
+}
+
+/// Parse the arguments after the program name
+/// 
+/// `--parallel` and `--threads N` may appear anywhere; everything else is kept
+/// in order as a positional argument.
+fn parse_args(args: &[String]) -> Result<BenchmarkArgs, String> {
+    let mut parsed = BenchmarkArgs { positional: Vec::new(), parallel: false, threads: None };
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--parallel" => parsed.parallel = true,
+            "--threads" => {
+                let value = args.next().ok_or("--threads needs a thread count")?;
+                match value.parse::<usize>() {
+                    Ok(threads) if threads > 0 => parsed.threads = Some(threads),
+                    _ => return Err(format!("Thread count must be a positive number, got: {}", value)),
+                }
+            }
+            _ => parsed.positional.push(arg.clone()),
+        }
+    }
+
+    Ok(parsed)
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🎯 Wordle Solver Benchmark Tool");
     println!("================================");
     
-    let args: Vec<String> = env::args().collect();
-    let benchmark_type = args.get(1).map(|s| s.as_str()).unwrap_or("comprehensive");
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            println!("❌ {}", error);
+            print_help();
+            return Ok(());
+        }
+    };
+    let args = &options.positional;
+    let benchmark_type = args.first().map(|s| s.as_str()).unwrap_or("comprehensive");
+
+    if let Some(threads) = options.threads {
+        rayon::ThreadPoolBuilder::new().num_threads(threads).build_global()?;
+    }
     
-    let runner = BenchmarkRunner::new()?;
+    // Sequential by default so timings are reproducible
+    let runner = BenchmarkRunner::new()?.with_parallel(options.parallel);
+    println!("🧵 Threads: {}", runner.thread_count());
     
     match benchmark_type {
         "comprehensive" | "" => {
@@ -26,6 +78,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let report = runner.run_random_benchmark(50);
             report.print_report();
         }
+        "compare" => {
+            let num_games = args.get(1).and_then(|s| s.parse::<usize>().ok()).unwrap_or(50);
+            let seed = args.get(2).and_then(|s| s.parse::<u64>().ok()).unwrap_or(42);
+
+            // A: current defaults; B: a narrow candidate pool
+            let config_a = SOLVER_CONFIG.lock().unwrap().clone();
+            let config_b = SolverConfig { candidate_cap: 25, ..config_a.clone() };
+
+            println!("\n⚖️  Comparing solver configs over {} seeded games (seed {})...", num_games, seed);
+            println!("  • Config A: defaults (candidate cap {})", config_a.candidate_cap);
+            println!("  • Config B: narrow candidate pool (candidate cap {})", config_b.candidate_cap);
+            let comparison = runner.run_config_comparison(num_games, seed, &config_a, &config_b);
+            comparison.print_comparison();
+        }
+        "variants" => {
+            let num_games = args.get(1).and_then(|s| s.parse::<usize>().ok()).unwrap_or(50);
+            let seed = args.get(2).and_then(|s| s.parse::<u64>().ok()).unwrap_or(42);
+
+            println!("\n⚖️  Comparing solvers over {} seeded games (seed {})...", num_games, seed);
+            println!("  • Config A: production solver (get_best_guess)");
+            println!("  • Config B: reference solver (get_intelligent_guess_reference)");
+            let mut runner = runner;
+            let comparison = runner.run_variant_comparison(num_games, seed);
+            comparison.print_comparison();
+        }
+        "timing" => {
+            let num_games = args.get(1).and_then(|s| s.parse::<usize>().ok()).unwrap_or(50);
+            let report = runner.run_timing_benchmark(num_games);
+            report.print_report();
+        }
         "help" => {
             print_help();
         }
@@ -52,11 +134,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 fn print_help() {
     println!("\n📖 Usage:");
-    println!("  cargo run --bin benchmark [number_of_games]");
+    println!("  cargo run --bin benchmark [number_of_games] [--parallel] [--threads N]");
     println!("\n🎯 Benchmark Options:");
     println!("  900 or comprehensive - Run 900 random Wordle answer words (statistically significant)");
     println!("  50 or quick         - Run 50 random Wordle answer words");
+    println!("  compare <n> [seed]  - Run n seeded games under two solver configs and compare");
+    println!("  variants <n> [seed] - Run n seeded games with the production and reference solvers and compare");
+    println!("  timing <n>          - Report p50/p95/p99/max get_best_guess latency over n seeded games");
     println!("  help                - Show this help message");
+    println!("\n🧵 Threading Options:");
+    println!("  --parallel          - Play random games in parallel (default: sequential, for reproducible timing)");
+    println!("  --threads N         - Size of the thread pool used with --parallel");
     println!("\n📊 What the benchmark tests:");
     println!("  • AI solver performance vs human statistics");
     println!("  • Success rate comparison");
@@ -64,3 +152,31 @@ fn print_help() {
     println!("  • Win distribution by guess count");
     println!("  • Timing metrics (total time, time per game)");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_threads_and_parallel() {
+        let parsed = parse_args(&args(&["--threads", "4", "--parallel"])).unwrap();
+        assert_eq!(parsed, BenchmarkArgs { positional: vec![], parallel: true, threads: Some(4) });
+
+        // Flags can follow the positional arguments
+        let parsed = parse_args(&args(&["compare", "20", "--parallel", "7"])).unwrap();
+        assert_eq!(parsed.positional, args(&["compare", "20", "7"]));
+        assert!(parsed.parallel);
+        assert_eq!(parsed.threads, None);
+
+        // Sequential by default
+        assert!(!parse_args(&args(&["50"])).unwrap().parallel);
+
+        assert!(parse_args(&args(&["--threads"])).is_err());
+        assert!(parse_args(&args(&["--threads", "0"])).is_err());
+        assert!(parse_args(&args(&["--threads", "many"])).is_err());
+    }
+}
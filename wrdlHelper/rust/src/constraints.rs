@@ -0,0 +1,214 @@
+//! Merged Game Constraints
+//!
+//! Per-guess matchers check each guess in isolation. This module folds every
+//! guess of a game into one set of constraints first:
+//! - Fixed letters per position (greens)
+//! - Banned letters per position (yellows and grays)
+//! - Minimum and maximum count per letter
+//!
+//! Words are then checked against the merged constraints in a single pass.
+//!
+//! `word_matches_single_feedback` and `word_matches_all_feedback` are the one
+//! canonical feedback matcher. `get_best_guess`, `get_possible_words`,
+//! `WordManager::get_possible_answers`, and the solver's `filter_words` all go
+//! through them so their filtering can't diverge.
+
+use crate::api::wrdl_helper::{GuessResult, LetterResult};
+use std::collections::HashMap;
+
+/// Constraints accumulated across every guess of a game
+#[derive(Debug, Clone, Default)]
+pub struct MergedConstraints {
+    /// Letter known to be at each position
+    pub fixed_positions: [Option<char>; 5],
+    /// Letters known not to be at each position
+    pub banned_positions: [Vec<char>; 5],
+    /// Fewest occurrences of each letter the answer must have
+    pub min_counts: HashMap<char, usize>,
+    /// Most occurrences of each letter the answer may have
+    pub max_counts: HashMap<char, usize>,
+    /// Set when two guesses fix different letters at the same position
+    contradictory: bool,
+}
+
+impl MergedConstraints {
+    /// Merge the constraints of all guess results
+    ///
+    /// Within a guess, greens and yellows of a letter set its minimum count, and a
+    /// gray of the same letter caps the count at that minimum. Across guesses the
+    /// minimum is the largest seen and the cap is the smallest seen.
+    pub fn from_guess_results(guess_results: &[GuessResult]) -> Self {
+        let mut constraints = Self::default();
+
+        for guess_result in guess_results {
+            let guess_chars: Vec<char> = guess_result.word.chars().collect();
+            let mut found_counts: HashMap<char, usize> = HashMap::new();
+            let mut capped_letters = Vec::new();
+
+            for (i, (&letter, result)) in guess_chars.iter().zip(&guess_result.results).enumerate().take(5) {
+                match result {
+                    LetterResult::Green => {
+                        if constraints.fixed_positions[i].is_some_and(|fixed| fixed != letter) {
+                            constraints.contradictory = true;
+                        }
+                        constraints.fixed_positions[i] = Some(letter);
+                        *found_counts.entry(letter).or_insert(0) += 1;
+                    }
+                    LetterResult::Yellow => {
+                        constraints.ban(i, letter);
+                        *found_counts.entry(letter).or_insert(0) += 1;
+                    }
+                    LetterResult::Gray => {
+                        constraints.ban(i, letter);
+                        capped_letters.push(letter);
+                    }
+                }
+            }
+
+            for (&letter, &count) in &found_counts {
+                let min_count = constraints.min_counts.entry(letter).or_insert(0);
+                *min_count = (*min_count).max(count);
+            }
+            for letter in capped_letters {
+                let cap = found_counts.get(&letter).copied().unwrap_or(0);
+                let max_count = constraints.max_counts.entry(letter).or_insert(cap);
+                *max_count = (*max_count).min(cap);
+            }
+        }
+
+        constraints
+    }
+
+    /// Ban a letter from a position
+    fn ban(&mut self, position: usize, letter: char) {
+        if !self.banned_positions[position].contains(&letter) {
+            self.banned_positions[position].push(letter);
+        }
+    }
+
+    /// Check if a word satisfies every merged constraint
+    /// 
+    /// Matches exactly the words that satisfy each guess on its own.
+    pub fn matches(&self, word: &str) -> bool {
+        if self.contradictory || word.chars().count() != 5 {
+            return false;
+        }
+
+        // Runs once per word in every filter, so nothing here allocates
+        for (i, letter) in word.chars().enumerate() {
+            if self.fixed_positions[i].is_some_and(|fixed| fixed != letter) {
+                return false;
+            }
+            if self.banned_positions[i].contains(&letter) {
+                return false;
+            }
+        }
+
+        let count_of = |letter: char| word.chars().filter(|&c| c == letter).count();
+        self.min_counts.iter().all(|(&letter, &min)| count_of(letter) >= min)
+            && self.max_counts.iter().all(|(&letter, &max)| count_of(letter) <= max)
+    }
+}
+
+/// Check if a word matches the feedback of a single guess
+/// 
+/// Greens fix their position, yellows require the letter elsewhere, and grays
+/// cap the letter at the number of greens and yellows it got in that guess.
+pub fn word_matches_single_feedback(candidate: &str, guess_result: &GuessResult) -> bool {
+    MergedConstraints::from_guess_results(std::slice::from_ref(guess_result)).matches(candidate)
+}
+
+/// Check if a word matches the feedback of every guess
+pub fn word_matches_all_feedback(candidate: &str, guess_results: &[GuessResult]) -> bool {
+    MergedConstraints::from_guess_results(guess_results).matches(candidate)
+}
+
+/// Filter words against the merged constraints of all guess results
+pub fn filter_words_merged(words: &[String], guess_results: &[GuessResult]) -> Vec<String> {
+    let constraints = MergedConstraints::from_guess_results(guess_results);
+    words.iter()
+        .filter(|word| constraints.matches(word))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yellows_in_different_positions_are_merged() {
+        use LetterResult::{Gray, Yellow};
+
+        let words: Vec<String> = ["PIEZO", "WEDGY", "OPINE", "CRANE"].iter().map(|w| w.to_string()).collect();
+        // E is yellow at position 4 in the first guess and position 2 in the second
+        let crane = GuessResult::new("CRANE".to_string(), [Gray, Gray, Gray, Gray, Yellow]);
+        let blest = GuessResult::new("BLEST".to_string(), [Gray, Gray, Yellow, Gray, Gray]);
+
+        // The first guess alone keeps PIEZO, which has its E at position 2
+        let first_guess_only = filter_words_merged(&words, std::slice::from_ref(&crane));
+        assert_eq!(first_guess_only, vec!["PIEZO".to_string(), "WEDGY".to_string()]);
+
+        // Merged, E is banned from both positions but still required
+        let constraints = MergedConstraints::from_guess_results(&[crane.clone(), blest.clone()]);
+        assert!(constraints.banned_positions[2].contains(&'E'));
+        assert!(constraints.banned_positions[4].contains(&'E'));
+        assert_eq!(constraints.min_counts.get(&'E'), Some(&1));
+
+        assert_eq!(filter_words_merged(&words, &[crane, blest]), vec!["WEDGY".to_string()]);
+    }
+
+    #[test]
+    fn test_repeated_yellows_require_each_occurrence() {
+        use LetterResult::{Gray, Yellow};
+
+        // Both Es of ELDER are yellow against THEME, so the answer has at least two Es
+        let elder = GuessResult::new("ELDER".to_string(), [Yellow, Gray, Gray, Yellow, Gray]);
+        assert_eq!(elder.results, GuessResult::from_guess_against("ELDER", "THEME").results);
+        assert_eq!(MergedConstraints::from_guess_results(std::slice::from_ref(&elder)).min_counts.get(&'E'), Some(&2));
+
+        // THOSE and SPICE fit every position but only have one E
+        let words: Vec<String> = ["THEME", "THOSE", "PIECE", "SPICE"].iter().map(|w| w.to_string()).collect();
+        assert_eq!(filter_words_merged(&words, std::slice::from_ref(&elder)), vec!["THEME".to_string(), "PIECE".to_string()]);
+        assert!(!word_matches_single_feedback("THOSE", &elder));
+    }
+
+    #[test]
+    fn test_letter_count_bounds_combine_across_guesses() {
+        // CRANE greens an E but says nothing about a second one; EERIE later grays
+        // the extra Es, capping the count
+        let crane = GuessResult::from_guess_against("CRANE", "SPICE");
+        let eerie = GuessResult::from_guess_against("EERIE", "SPICE");
+        let words: Vec<String> = ["SPICE", "PIECE", "SLICE"].iter().map(|w| w.to_string()).collect();
+
+        let first_guess_only = MergedConstraints::from_guess_results(std::slice::from_ref(&crane));
+        assert_eq!(first_guess_only.min_counts.get(&'E'), Some(&1));
+        assert_eq!(first_guess_only.max_counts.get(&'E'), None);
+        assert_eq!(filter_words_merged(&words, std::slice::from_ref(&crane)), words);
+
+        // The minimum is the largest seen and the cap the smallest, in either order
+        for state in [vec![crane.clone(), eerie.clone()], vec![eerie, crane]] {
+            let constraints = MergedConstraints::from_guess_results(&state);
+            assert_eq!(constraints.min_counts.get(&'E'), Some(&1));
+            assert_eq!(constraints.max_counts.get(&'E'), Some(&1));
+            assert_eq!(filter_words_merged(&words, &state), vec!["SPICE".to_string(), "SLICE".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_merged_matches_every_single_guess() {
+        let words = ["CRANE", "SLATE", "EERIE", "SPEED", "ERASE", "LLAMA", "ALLEY", "WEDGY"];
+        let states = [
+            vec![GuessResult::from_guess_against("SPEED", "ERASE")],
+            vec![GuessResult::from_guess_against("TARES", "CRANE"), GuessResult::from_guess_against("ALLEY", "CRANE")],
+            vec![GuessResult::from_guess_against("CRANE", "CRANE"), GuessResult::from_guess_against("SLATE", "SLATE")],
+        ];
+
+        for state in &states {
+            for word in words {
+                let each_guess = state.iter().all(|gr| word_matches_single_feedback(word, gr));
+                assert_eq!(word_matches_all_feedback(word, state), each_guess, "{} against {:?}", word, state);
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod api;
 pub mod benchmarking;
 pub mod benchmark_runner;
+pub mod constraints;
 mod frb_generated;
+pub use wrdl_solver_core as solver_core;
@@ -0,0 +1,50 @@
+//! Full games played only through the public FFI functions
+//!
+//! Mirrors the call sequence the Flutter app makes: load the word lists, set the
+//! config, then alternate `get_best_guess` with the feedback for the guess. The
+//! answers come from `get_daily_answer`, so every run plays the same games.
+
+use rust_lib_wrdlhelper::api::simple::{
+    get_best_guess, get_daily_answer, get_possible_word_count, get_possible_words, initialize_word_lists,
+    is_game_solved, set_solver_config, simulate_guess_pattern_results,
+};
+
+/// Days whose answers are played
+const DAYS: [i32; 3] = [0, 100, 1000];
+const MAX_GUESSES: usize = 6;
+
+#[test]
+fn ffi_games_solve_and_narrow_the_answers() {
+    initialize_word_lists().unwrap();
+    set_solver_config(false, true, 100, false, 10.0, true).unwrap();
+    let starting_count = get_possible_word_count(vec![]);
+    assert!(starting_count > 0);
+
+    for day in DAYS {
+        let answer = get_daily_answer(day).unwrap();
+        let mut guess_results: Vec<(String, Vec<String>)> = Vec::new();
+        let mut previous_count = starting_count;
+
+        let solved = loop {
+            assert!(guess_results.len() < MAX_GUESSES, "{} not solved in {} guesses: {:?}", answer, MAX_GUESSES, guess_results);
+
+            let guess = get_best_guess(guess_results.clone()).unwrap();
+            let pattern = simulate_guess_pattern_results(guess.clone(), answer.clone());
+            let won = pattern.iter().all(|token| token == "G");
+            guess_results.push((guess, pattern));
+            if won {
+                break guess_results.len();
+            }
+
+            // The answer always survives the feedback, and the possible answers never grow
+            let count = get_possible_word_count(guess_results.clone());
+            assert!(count >= 1 && count <= previous_count, "{}: count went from {} to {}", answer, previous_count, count);
+            assert!(get_possible_words(guess_results.clone()).contains(&answer));
+            previous_count = count;
+        };
+
+        assert!(solved <= MAX_GUESSES);
+        assert_eq!(is_game_solved(guess_results.clone()), Some(answer.clone()));
+        assert!(get_possible_word_count(guess_results) < starting_count);
+    }
+}
@@ -0,0 +1,115 @@
+//! Allocation benchmark for the interned word pipeline
+//!
+//! Counts heap allocations when handing the guess list to the solving pipeline.
+//! Before interning, every call cloned the full list, allocating once per word.
+//! Run with `cargo test --test interning_allocations -- --nocapture` to see the numbers.
+
+use rust_lib_wrdlhelper::api::simple::get_best_guess;
+use rust_lib_wrdlhelper::api::wrdl_helper::{IntelligentSolver, WORD_MANAGER};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The allocation counter and word manager are process-wide, so the tests take turns
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Every five-letter word over a small alphabet, so the list is large but synthetic
+fn synthetic_words() -> Vec<String> {
+    let letters = ['A', 'B', 'C', 'D', 'E', 'R', 'S', 'T'];
+    let mut words = Vec::new();
+    for &a in &letters {
+        for &b in &letters {
+            for &c in &letters {
+                for &d in &letters {
+                    for &e in &letters {
+                        words.push([a, b, c, d, e].iter().collect());
+                    }
+                }
+            }
+        }
+    }
+    words
+}
+
+/// Count the heap allocations made while running `f`
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    (result, ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+#[test]
+fn test_interned_word_list_allocations() {
+    let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let words = synthetic_words();
+    let word_count = words.len();
+    WORD_MANAGER.lock().unwrap().set_words(words.clone(), words);
+
+    // Previous pipeline: every call copied the whole list out of the manager
+    let (_, owned_allocations) = count_allocations(|| WORD_MANAGER.lock().unwrap().get_guess_words().to_vec());
+    // Interned pipeline: sharing the list is a reference count bump
+    let (_, interned_allocations) = count_allocations(|| WORD_MANAGER.lock().unwrap().get_interned_guess_words());
+
+    // Two greens short of a win leaves a tiny endgame, so the count is dominated by filtering
+    let state = vec![(
+        "ABCDE".to_string(),
+        vec!["G".to_string(), "G".to_string(), "G".to_string(), "G".to_string(), "X".to_string()],
+    )];
+    let (guess, call_allocations) = count_allocations(|| get_best_guess(state));
+
+    println!("📊 Word list copy over {} words: owned {} allocations, interned {} allocations",
+        word_count, owned_allocations, interned_allocations);
+    println!("📊 get_best_guess over {} words: {} allocations", word_count, call_allocations);
+
+    assert!(guess.is_some());
+    assert!(owned_allocations > word_count);
+    assert_eq!(interned_allocations, 0);
+}
+
+#[test]
+fn test_get_best_guess_skips_word_list_clone() {
+    let _serial = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let words = synthetic_words();
+    let word_count = words.len();
+    WORD_MANAGER.lock().unwrap().set_words(words.clone(), words);
+
+    // Previous solver construction: an owned copy of the whole guess list
+    let (_, owning_allocations) = count_allocations(|| {
+        IntelligentSolver::new(WORD_MANAGER.lock().unwrap().get_guess_words().to_vec())
+    });
+    let (_, manager_allocations) = count_allocations(IntelligentSolver::new_from_manager);
+
+    // A whole call stays below one allocation per word, so no per-word copy is made
+    let state = vec![(
+        "ABCDE".to_string(),
+        vec!["G".to_string(), "G".to_string(), "G".to_string(), "G".to_string(), "X".to_string()],
+    )];
+    let (guess, call_allocations) = count_allocations(|| get_best_guess(state));
+
+    println!("📊 Solver construction over {} words: owning {} allocations, from manager {} allocations",
+        word_count, owning_allocations, manager_allocations);
+    println!("📊 get_best_guess over {} words: {} allocations", word_count, call_allocations);
+
+    assert!(guess.is_some());
+    assert!(owning_allocations > word_count);
+    assert_eq!(manager_allocations, 0);
+    assert!(call_allocations < word_count);
+}
@@ -0,0 +1,40 @@
+//! Accuracy regression guard against the human baseline
+//!
+//! Plays a seeded sample of games on the real word lists and checks the solver
+//! still beats `HumanBenchmarks`. Slow, so it is ignored by default:
+//! `cargo test --release --test solver_beats_humans -- --ignored --nocapture`
+
+use rust_lib_wrdlhelper::api::simple::initialize_word_lists;
+use rust_lib_wrdlhelper::api::wrdl_helper::WORD_MANAGER;
+use rust_lib_wrdlhelper::benchmark_runner::HumanBenchmarks;
+use rust_lib_wrdlhelper::benchmarking::WordleBenchmark;
+
+/// Number of distinct answer words played
+const SAMPLE_SIZE: usize = 200;
+/// Seed for picking the answer words, so failures are reproducible
+const SEED: u64 = 2024;
+
+#[test]
+#[ignore = "plays hundreds of full games; run with --ignored"]
+fn solver_beats_humans() {
+    initialize_word_lists().unwrap();
+    let (answer_words, guess_words) = {
+        let manager = WORD_MANAGER.lock().unwrap();
+        (manager.get_answer_words().to_vec(), manager.get_guess_words().to_vec())
+    };
+
+    let benchmark = WordleBenchmark::new(answer_words, guess_words);
+    let human_benchmarks = HumanBenchmarks::new();
+    let stats = benchmark.run_benchmark_no_replacement(SAMPLE_SIZE, human_benchmarks.max_guesses, SEED);
+
+    println!("📊 Solver: {:.1}% success, {:.2} average guesses over {} games (seed {})",
+        stats.success_rate * 100.0, stats.average_guesses, stats.total_games, SEED);
+    println!("👤 Humans: {:.1}% success, {:.2} average guesses",
+        human_benchmarks.success_rate * 100.0, human_benchmarks.average_guesses);
+
+    assert_eq!(stats.total_games, SAMPLE_SIZE);
+    assert!(stats.success_rate > human_benchmarks.success_rate,
+        "Success rate {:.3} is not above the human {:.3}", stats.success_rate, human_benchmarks.success_rate);
+    assert!(stats.average_guesses < human_benchmarks.average_guesses,
+        "Average guesses {:.2} is not below the human {:.2}", stats.average_guesses, human_benchmarks.average_guesses);
+}